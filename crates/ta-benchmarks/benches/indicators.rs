@@ -0,0 +1,307 @@
+//! Indicator benchmarks comparing the dispatched SIMD path against scalar kernels
+//!
+//! Every entry is benchmarked twice per input size: once through the normal runtime
+//! dispatch, and once with the scalar kernels forced via
+//! `simd::dispatch::LevelGuard`. After the Criterion runs, the suite checks the
+//! `Indicator` performance contract: for inputs larger than 1000 points the SIMD
+//! path must be at least 2x faster than scalar. Missed targets are reported; set
+//! `TA_BENCH_ENFORCE_SPEEDUP=1` to make them fail the run. The check is skipped when
+//! the host has no SIMD level to compare against.
+//!
+//! The contract only covers entries whose hot loop runs a vectorized
+//! `simd::dispatch` kernel (the dispatched `sum` is scalar on every level, so it
+//! does not count). The other indicators are sequential recurrences or scalar
+//! sliding sums: their speedups are still reported, but forcing the scalar level
+//! changes little for them. Adding an indicator only takes a new entry in
+//! [`entries`].
+
+use std::time::{Duration, Instant};
+
+use criterion::{black_box, criterion_group, BenchmarkId, Criterion, Throughput};
+use ta_core::{
+    momentum::{Aroon, Stochastic, WilliamsR, ADX, CMO, MACD, MFI, PPO, RSI, TSI},
+    overlap::{
+        BollingerBands, MaEnvelope, MaKind, McGinleyDynamic, DEMA, EMA, GMMA, HMA, KAMA, SAR, SMA,
+        TEMA, TRIMA, WMA,
+    },
+    simd::{
+        dispatch::{self, LevelGuard},
+        SimdLevel,
+    },
+    stats::{
+        Alpha, EquityCurve, HampelFilter, HedgeRatio, LinRegForecast, LinearReg, LinearRegAngle,
+        LinearRegIntercept, LinearRegSlope, MaxDrawdown, ProductMethod, RollingEntropy,
+        RollingProduct, Spread, ZScore, STDDEV, TSF, VAR,
+    },
+    transform::ReturnKind,
+    volatility::{ATR, TRANGE},
+    volume::{Klinger, VwapBands, OBV},
+    Float, Indicator, Ohlc,
+};
+
+/// Input sizes every entry is benchmarked at
+const SIZES: [usize; 4] = [100, 1_000, 10_000, 100_000];
+/// The contract only applies to inputs strictly larger than this
+const CONTRACT_MIN_LEN: usize = 1_000;
+/// Minimum SIMD-over-scalar speedup promised by the `Indicator` docs
+const CONTRACT_SPEEDUP: f64 = 2.0;
+/// Environment variable that turns missed speedup targets into a failed run
+const ENFORCE_VAR: &str = "TA_BENCH_ENFORCE_SPEEDUP";
+
+/// Synthetic market data every entry draws its inputs from
+struct Series {
+    prices: Vec<Float>,
+    returns: Vec<Float>,
+    bars: Vec<Ohlc>,
+    pairs: Vec<(Float, Float)>,
+}
+
+impl Series {
+    /// Deterministic, strictly positive series of `n` points
+    fn new(n: usize) -> Self {
+        let prices: Vec<Float> = (0..n)
+            .map(|i| 100.0 + (i as Float * 0.05).sin() * 10.0 + (i as Float * 0.37).cos())
+            .collect();
+        let returns = prices
+            .iter()
+            .scan(prices[0], |prev, &p| {
+                Some((p / core::mem::replace(prev, p)) - 1.0)
+            })
+            .collect();
+        let bars = prices
+            .iter()
+            .enumerate()
+            .map(|(i, &close)| Ohlc {
+                open: close - (i as Float * 0.7).sin() * 0.3,
+                high: close + 0.5 + (i as Float * 0.11).cos().abs(),
+                low: close - 0.5 - (i as Float * 0.13).sin().abs(),
+                close,
+                volume: 1_000.0 + (i as Float * 0.17).sin() * 300.0,
+            })
+            .collect();
+        let pairs = prices
+            .iter()
+            .enumerate()
+            .map(|(i, &p)| (p, 0.8 * p + (i as Float * 0.23).cos()))
+            .collect();
+        Self {
+            prices,
+            returns,
+            bars,
+            pairs,
+        }
+    }
+}
+
+/// A benchmarked indicator: a name and a batch computation over one series
+struct Entry {
+    name: &'static str,
+    /// Whether the hot loop runs a vectorized dispatch kernel, so the contract applies
+    vectorized: bool,
+    compute: fn(&Series),
+}
+
+impl Entry {
+    fn vectorized(name: &'static str, compute: fn(&Series)) -> Self {
+        Self {
+            name,
+            vectorized: true,
+            compute,
+        }
+    }
+
+    fn scalar(name: &'static str, compute: fn(&Series)) -> Self {
+        Self {
+            name,
+            vectorized: false,
+            compute,
+        }
+    }
+}
+
+/// Batch-compute `indicator` over `inputs`, keeping the result alive
+fn run<I: Indicator<N>, const N: usize>(indicator: I, inputs: &[I::Input]) {
+    black_box(indicator.compute_to_vec(black_box(inputs)).unwrap());
+}
+
+fn entries() -> Vec<Entry> {
+    vec![
+        // Overlap
+        Entry::vectorized("wma_256", |s| run(WMA::new(256).unwrap(), &s.prices)),
+        Entry::vectorized("hma_256", |s| run(HMA::new(256).unwrap(), &s.prices)),
+        Entry::vectorized("bollinger_256", |s| {
+            run(BollingerBands::new(256, 2.0).unwrap(), &s.prices)
+        }),
+        Entry::scalar("sma_20", |s| run(SMA::new(20), &s.prices)),
+        Entry::scalar("ema_20", |s| run(EMA::new(20).unwrap(), &s.prices)),
+        Entry::scalar("dema_20", |s| run(DEMA::new(20).unwrap(), &s.prices)),
+        Entry::scalar("tema_20", |s| run(TEMA::new(20).unwrap(), &s.prices)),
+        Entry::scalar("trima_20", |s| run(TRIMA::new(20).unwrap(), &s.prices)),
+        Entry::scalar("kama_10", |s| run(KAMA::new(10, 2, 30).unwrap(), &s.prices)),
+        Entry::scalar("mcginley_14", |s| {
+            run(McGinleyDynamic::with_period(14).unwrap(), &s.prices)
+        }),
+        Entry::scalar("envelope_20", |s| {
+            run(MaEnvelope::new(20, MaKind::Sma, 2.5).unwrap(), &s.prices)
+        }),
+        Entry::scalar("gmma", |s| {
+            black_box(GMMA::new().compute(black_box(&s.prices)).unwrap());
+        }),
+        Entry::scalar("sar", |s| run(SAR::new(0.02, 0.2).unwrap(), &s.bars)),
+        // Momentum
+        Entry::scalar("adx_14", |s| run(ADX::new(14).unwrap(), &s.bars)),
+        Entry::scalar("aroon_25", |s| run(Aroon::new(25).unwrap(), &s.bars)),
+        Entry::scalar("cmo_14", |s| run(CMO::new(14).unwrap(), &s.prices)),
+        Entry::scalar("macd_12_26_9", |s| {
+            run(MACD::new(12, 26, 9).unwrap(), &s.prices)
+        }),
+        Entry::scalar("mfi_14", |s| run(MFI::new(14).unwrap(), &s.bars)),
+        Entry::scalar("ppo_12_26_9", |s| {
+            run(PPO::new(12, 26, 9).unwrap(), &s.prices)
+        }),
+        Entry::scalar("rsi_14", |s| run(RSI::new(14).unwrap(), &s.prices)),
+        Entry::scalar("stochastic_14_3_3", |s| {
+            run(Stochastic::new(14, 3, 3).unwrap(), &s.bars)
+        }),
+        Entry::scalar("tsi_25_13", |s| run(TSI::new(25, 13).unwrap(), &s.prices)),
+        Entry::scalar("willr_14", |s| run(WilliamsR::new(14).unwrap(), &s.bars)),
+        // Statistics
+        Entry::vectorized("linearreg_256", |s| {
+            run(LinearReg::new(256).unwrap(), &s.prices)
+        }),
+        Entry::vectorized("var_256", |s| run(VAR::new(256).unwrap(), &s.prices)),
+        Entry::vectorized("stddev_256", |s| {
+            run(STDDEV::new(256, 1.0).unwrap(), &s.prices)
+        }),
+        Entry::vectorized("zscore_256", |s| run(ZScore::new(256).unwrap(), &s.prices)),
+        Entry::scalar("linearreg_slope_14", |s| {
+            run(LinearRegSlope::new(14).unwrap(), &s.prices)
+        }),
+        Entry::scalar("linearreg_angle_14", |s| {
+            run(LinearRegAngle::new(14).unwrap(), &s.prices)
+        }),
+        Entry::scalar("linearreg_intercept_14", |s| {
+            run(LinearRegIntercept::new(14).unwrap(), &s.prices)
+        }),
+        Entry::scalar("linreg_forecast_14", |s| {
+            run(LinRegForecast::new(14, 1).unwrap(), &s.prices)
+        }),
+        Entry::scalar("tsf_14", |s| run(TSF::new(14).unwrap(), &s.prices)),
+        Entry::scalar("hampel_7", |s| {
+            run(HampelFilter::new(7, 3.0).unwrap(), &s.prices)
+        }),
+        Entry::scalar("entropy_20", |s| {
+            run(RollingEntropy::new(20, 8).unwrap(), &s.prices)
+        }),
+        Entry::scalar("rolling_product_10", |s| {
+            run(
+                RollingProduct::new(10, ProductMethod::LogSum).unwrap(),
+                &s.prices,
+            )
+        }),
+        Entry::scalar("max_drawdown_20", |s| {
+            run(MaxDrawdown::new(20).unwrap(), &s.prices)
+        }),
+        Entry::scalar("equity_curve", |s| {
+            run(
+                EquityCurve::new(100.0, ReturnKind::Simple).unwrap(),
+                &s.returns,
+            )
+        }),
+        Entry::scalar("alpha_20", |s| run(Alpha::new(20).unwrap(), &s.pairs)),
+        Entry::scalar("hedge_ratio_20", |s| {
+            run(HedgeRatio::new(20).unwrap(), &s.pairs)
+        }),
+        Entry::scalar("spread", |s| run(Spread::new(1.25).unwrap(), &s.pairs)),
+        // Volatility and volume
+        Entry::scalar("atr_14", |s| run(ATR::new(14).unwrap(), &s.bars)),
+        Entry::scalar("trange", |s| run(TRANGE::new(), &s.bars)),
+        Entry::scalar("klinger_34_55_13", |s| {
+            run(Klinger::new(34, 55, 13).unwrap(), &s.bars)
+        }),
+        Entry::scalar("vwap_bands", |s| run(VwapBands::new(2.0).unwrap(), &s.bars)),
+        Entry::scalar("obv", |s| run(OBV::new(), &s.bars)),
+    ]
+}
+
+fn bench_indicators(c: &mut Criterion) {
+    for entry in entries() {
+        let mut group = c.benchmark_group(entry.name);
+        for size in SIZES {
+            let series = Series::new(size);
+            group.throughput(Throughput::Elements(size as u64));
+            group.bench_with_input(BenchmarkId::new("simd", size), &series, |b, series| {
+                b.iter(|| (entry.compute)(series))
+            });
+            group.bench_with_input(BenchmarkId::new("scalar", size), &series, |b, series| {
+                let _guard = LevelGuard::new(SimdLevel::Scalar);
+                b.iter(|| (entry.compute)(series))
+            });
+        }
+        group.finish();
+    }
+}
+
+/// Best-of-`runs` wall time for one computation, to damp scheduler noise
+fn best_time(compute: fn(&Series), series: &Series, runs: usize) -> Duration {
+    (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            compute(series);
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+/// Report the SIMD speedup of every entry and list the vectorized entries that miss
+/// the contract. Returns `false` if any did.
+fn check_speedup_contract() -> bool {
+    let level = dispatch::active_level();
+    if level == SimdLevel::Scalar {
+        println!("\nNo SIMD level detected; skipping the speedup contract check");
+        return true;
+    }
+    let mut misses = Vec::new();
+
+    println!("\n{level:?} speedup over scalar (contract: >= {CONTRACT_SPEEDUP}x above {CONTRACT_MIN_LEN} points, * = vectorized)");
+    for entry in entries() {
+        for size in SIZES.into_iter().filter(|&size| size > CONTRACT_MIN_LEN) {
+            let series = Series::new(size);
+            let simd = best_time(entry.compute, &series, 10);
+            let scalar = {
+                let _guard = LevelGuard::new(SimdLevel::Scalar);
+                best_time(entry.compute, &series, 10)
+            };
+            let speedup = scalar.as_secs_f64() / simd.as_secs_f64().max(f64::MIN_POSITIVE);
+            let marker = if entry.vectorized { '*' } else { ' ' };
+            println!(
+                "  {marker} {:<24} {:>7}  {:>6.2}x",
+                entry.name, size, speedup
+            );
+            if entry.vectorized && speedup < CONTRACT_SPEEDUP {
+                misses.push(format!("{} @ {}: {:.2}x", entry.name, size, speedup));
+            }
+        }
+    }
+
+    if misses.is_empty() {
+        return true;
+    }
+    eprintln!(
+        "\nSIMD speedup target missed (set {ENFORCE_VAR}=1 to fail the run):\n  {}",
+        misses.join("\n  ")
+    );
+    false
+}
+
+criterion_group!(benches, bench_indicators);
+
+fn main() {
+    benches();
+    let met = check_speedup_contract();
+    Criterion::default().configure_from_args().final_summary();
+    if !met && std::env::var_os(ENFORCE_VAR).is_some_and(|v| v == "1") {
+        std::process::exit(1);
+    }
+}
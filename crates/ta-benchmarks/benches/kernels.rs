@@ -0,0 +1,40 @@
+//! Throughput benchmarks for the dispatched SIMD kernels
+//!
+//! Compares the runtime-dispatched kernels against the scalar fallback on long
+//! inputs, where loop structure (unrolling, independent accumulators) dominates.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use ta_core::{
+    simd::{dispatch, dispatch::LevelGuard, SimdLevel},
+    Float,
+};
+
+const SIZES: [usize; 2] = [10_000, 100_000];
+
+fn series(n: usize, step: Float) -> Vec<Float> {
+    (0..n).map(|i| (i as Float * step).sin()).collect()
+}
+
+fn bench_dot_product(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dot_product");
+    for size in SIZES {
+        let a = series(size, 0.01);
+        let b = series(size, 0.03);
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::new("dispatch", size), &size, |bench, _| {
+            bench.iter(|| dispatch::dot_product(black_box(&a), black_box(&b)))
+        });
+        group.bench_with_input(BenchmarkId::new("avx2", size), &size, |bench, _| {
+            let _guard = LevelGuard::new(SimdLevel::Avx2);
+            bench.iter(|| dispatch::dot_product(black_box(&a), black_box(&b)))
+        });
+        group.bench_with_input(BenchmarkId::new("scalar", size), &size, |bench, _| {
+            let _guard = LevelGuard::new(SimdLevel::Scalar);
+            bench.iter(|| dispatch::dot_product(black_box(&a), black_box(&b)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_dot_product);
+criterion_main!(benches);
@@ -0,0 +1,126 @@
+//! Stateful wrappers around batch computation
+//!
+//! These adapters bridge the stateless batch API (`compute_to_vec`) and streaming
+//! for append-only data, where recomputing the full history on every new bar is
+//! wasteful.
+
+use crate::{error::Result, traits::Indicator};
+
+/// Incremental batch computation for append-only series
+///
+/// Keeps the last `lookback()` inputs as context so that each call to
+/// [`append`](IncrementalBatch::append) only computes outputs for the newly
+/// appended inputs, while still using the batch `compute_to_vec` path.
+///
+/// The result of appending in chunks equals a single `compute_to_vec` over the
+/// concatenated inputs for indicators whose output depends only on the trailing
+/// `lookback() + 1` inputs (window indicators such as SMA). Recursive indicators
+/// whose state reaches further back should be driven through `next` instead.
+///
+/// # Example
+///
+/// ```rust
+/// use ta_core::{batch::IncrementalBatch, overlap::SMA};
+///
+/// let mut batch = IncrementalBatch::new(SMA::new(3));
+/// let first = batch.append(&[1.0, 2.0, 3.0]).unwrap();
+/// assert_eq!(first[2], 2.0);
+/// let second = batch.append(&[4.0]).unwrap();
+/// assert_eq!(second, vec![3.0]);
+/// ```
+pub struct IncrementalBatch<I: Indicator> {
+    indicator: I,
+    tail: Vec<I::Input>,
+}
+
+impl<I> IncrementalBatch<I>
+where
+    I: Indicator,
+    I::Input: Copy,
+{
+    /// Wrap an indicator for incremental batch computation.
+    pub fn new(indicator: I) -> Self {
+        let tail = Vec::with_capacity(indicator.lookback());
+        Self { indicator, tail }
+    }
+
+    /// Compute outputs for `new_inputs` only, using the retained tail as context.
+    ///
+    /// Returns exactly one output per new input. Outputs that are still inside the
+    /// overall warm-up period are `Float::NAN`, as with `compute_to_vec`.
+    pub fn append(&mut self, new_inputs: &[I::Input]) -> Result<Vec<I::Output>> {
+        let context = self.tail.len();
+        let mut combined = Vec::with_capacity(context + new_inputs.len());
+        combined.extend_from_slice(&self.tail);
+        combined.extend_from_slice(new_inputs);
+
+        let mut outputs = self.indicator.compute_to_vec(&combined)?;
+        let new_outputs = outputs.split_off(context);
+
+        let keep = self.indicator.lookback();
+        let start = combined.len().saturating_sub(keep);
+        self.tail.clear();
+        self.tail.extend_from_slice(&combined[start..]);
+
+        Ok(new_outputs)
+    }
+
+    /// Access the wrapped indicator.
+    pub fn indicator(&self) -> &I {
+        &self.indicator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{overlap::SMA, stats::RollingEntropy, Float};
+
+    fn assert_same(a: &[Float], b: &[Float]) {
+        assert_eq!(a.len(), b.len());
+        for (x, y) in a.iter().zip(b.iter()) {
+            assert!((x.is_nan() && y.is_nan()) || (x - y).abs() < 1e3 * Float::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_append_in_chunks_equals_full_compute() {
+        let inputs: Vec<Float> = (0..97).map(|i| (i as Float * 0.21).cos() * 5.0).collect();
+        let expected = SMA::new(10).compute_to_vec(&inputs).unwrap();
+
+        for chunk_size in [1, 3, 10, 41] {
+            let mut batch = IncrementalBatch::new(SMA::new(10));
+            let mut collected = Vec::new();
+            for chunk in inputs.chunks(chunk_size) {
+                let outputs = batch.append(chunk).unwrap();
+                assert_eq!(outputs.len(), chunk.len());
+                collected.extend(outputs);
+            }
+            assert_same(&collected, &expected);
+        }
+    }
+
+    #[test]
+    fn test_append_works_for_other_window_indicators() {
+        let inputs: Vec<Float> = (0..50).map(|i| ((i * 13) % 7) as Float).collect();
+        let expected = RollingEntropy::new(8, 3)
+            .unwrap()
+            .compute_to_vec(&inputs)
+            .unwrap();
+        let mut batch = IncrementalBatch::new(RollingEntropy::new(8, 3).unwrap());
+        let mut collected = Vec::new();
+        for chunk in inputs.chunks(6) {
+            collected.extend(batch.append(chunk).unwrap());
+        }
+        assert_same(&collected, &expected);
+    }
+
+    #[test]
+    fn test_append_empty_slice() {
+        let mut batch = IncrementalBatch::new(SMA::new(3));
+        assert!(batch.append(&[]).unwrap().is_empty());
+        batch.append(&[1.0, 2.0]).unwrap();
+        assert!(batch.append(&[]).unwrap().is_empty());
+        assert_eq!(batch.append(&[3.0]).unwrap(), vec![2.0]);
+    }
+}
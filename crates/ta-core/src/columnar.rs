@@ -0,0 +1,130 @@
+//! Columnar (struct-of-arrays) data helpers
+//!
+//! Market data is often stored as one vector per field. These helpers zip such
+//! columns into the [`Ohlc`] bars that bar-based indicators consume.
+
+use crate::{Float, Ohlc, Result, TalibError};
+
+/// Zip five equally long columns into a vector of bars
+///
+/// # Errors
+///
+/// Returns `TalibError::InvalidInput` if the columns differ in length.
+///
+/// # Example
+///
+/// ```rust
+/// use ta_core::columnar::zip_ohlc;
+///
+/// let bars = zip_ohlc(&[1.0, 2.0], &[3.0, 4.0], &[0.5, 1.5], &[2.5, 3.5], &[10.0, 20.0]).unwrap();
+/// assert_eq!(bars[1].high, 4.0);
+/// assert_eq!(bars[1].volume, 20.0);
+/// ```
+pub fn zip_ohlc(
+    open: &[Float],
+    high: &[Float],
+    low: &[Float],
+    close: &[Float],
+    volume: &[Float],
+) -> Result<Vec<Ohlc>> {
+    let mut bars = vec![Ohlc::default(); open.len()];
+    zip_ohlc_into(open, high, low, close, volume, &mut bars)?;
+    Ok(bars)
+}
+
+/// Zip five equally long columns into the start of `out`
+///
+/// Writes `open.len()` bars and leaves the rest of `out` untouched, so one buffer
+/// can be reused across batches.
+///
+/// # Errors
+///
+/// - `TalibError::InvalidInput` if the columns differ in length
+/// - `TalibError::OutputBufferTooSmall` if `out` is shorter than the columns
+pub fn zip_ohlc_into(
+    open: &[Float],
+    high: &[Float],
+    low: &[Float],
+    close: &[Float],
+    volume: &[Float],
+    out: &mut [Ohlc],
+) -> Result<()> {
+    let len = open.len();
+    let columns = [
+        ("high", high),
+        ("low", low),
+        ("close", close),
+        ("volume", volume),
+    ];
+    if let Some((name, column)) = columns.iter().find(|(_, c)| c.len() != len) {
+        return Err(TalibError::invalid_input(format!(
+            "column lengths differ: open has {} values, {} has {}",
+            len,
+            name,
+            column.len()
+        )));
+    }
+    if out.len() < len {
+        return Err(TalibError::output_buffer_too_small(len, out.len()));
+    }
+    for (i, bar) in out[..len].iter_mut().enumerate() {
+        *bar = Ohlc {
+            open: open[i],
+            high: high[i],
+            low: low[i],
+            close: close[i],
+            volume: volume[i],
+        };
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zip_ohlc() {
+        let bars = zip_ohlc(
+            &[1.0, 2.0, 3.0],
+            &[1.5, 2.5, 3.5],
+            &[0.5, 1.5, 2.5],
+            &[1.2, 2.2, 3.2],
+            &[100.0, 200.0, 300.0],
+        )
+        .unwrap();
+        assert_eq!(
+            bars[2],
+            Ohlc {
+                open: 3.0,
+                high: 3.5,
+                low: 2.5,
+                close: 3.2,
+                volume: 300.0,
+            }
+        );
+        assert_eq!(bars.len(), 3);
+    }
+
+    #[test]
+    fn test_length_mismatch_rejected() {
+        let col = [1.0, 2.0, 3.0];
+        let result = zip_ohlc(&col, &col, &col[..2], &col, &col);
+        assert!(matches!(result, Err(TalibError::InvalidInput { .. })));
+    }
+
+    #[test]
+    fn test_zip_into_reuses_buffer() {
+        let col = [1.0, 2.0];
+        let mut out = [Ohlc::default(); 3];
+        zip_ohlc_into(&col, &col, &col, &col, &col, &mut out).unwrap();
+        assert_eq!(out[1].close, 2.0);
+        assert_eq!(out[2], Ohlc::default());
+
+        let mut small = [Ohlc::default(); 1];
+        assert_eq!(
+            zip_ohlc_into(&col, &col, &col, &col, &col, &mut small),
+            Err(TalibError::output_buffer_too_small(2, 1))
+        );
+    }
+}
@@ -10,6 +10,7 @@
 /// This enum covers all possible error scenarios that might occur during indicator
 /// computation, input validation, and data processing.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TalibError {
     /// Invalid input data (e.g., NaN values, infinite values)
     InvalidInput {
@@ -43,6 +44,14 @@ pub enum TalibError {
         expected: String,
     },
 
+    /// Paired inputs have different lengths (e.g., two series for a spread)
+    DimensionMismatch {
+        /// Length of the first input
+        expected: usize,
+        /// Length of the mismatching input
+        actual: usize,
+    },
+
     /// Computation error (e.g., numerical issues, overflow)
     ComputationError {
         /// Description of the computation failure
@@ -54,9 +63,73 @@ pub enum TalibError {
         /// Feature name or description
         feature: String,
     },
+
+    /// Caller-provided output buffer cannot hold the result
+    OutputBufferTooSmall {
+        /// Required output length
+        required: usize,
+        /// Actual output buffer length
+        actual: usize,
+    },
 }
 
 impl TalibError {
+    /// Code of [`TalibError::InvalidInput`]
+    pub const CODE_INVALID_INPUT: u32 = 1;
+    /// Code of [`TalibError::InvalidPeriod`]
+    pub const CODE_INVALID_PERIOD: u32 = 2;
+    /// Code of [`TalibError::InsufficientData`]
+    pub const CODE_INSUFFICIENT_DATA: u32 = 3;
+    /// Code of [`TalibError::InvalidParameter`]
+    pub const CODE_INVALID_PARAMETER: u32 = 4;
+    /// Code of [`TalibError::ComputationError`]
+    pub const CODE_COMPUTATION_ERROR: u32 = 5;
+    /// Code of [`TalibError::NotImplemented`]
+    pub const CODE_NOT_IMPLEMENTED: u32 = 6;
+    /// Code of [`TalibError::DimensionMismatch`]
+    pub const CODE_DIMENSION_MISMATCH: u32 = 7;
+    /// Code of [`TalibError::OutputBufferTooSmall`]
+    pub const CODE_OUTPUT_BUFFER_TOO_SMALL: u32 = 8;
+
+    /// Stable numeric code of the error variant
+    ///
+    /// Bindings use this to map errors across an FFI boundary without parsing the
+    /// message. The codes are part of the stable API: an assigned code never changes,
+    /// and new variants get the next unused number. `0` is never returned, so hosts
+    /// can use it for success.
+    ///
+    /// | Code | Variant |
+    /// |------|---------|
+    /// | 1 | `InvalidInput` |
+    /// | 2 | `InvalidPeriod` |
+    /// | 3 | `InsufficientData` |
+    /// | 4 | `InvalidParameter` |
+    /// | 5 | `ComputationError` |
+    /// | 6 | `NotImplemented` |
+    /// | 7 | `DimensionMismatch` |
+    /// | 8 | `OutputBufferTooSmall` |
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ta_core::error::TalibError;
+    ///
+    /// let err = TalibError::invalid_period(0, "period must be greater than zero");
+    /// assert_eq!(err.code(), TalibError::CODE_INVALID_PERIOD);
+    /// ```
+    pub fn code(&self) -> u32 {
+        match self {
+            TalibError::InvalidInput { .. } => Self::CODE_INVALID_INPUT,
+            TalibError::InvalidPeriod { .. } => Self::CODE_INVALID_PERIOD,
+            TalibError::InsufficientData { .. } => Self::CODE_INSUFFICIENT_DATA,
+            TalibError::InvalidParameter { .. } => Self::CODE_INVALID_PARAMETER,
+            TalibError::ComputationError { .. } => Self::CODE_COMPUTATION_ERROR,
+            TalibError::NotImplemented { .. } => Self::CODE_NOT_IMPLEMENTED,
+            TalibError::DimensionMismatch { .. } => Self::CODE_DIMENSION_MISMATCH,
+            TalibError::OutputBufferTooSmall { .. } => Self::CODE_OUTPUT_BUFFER_TOO_SMALL,
+        }
+    }
+
     /// Creates an InvalidInput error
     ///
     /// # Arguments
@@ -138,6 +211,24 @@ impl TalibError {
         }
     }
 
+    /// Creates a DimensionMismatch error
+    ///
+    /// # Arguments
+    ///
+    /// * `expected` - Length of the first input
+    /// * `actual` - Length of the mismatching input
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ta_core::error::TalibError;
+    ///
+    /// let err = TalibError::dimension_mismatch(100, 99);
+    /// ```
+    pub fn dimension_mismatch(expected: usize, actual: usize) -> Self {
+        TalibError::DimensionMismatch { expected, actual }
+    }
+
     /// Creates a ComputationError
     ///
     /// # Arguments
@@ -175,6 +266,24 @@ impl TalibError {
             feature: feature.into(),
         }
     }
+
+    /// Creates an OutputBufferTooSmall error
+    ///
+    /// # Arguments
+    ///
+    /// * `required` - Output length the computation needs
+    /// * `actual` - Length of the buffer provided
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ta_core::error::TalibError;
+    ///
+    /// let err = TalibError::output_buffer_too_small(20, 10);
+    /// ```
+    pub fn output_buffer_too_small(required: usize, actual: usize) -> Self {
+        TalibError::OutputBufferTooSmall { required, actual }
+    }
 }
 
 impl core::fmt::Display for TalibError {
@@ -204,12 +313,26 @@ impl core::fmt::Display for TalibError {
                     name, value, expected
                 )
             }
+            TalibError::DimensionMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "Dimension mismatch: expected length {}, got {}",
+                    expected, actual
+                )
+            }
             TalibError::ComputationError { message } => {
                 write!(f, "Computation error: {}", message)
             }
             TalibError::NotImplemented { feature } => {
                 write!(f, "Feature not implemented: {}", feature)
             }
+            TalibError::OutputBufferTooSmall { required, actual } => {
+                write!(
+                    f,
+                    "Output buffer too small: required {} values, got {}",
+                    required, actual
+                )
+            }
         }
     }
 }
@@ -308,18 +431,64 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_dimension_mismatch_creation() {
+        let err = TalibError::dimension_mismatch(10, 8);
+        assert_eq!(
+            err.to_string(),
+            "Dimension mismatch: expected length 10, got 8"
+        );
+    }
+
     #[test]
     fn test_computation_error_creation() {
         let err = TalibError::computation_error("Numerical overflow");
         assert_eq!(err.to_string(), "Computation error: Numerical overflow");
     }
 
+    #[test]
+    fn test_output_buffer_too_small_creation() {
+        let err = TalibError::output_buffer_too_small(20, 10);
+        assert_eq!(
+            err.to_string(),
+            "Output buffer too small: required 20 values, got 10"
+        );
+    }
+
     #[test]
     fn test_not_implemented_creation() {
         let err = TalibError::not_implemented("Feature X");
         assert_eq!(err.to_string(), "Feature not implemented: Feature X");
     }
 
+    #[test]
+    fn test_error_codes_are_stable() {
+        let errors = [
+            (TalibError::invalid_input("x"), 1),
+            (TalibError::invalid_period(0, "x"), 2),
+            (TalibError::insufficient_data(2, 1), 3),
+            (TalibError::invalid_parameter("a", "b", "c"), 4),
+            (TalibError::computation_error("x"), 5),
+            (TalibError::not_implemented("x"), 6),
+            (TalibError::dimension_mismatch(2, 1), 7),
+            (TalibError::output_buffer_too_small(2, 1), 8),
+        ];
+        for (i, (err, code)) in errors.iter().enumerate() {
+            assert_eq!(err.code(), *code, "{:?}", err);
+            assert!(errors[i + 1..]
+                .iter()
+                .all(|(other, _)| other.code() != err.code()));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let err = TalibError::invalid_parameter("alpha", "1.5", "value in [0.0, 1.0]");
+        let json = serde_json::to_string(&err).unwrap();
+        assert_eq!(serde_json::from_str::<TalibError>(&json).unwrap(), err);
+    }
+
     #[test]
     fn test_error_variants_are_equality_comparable() {
         let err1 = TalibError::invalid_input("Test");
@@ -437,6 +606,9 @@ mod tests {
 
         let err6 = TalibError::not_implemented("advanced feature");
         assert!(matches!(err6, TalibError::NotImplemented { .. }));
+
+        let err7 = TalibError::output_buffer_too_small(10, 5);
+        assert!(matches!(err7, TalibError::OutputBufferTooSmall { .. }));
     }
 
     #[test]
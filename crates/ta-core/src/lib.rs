@@ -14,6 +14,9 @@
 //!
 //! - [`types`]: Floating-point type configuration
 //! - [`error`]: Error types and handling
+//! - [`batch`]: Stateful wrappers around batch computation
+//! - [`validation`]: Input data validation helpers
+//! - [`transform`]: Input transforms applied before an indicator
 #![cfg_attr(not(feature = "std"), no_std)]
 #![warn(missing_docs)]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
@@ -29,13 +32,39 @@ use alloc::{format, string::String, vec::Vec};
 #[allow(unused_imports)]
 use std::{format, string::String, vec::Vec};
 
+pub mod batch;
+/// Columnar data: Zipping struct-of-arrays columns into price bars
+pub mod columnar;
 pub mod error;
+/// Momentum indicators: Oscillators measuring the rate of price change
+pub mod momentum;
 /// Overlap studies: Moving averages and other price overlay indicators
 pub mod overlap;
+/// Trading signals: Discrete events derived from indicator outputs
+pub mod signals;
 pub mod simd;
+mod smoothing;
+#[cfg(feature = "std")]
+mod speedup;
+/// Statistic functions: Rolling-window distribution measures
+pub mod stats;
+#[cfg(test)]
+mod test_util;
 pub mod traits;
+pub mod transform;
 pub mod types;
+pub mod validation;
+/// Volatility indicators: Measures of the size of price moves
+pub mod volatility;
+/// Volume indicators: Volume-weighted and cumulative volume measures
+pub mod volume;
 
 pub use error::{Result, TalibError};
-pub use traits::{Indicator, Resettable};
-pub use types::Float;
+#[cfg(feature = "std")]
+pub use speedup::benchmark_speedup;
+pub use traits::{
+    BarIndicator, Indicator, IndicatorOutput, IterIndicator, PairIndicator, Resettable,
+    StatefulIndicator,
+};
+pub use types::{Candle, Float, InvalidPolicy, Ohlc, PriceSource, Real};
+pub use validation::validate;
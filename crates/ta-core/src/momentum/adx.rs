@@ -0,0 +1,239 @@
+//! Implementation of the Average Directional Movement Index (ADX).
+
+use crate::{volatility::TRANGE, Float, Indicator, Ohlc, Resettable, Result, TalibError};
+
+/// Average Directional Movement Index
+///
+/// Measures trend strength regardless of direction. Each bar's directional
+/// movement is the larger of the up move (`high - prev_high`) and the down move
+/// (`prev_low - low`), counted only when positive. The movements and the
+/// [true range](TRANGE) are Wilder-smoothed, giving
+///
+/// ```text
+/// +DI = 100 * smoothed(+DM) / smoothed(TR)
+/// -DI = 100 * smoothed(-DM) / smoothed(TR)
+/// DX  = 100 * |+DI - -DI| / (+DI + -DI)
+/// ```
+///
+/// and ADX is the Wilder smoothing of DX. The smoothed sums are seeded with the
+/// first `period - 1` movements, ADX is seeded with the average of the first
+/// `period` DX values, and `lookback() == 2 * period - 1`. This is the seeding
+/// TA-Lib's `TA_ADX` uses, but the output has not been checked against TA-Lib.
+/// A bar whose DX is undefined (no range or no movement) leaves ADX unchanged.
+/// ADX is deeply sequential, so batch computation drives `next`.
+#[derive(Debug, Clone)]
+pub struct ADX {
+    period: usize,
+    true_range: TRANGE,
+    prev_high_low: Option<(Float, Float)>,
+    count: usize,
+    plus_dm: Float,
+    minus_dm: Float,
+    tr: Float,
+    sum_dx: Float,
+    adx: Float,
+}
+
+impl ADX {
+    /// Create a new ADX, commonly `ADX::new(14)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if `period` is zero.
+    pub fn new(period: usize) -> Result<Self> {
+        if period == 0 {
+            return Err(TalibError::invalid_period(
+                period,
+                "period must be greater than zero",
+            ));
+        }
+        Ok(Self {
+            period,
+            true_range: TRANGE::new(),
+            prev_high_low: None,
+            count: 0,
+            plus_dm: 0.0,
+            minus_dm: 0.0,
+            tr: 0.0,
+            sum_dx: 0.0,
+            adx: Float::NAN,
+        })
+    }
+
+    /// DX of the current smoothed sums, or `None` when it is undefined.
+    fn dx(&self) -> Option<Float> {
+        if self.tr == 0.0 {
+            return None;
+        }
+        let plus_di = 100.0 * self.plus_dm / self.tr;
+        let minus_di = 100.0 * self.minus_dm / self.tr;
+        let total = plus_di + minus_di;
+        (total != 0.0).then(|| 100.0 * (plus_di - minus_di).abs() / total)
+    }
+}
+
+impl Indicator for ADX {
+    type Input = Ohlc;
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        format!("ADX({})", self.period)
+    }
+
+    fn lookback(&self) -> usize {
+        2 * self.period - 1
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut adx = Self::new(self.period)?;
+        Ok(inputs.iter().map(|&bar| adx.next(bar)).collect())
+    }
+
+    fn next(&mut self, input: Ohlc) -> Float {
+        let tr = self.true_range.next(input);
+        let Some((prev_high, prev_low)) = self.prev_high_low.replace((input.high, input.low))
+        else {
+            return Float::NAN;
+        };
+        let up = input.high - prev_high;
+        let down = prev_low - input.low;
+        let (plus, minus) = if down > 0.0 && up < down {
+            (0.0, down)
+        } else if up > 0.0 && up > down {
+            (up, 0.0)
+        } else {
+            (0.0, 0.0)
+        };
+
+        self.count += 1;
+        let period = self.period as Float;
+        if self.count < self.period {
+            self.plus_dm += plus;
+            self.minus_dm += minus;
+            self.tr += tr;
+            return Float::NAN;
+        }
+        self.plus_dm += plus - self.plus_dm / period;
+        self.minus_dm += minus - self.minus_dm / period;
+        self.tr += tr - self.tr / period;
+
+        let dx = self.dx();
+        if self.count < 2 * self.period {
+            self.sum_dx += dx.unwrap_or(0.0);
+            if self.count < 2 * self.period - 1 {
+                return Float::NAN;
+            }
+            self.adx = self.sum_dx / period;
+        } else if let Some(dx) = dx {
+            self.adx = (self.adx * (period - 1.0) + dx) / period;
+        }
+        self.adx
+    }
+}
+
+impl Resettable for ADX {
+    fn reset(&mut self) {
+        self.true_range.reset();
+        self.prev_high_low = None;
+        self.count = 0;
+        self.plus_dm = 0.0;
+        self.minus_dm = 0.0;
+        self.tr = 0.0;
+        self.sum_dx = 0.0;
+        self.adx = Float::NAN;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_reset_streaming_matches_batch;
+
+    fn bars() -> Vec<Ohlc> {
+        [
+            (101.5, 99.0, 100.0),
+            (105.08, 100.83, 103.77),
+            (105.55, 102.65, 104.05),
+            (107.81, 103.88, 104.96),
+            (110.12, 106.46, 108.67),
+            (111.4, 107.74, 110.29),
+            (111.1, 107.57, 108.73),
+            (111.47, 107.55, 109.04),
+            (112.26, 108.38, 110.96),
+            (110.64, 107.7, 109.52),
+            (109.24, 104.85, 106.35),
+            (107.8, 104.91, 106.13),
+            (107.33, 103.43, 106.23),
+            (104.36, 101.38, 102.87),
+            (102.89, 98.79, 99.94),
+            (101.77, 98.57, 100.47),
+            (101.19, 97.27, 100.06),
+            (99.4, 95.99, 97.06),
+            (99.02, 95.08, 96.49),
+            (99.96, 96.26, 98.87),
+            (100.45, 97.32, 99.12),
+            (100.93, 96.61, 98.06),
+            (102.25, 99.11, 100.51),
+            (105.45, 101.75, 104.31),
+            (106.49, 103.49, 105.03),
+            (108.67, 104.39, 105.74),
+            (110.92, 108.36, 109.84),
+            (114.45, 110.21, 113.11),
+            (115.15, 111.68, 112.97),
+            (116.57, 112.82, 114.05),
+            (118.66, 115.0, 117.51),
+            (119.7, 116.04, 118.23),
+            (118.95, 114.96, 116.26),
+            (118.35, 115.14, 116.63),
+            (119.35, 115.44, 118.01),
+            (117.28, 114.14, 115.78),
+            (115.37, 111.13, 112.6),
+            (113.74, 111.42, 112.59),
+            (113.74, 109.39, 112.27),
+            (110.57, 107.22, 108.66),
+        ]
+        .iter()
+        .map(|&(high, low, close)| Ohlc {
+            open: close,
+            high,
+            low,
+            close,
+            volume: 0.0,
+        })
+        .collect()
+    }
+
+    #[test]
+    fn test_regression_fixture() {
+        // ADX(14) from an independent Python transcription of the recurrence above,
+        // rounded to 6 decimals. It pins the current behaviour; it cannot catch a
+        // misreading of TA-Lib shared by both transcriptions
+        let expected = [
+            15.478226, 17.92244, 20.433578, 23.088045, 25.701516, 27.535032, 29.237583, 31.002284,
+            31.882679, 31.198134, 30.562485, 29.072956, 27.133092,
+        ];
+        let adx = ADX::new(14).unwrap();
+        assert_eq!(adx.lookback(), 27);
+        let out = adx.compute_to_vec(&bars()).unwrap();
+        assert!(out[..27].iter().all(|v| v.is_nan()));
+        for (got, want) in out[27..].iter().zip(&expected) {
+            // Rounding of the fixture, plus rounding carried through the smoothing
+            assert!(
+                (got - want).abs() < 1e-6 + 1e4 * Float::EPSILON,
+                "{got} != {want}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_streaming_matches_batch() {
+        let inputs = bars();
+        assert_reset_streaming_matches_batch(ADX::new(5).unwrap(), &inputs[20..21], &inputs, 0.0);
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        assert!(matches!(ADX::new(0), Err(TalibError::InvalidPeriod { .. })));
+    }
+}
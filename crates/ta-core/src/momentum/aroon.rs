@@ -0,0 +1,159 @@
+//! Implementation of the Aroon indicator.
+
+#[cfg(not(feature = "std"))]
+use alloc::collections::VecDeque;
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+
+use crate::{simd::scalar::push_extreme, Float, Indicator, Ohlc, Resettable, Result, TalibError};
+
+/// Aroon
+///
+/// Produces `[aroon_down, aroon_up]` per bar from how long ago the extremes of the
+/// last `period + 1` bars were set:
+///
+/// ```text
+/// aroon_up   = 100 * (period - bars_since_highest_high) / period
+/// aroon_down = 100 * (period - bars_since_lowest_low) / period
+/// ```
+///
+/// A fresh high reads 100 and a high `period` bars old reads 0. When the extreme
+/// is tied the most recent bar counts. The window extremes and their positions are
+/// tracked with monotonic deques, so each `next` is amortized O(1).
+#[derive(Debug, Clone)]
+pub struct Aroon {
+    period: usize,
+    count: usize,
+    highs: VecDeque<(usize, Float)>,
+    lows: VecDeque<(usize, Float)>,
+}
+
+impl Aroon {
+    /// Create a new Aroon indicator over `period` bars, commonly 25.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if `period` is zero.
+    pub fn new(period: usize) -> Result<Self> {
+        if period == 0 {
+            return Err(TalibError::invalid_period(
+                period,
+                "period must be greater than zero",
+            ));
+        }
+        Ok(Self {
+            period,
+            count: 0,
+            highs: VecDeque::with_capacity(period + 1),
+            lows: VecDeque::with_capacity(period + 1),
+        })
+    }
+
+    #[inline]
+    fn score(&self, bars_since: usize) -> Float {
+        100.0 * (self.period - bars_since) as Float / self.period as Float
+    }
+}
+
+impl Indicator<2> for Aroon {
+    type Input = Ohlc;
+
+    type Output = [Float; 2];
+
+    fn name(&self) -> String {
+        format!("Aroon({})", self.period)
+    }
+
+    fn lookback(&self) -> usize {
+        self.period
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut aroon = Self::new(self.period)?;
+        Ok(inputs.iter().map(|&bar| aroon.next(bar)).collect())
+    }
+
+    fn next(&mut self, input: Ohlc) -> [Float; 2] {
+        let index = self.count;
+        self.count += 1;
+        let window = self.period + 1;
+        let (high_at, _) = push_extreme(&mut self.highs, index, input.high, window, |v, x| v <= x);
+        let (low_at, _) = push_extreme(&mut self.lows, index, input.low, window, |v, x| v >= x);
+        if self.count < window {
+            return [Float::NAN; 2];
+        }
+        [self.score(index - low_at), self.score(index - high_at)]
+    }
+}
+
+impl Resettable for Aroon {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.highs.clear();
+        self.lows.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_reset_streaming_matches_batch;
+
+    fn bar(high: Float, low: Float) -> Ohlc {
+        Ohlc {
+            open: low,
+            high,
+            low,
+            close: high,
+            volume: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_rising_window() {
+        let bars: Vec<Ohlc> = (0..10).map(|i| bar(i as Float + 1.0, i as Float)).collect();
+        let aroon = Aroon::new(4).unwrap();
+        assert_eq!(aroon.lookback(), 4);
+        let out = aroon.compute_to_vec(&bars).unwrap();
+        assert!(out[..4].iter().all(|v| v.iter().all(|x| x.is_nan())));
+        // Every bar is a new high, and the lowest low is always the oldest bar
+        assert!(out[4..].iter().all(|&v| v == [0.0, 100.0]));
+    }
+
+    #[test]
+    fn test_hand_computed_fixture() {
+        let bars = [
+            bar(10.0, 5.0),
+            bar(12.0, 6.0), // highest high
+            bar(11.0, 4.0), // lowest low
+            bar(9.0, 7.0),
+            bar(8.0, 6.0),
+            bar(9.0, 5.0),
+        ];
+        let out = Aroon::new(4).unwrap().compute_to_vec(&bars).unwrap();
+        // Window 0..=4: high 3 bars ago, low 2 bars ago
+        assert_eq!(out[4], [50.0, 25.0]);
+        // Window 1..=5: high 4 bars ago, low 3 bars ago
+        assert_eq!(out[5], [25.0, 0.0]);
+    }
+
+    #[test]
+    fn test_streaming_matches_batch() {
+        let bars: Vec<Ohlc> = (0..60)
+            .map(|i| {
+                let mid = 50.0 + (i as Float * 0.4).sin() * 5.0 + (i as Float * 1.1).cos();
+                bar(mid + 1.0, mid - 1.0)
+            })
+            .collect();
+        let aroon = Aroon::new(14).unwrap();
+        assert_reset_streaming_matches_batch(aroon, &[bar(1000.0, 0.0)], &bars, 0.0);
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        assert!(matches!(
+            Aroon::new(0),
+            Err(TalibError::InvalidPeriod { .. })
+        ));
+    }
+}
@@ -0,0 +1,136 @@
+//! Implementation of the Chande Momentum Oscillator (CMO).
+
+use super::pair_sum::RollingPairSum;
+use crate::{Float, Indicator, Resettable, Result, TalibError};
+
+/// Chande Momentum Oscillator
+///
+/// Compares the summed gains and losses of the last `period` price changes directly,
+/// rather than through smoothed averages as RSI does:
+///
+/// ```text
+/// CMO = 100 * (sum_up - sum_down) / (sum_up + sum_down)
+/// ```
+///
+/// The output is bounded to `[-100, 100]`, and a flat window reads 0. As the first
+/// price has no change, the first output is on index `period`.
+#[derive(Debug, Clone)]
+pub struct CMO {
+    period: usize,
+    changes: RollingPairSum,
+    prev: Option<Float>,
+}
+
+impl CMO {
+    /// Create a new Chande Momentum Oscillator over `period` changes, commonly 14.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if `period` is zero.
+    pub fn new(period: usize) -> Result<Self> {
+        if period == 0 {
+            return Err(TalibError::invalid_period(
+                period,
+                "period must be greater than zero",
+            ));
+        }
+        Ok(Self {
+            period,
+            changes: RollingPairSum::new(period),
+            prev: None,
+        })
+    }
+}
+
+impl Indicator for CMO {
+    type Input = Float;
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        format!("CMO({})", self.period)
+    }
+
+    fn lookback(&self) -> usize {
+        self.period
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut cmo = Self::new(self.period)?;
+        Ok(inputs.iter().map(|&x| cmo.next(x)).collect())
+    }
+
+    fn next(&mut self, input: Float) -> Float {
+        let Some(prev) = self.prev.replace(input) else {
+            return Float::NAN;
+        };
+        let change = input - prev;
+        let Some((up, down)) = self.changes.push((change.max(0.0), (-change).max(0.0))) else {
+            return Float::NAN;
+        };
+
+        let total = up + down;
+        if total <= 0.0 {
+            0.0
+        } else {
+            (100.0 * (up - down) / total).clamp(-100.0, 100.0)
+        }
+    }
+}
+
+impl Resettable for CMO {
+    fn reset(&mut self) {
+        self.changes.reset();
+        self.prev = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_reset_streaming_matches_batch;
+
+    #[test]
+    fn test_monotonic_and_flat_series() {
+        let rising: Vec<Float> = (0..10).map(|i| 10.0 + 0.5 * i as Float).collect();
+        let cmo = CMO::new(4).unwrap();
+        assert_eq!(cmo.lookback(), 4);
+        let out = cmo.compute_to_vec(&rising).unwrap();
+        assert!(out[..4].iter().all(|v| v.is_nan()));
+        assert!(out[4..].iter().all(|&v| v == 100.0));
+
+        let falling: Vec<Float> = rising.iter().rev().copied().collect();
+        let out = CMO::new(4).unwrap().compute_to_vec(&falling).unwrap();
+        assert!(out[4..].iter().all(|&v| v == -100.0));
+
+        let out = CMO::new(4).unwrap().compute_to_vec(&[7.0; 8]).unwrap();
+        assert!(out[4..].iter().all(|&v| v == 0.0));
+    }
+
+    #[test]
+    fn test_oscillating_series() {
+        // Changes: +2, -1, +2, -1
+        let inputs = [10.0, 12.0, 11.0, 13.0, 12.0];
+        let out = CMO::new(3).unwrap().compute_to_vec(&inputs).unwrap();
+        assert!(out[..3].iter().all(|v| v.is_nan()));
+        assert!((out[3] - 100.0 * 3.0 / 5.0).abs() < 1e-10);
+        assert!(out[4].abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_streaming_matches_batch() {
+        let inputs: Vec<Float> = (0..60)
+            .map(|i| 50.0 + (i as Float * 0.3).sin() * 4.0 + (i as Float * 1.3).cos())
+            .collect();
+        let cmo = CMO::new(14).unwrap();
+        let streamed = assert_reset_streaming_matches_batch(cmo, &[500.0, -500.0], &inputs, 1e-9);
+        assert!(streamed
+            .iter()
+            .all(|v| v.is_nan() || (-100.0..=100.0).contains(v)));
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        assert!(matches!(CMO::new(0), Err(TalibError::InvalidPeriod { .. })));
+    }
+}
@@ -0,0 +1,155 @@
+//! Implementation of the Moving Average Convergence Divergence (MACD) indicator.
+
+use crate::{smoothing::EmaStage, Float, Indicator, Resettable, Result, TalibError};
+
+/// MACD indicator
+///
+/// Produces `[macd, signal, histogram]` per input:
+///
+/// ```text
+/// macd      = EMA(price, fast) - EMA(price, slow)
+/// signal    = EMA(macd, signal_period)
+/// histogram = macd - signal
+/// ```
+///
+/// Each EMA is seeded with the SMA of its first `period` inputs. All three values
+/// are `Float::NAN` until the signal EMA is warm.
+#[derive(Debug, Clone)]
+pub struct MACD {
+    fast_period: usize,
+    slow_period: usize,
+    signal_period: usize,
+    fast: EmaStage,
+    slow: EmaStage,
+    signal: EmaStage,
+}
+
+impl MACD {
+    /// Create a new MACD, commonly `MACD::new(12, 26, 9)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if any period is zero and
+    /// `TalibError::InvalidParameter` unless `fast_period < slow_period`.
+    pub fn new(fast_period: usize, slow_period: usize, signal_period: usize) -> Result<Self> {
+        validate_periods(fast_period, slow_period, signal_period)?;
+        Ok(Self {
+            fast_period,
+            slow_period,
+            signal_period,
+            fast: EmaStage::new(fast_period),
+            slow: EmaStage::new(slow_period),
+            signal: EmaStage::new(signal_period),
+        })
+    }
+}
+
+/// Check the periods of a fast/slow/signal EMA oscillator such as MACD or PPO.
+pub(super) fn validate_periods(
+    fast_period: usize,
+    slow_period: usize,
+    signal_period: usize,
+) -> Result<()> {
+    for period in [fast_period, slow_period, signal_period] {
+        if period == 0 {
+            return Err(TalibError::invalid_period(
+                period,
+                "periods must be greater than zero",
+            ));
+        }
+    }
+    if fast_period >= slow_period {
+        return Err(TalibError::invalid_parameter(
+            "fast_period".to_string(),
+            fast_period.to_string(),
+            format!("less than slow_period ({})", slow_period),
+        ));
+    }
+    Ok(())
+}
+
+impl Indicator<3> for MACD {
+    type Input = Float;
+
+    type Output = [Float; 3];
+
+    fn name(&self) -> String {
+        format!(
+            "MACD({},{},{})",
+            self.fast_period, self.slow_period, self.signal_period
+        )
+    }
+
+    fn lookback(&self) -> usize {
+        (self.slow_period - 1) + (self.signal_period - 1)
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut macd = Self::new(self.fast_period, self.slow_period, self.signal_period)?;
+        Ok(inputs.iter().map(|&x| macd.next(x)).collect())
+    }
+
+    fn next(&mut self, input: Float) -> [Float; 3] {
+        let fast = self.fast.next(input);
+        let slow = self.slow.next(input);
+        if slow.is_nan() {
+            return [Float::NAN; 3];
+        }
+        let line = fast - slow;
+        let signal = self.signal.next(line);
+        if signal.is_nan() {
+            return [Float::NAN; 3];
+        }
+        [line, signal, line - signal]
+    }
+}
+
+impl Resettable for MACD {
+    fn reset(&mut self) {
+        self.fast = EmaStage::new(self.fast_period);
+        self.slow = EmaStage::new(self.slow_period);
+        self.signal = EmaStage::new(self.signal_period);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_reset_streaming_matches_batch;
+
+    fn prices() -> Vec<Float> {
+        (0..120)
+            .map(|i| 50.0 + (i as Float * 0.21).sin() * 6.0 + i as Float * 0.1)
+            .collect()
+    }
+
+    #[test]
+    fn test_histogram_is_line_minus_signal() {
+        let macd = MACD::new(12, 26, 9).unwrap();
+        assert_eq!(macd.lookback(), 33);
+        let out = macd.compute_to_vec(&prices()).unwrap();
+        assert!(out[..33].iter().all(|v| v.iter().all(|x| x.is_nan())));
+        for [line, signal, histogram] in &out[33..] {
+            assert!(line.is_finite() && signal.is_finite());
+            assert_eq!(*histogram, line - signal);
+        }
+    }
+
+    #[test]
+    fn test_streaming_matches_batch() {
+        let macd = MACD::new(3, 6, 4).unwrap();
+        assert_reset_streaming_matches_batch(macd, &[1000.0], &prices(), 0.0);
+    }
+
+    #[test]
+    fn test_invalid_periods() {
+        assert!(matches!(
+            MACD::new(26, 12, 9),
+            Err(TalibError::InvalidParameter { .. })
+        ));
+        assert!(matches!(
+            MACD::new(12, 26, 0),
+            Err(TalibError::InvalidPeriod { .. })
+        ));
+    }
+}
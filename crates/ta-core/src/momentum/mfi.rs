@@ -0,0 +1,166 @@
+//! Implementation of the Money Flow Index (MFI).
+
+use super::pair_sum::RollingPairSum;
+use crate::{Float, Indicator, Ohlc, Resettable, Result, TalibError};
+
+/// Money Flow Index
+///
+/// A volume-weighted RSI. Each bar's raw money flow is `typical_price * volume`,
+/// with `typical_price = (high + low + close) / 3`, and counts as positive or
+/// negative by whether the typical price rose or fell from the previous bar
+/// (unchanged bars count as neither). Over the last `period` flows:
+///
+/// ```text
+/// MFI = 100 - 100 / (1 + positive_flow / negative_flow)
+/// ```
+///
+/// No negative flow reads 100. As in TA-Lib, a window whose total flow is below 1
+/// reads 0. As the first bar has no direction, the first output is on index `period`.
+#[derive(Debug, Clone)]
+pub struct MFI {
+    period: usize,
+    flows: RollingPairSum,
+    prev_typical: Option<Float>,
+}
+
+impl MFI {
+    /// Create a new Money Flow Index over `period` bars, commonly 14.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if `period` is zero.
+    pub fn new(period: usize) -> Result<Self> {
+        if period == 0 {
+            return Err(TalibError::invalid_period(
+                period,
+                "period must be greater than zero",
+            ));
+        }
+        Ok(Self {
+            period,
+            flows: RollingPairSum::new(period),
+            prev_typical: None,
+        })
+    }
+}
+
+impl Indicator for MFI {
+    type Input = Ohlc;
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        format!("MFI({})", self.period)
+    }
+
+    fn lookback(&self) -> usize {
+        self.period
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut mfi = Self::new(self.period)?;
+        Ok(inputs.iter().map(|&bar| mfi.next(bar)).collect())
+    }
+
+    fn next(&mut self, input: Ohlc) -> Float {
+        let typical = (input.high + input.low + input.close) / 3.0;
+        let Some(prev) = self.prev_typical.replace(typical) else {
+            return Float::NAN;
+        };
+        let flow = typical * input.volume;
+        let entry = if typical > prev {
+            (flow, 0.0)
+        } else if typical < prev {
+            (0.0, flow)
+        } else {
+            (0.0, 0.0)
+        };
+        let Some((positive, negative)) = self.flows.push(entry) else {
+            return Float::NAN;
+        };
+
+        let total = positive + negative;
+        if total < 1.0 {
+            0.0
+        } else {
+            (100.0 * positive / total).clamp(0.0, 100.0)
+        }
+    }
+}
+
+impl Resettable for MFI {
+    fn reset(&mut self) {
+        self.flows.reset();
+        self.prev_typical = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_reset_streaming_matches_batch;
+
+    /// A bar whose typical price is `price`
+    fn bar(price: Float, volume: Float) -> Ohlc {
+        Ohlc {
+            open: price,
+            high: price + 1.0,
+            low: price - 1.0,
+            close: price,
+            volume,
+        }
+    }
+
+    #[test]
+    fn test_hand_computed_fixture() {
+        let bars = [
+            bar(10.0, 100.0),
+            bar(11.0, 200.0), // +2200
+            bar(10.5, 150.0), // -1575
+            bar(12.0, 300.0), // +3600
+            bar(11.5, 250.0), // -2875
+        ];
+        let mfi = MFI::new(3).unwrap();
+        assert_eq!(mfi.lookback(), 3);
+        let out = mfi.compute_to_vec(&bars).unwrap();
+        assert!(out[..3].iter().all(|v| v.is_nan()));
+        assert!((out[3] - 100.0 * 5800.0 / 7375.0).abs() < 1e-10);
+        assert!((out[4] - 100.0 * 3600.0 / 8050.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_one_sided_and_flat_flow() {
+        let rising: Vec<Ohlc> = (0..6).map(|i| bar(10.0 + i as Float, 100.0)).collect();
+        let out = MFI::new(3).unwrap().compute_to_vec(&rising).unwrap();
+        assert!(out[3..].iter().all(|&v| v == 100.0));
+
+        let flat = vec![bar(10.0, 100.0); 6];
+        let out = MFI::new(3).unwrap().compute_to_vec(&flat).unwrap();
+        assert!(out[3..].iter().all(|&v| v == 0.0));
+
+        // TA-Lib also reads 0 while the total flow is below 1
+        let thin: Vec<Ohlc> = (0..6).map(|i| bar(10.0 + i as Float, 0.01)).collect();
+        let out = MFI::new(3).unwrap().compute_to_vec(&thin).unwrap();
+        assert!(out[3..].iter().all(|&v| v == 0.0));
+    }
+
+    #[test]
+    fn test_streaming_matches_batch() {
+        let bars: Vec<Ohlc> = (0..60)
+            .map(|i| {
+                let price = 50.0 + (i as Float * 0.3).sin() * 4.0 + (i as Float * 1.3).cos();
+                bar(price, 1000.0 + (i as Float * 0.7).sin() * 300.0)
+            })
+            .collect();
+        let mfi = MFI::new(14).unwrap();
+        let streamed = assert_reset_streaming_matches_batch(mfi, &[bar(500.0, 1e6)], &bars, 1e-9);
+        assert!(streamed
+            .iter()
+            .all(|v| v.is_nan() || (0.0..=100.0).contains(v)));
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        assert!(matches!(MFI::new(0), Err(TalibError::InvalidPeriod { .. })));
+    }
+}
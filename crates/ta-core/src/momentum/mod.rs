@@ -0,0 +1,27 @@
+//! Momentum indicators.
+//! This module groups together oscillators that measure the rate of price change or trend
+//! strength, like ADX, Aroon, the Chande Momentum Oscillator, MACD, the Money Flow Index,
+//! PPO, RSI, the Stochastic oscillator, TSI and Williams %R.
+
+mod adx;
+mod aroon;
+mod cmo;
+mod macd;
+mod mfi;
+mod pair_sum;
+mod ppo;
+mod rsi;
+mod stochastic;
+mod tsi;
+mod willr;
+
+pub use adx::ADX;
+pub use aroon::Aroon;
+pub use cmo::CMO;
+pub use macd::MACD;
+pub use mfi::MFI;
+pub use ppo::PPO;
+pub use rsi::RSI;
+pub use stochastic::Stochastic;
+pub use tsi::TSI;
+pub use willr::WilliamsR;
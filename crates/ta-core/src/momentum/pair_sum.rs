@@ -0,0 +1,53 @@
+//! Rolling sums of `(positive, negative)` pairs shared by CMO and MFI.
+
+use crate::Float;
+
+/// Running sums of the last `period` pairs pushed.
+///
+/// Oscillators that weigh an upward quantity against a downward one over a window
+/// push one pair per bar and read both sums back in O(1).
+#[derive(Debug, Clone)]
+pub(super) struct RollingPairSum {
+    pairs: Vec<(Float, Float)>,
+    index: usize,
+    count: usize,
+    sums: (Float, Float),
+}
+
+impl RollingPairSum {
+    pub(super) fn new(period: usize) -> Self {
+        Self {
+            pairs: vec![(0.0, 0.0); period],
+            index: 0,
+            count: 0,
+            sums: (0.0, 0.0),
+        }
+    }
+
+    /// Push a pair; returns both window sums once `period` pairs have been seen.
+    pub(super) fn push(&mut self, pair: (Float, Float)) -> Option<(Float, Float)> {
+        let period = self.pairs.len();
+        let (old_first, old_second) = self.pairs[self.index];
+        self.pairs[self.index] = pair;
+        self.index = (self.index + 1) % period;
+        if self.count < period {
+            self.count += 1;
+            self.sums.0 += pair.0;
+            self.sums.1 += pair.1;
+        } else if self.index == 0 {
+            // Rebuild once per period so the running sums cannot drift
+            self.sums.0 = self.pairs.iter().map(|p| p.0).sum();
+            self.sums.1 = self.pairs.iter().map(|p| p.1).sum();
+        } else {
+            self.sums.0 += pair.0 - old_first;
+            self.sums.1 += pair.1 - old_second;
+        }
+        (self.count == period).then_some(self.sums)
+    }
+
+    pub(super) fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.sums = (0.0, 0.0);
+    }
+}
@@ -0,0 +1,165 @@
+//! Implementation of the Percentage Price Oscillator (PPO).
+
+use super::macd::validate_periods;
+use crate::{smoothing::EmaStage, Float, Indicator, Resettable, Result};
+
+/// Percentage Price Oscillator
+///
+/// MACD expressed as a percentage of the slow EMA, so values are comparable across
+/// instruments with different price levels. Produces `[ppo, signal, histogram]`:
+///
+/// ```text
+/// ppo       = 100 * (EMA(price, fast) - EMA(price, slow)) / EMA(price, slow)
+/// signal    = EMA(ppo, signal_period)
+/// histogram = ppo - signal
+/// ```
+///
+/// Each EMA is seeded with the SMA of its first `period` inputs, as in
+/// [`MACD`](super::MACD). When the slow EMA is zero the previous PPO value is
+/// repeated, or 0 if there is none yet. All three values are `Float::NAN` until
+/// the signal EMA is warm.
+#[derive(Debug, Clone)]
+pub struct PPO {
+    fast_period: usize,
+    slow_period: usize,
+    signal_period: usize,
+    fast: EmaStage,
+    slow: EmaStage,
+    signal: EmaStage,
+    prev_line: Option<Float>,
+}
+
+impl PPO {
+    /// Create a new PPO, commonly `PPO::new(12, 26, 9)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if any period is zero and
+    /// `TalibError::InvalidParameter` unless `fast_period < slow_period`.
+    pub fn new(fast_period: usize, slow_period: usize, signal_period: usize) -> Result<Self> {
+        validate_periods(fast_period, slow_period, signal_period)?;
+        Ok(Self {
+            fast_period,
+            slow_period,
+            signal_period,
+            fast: EmaStage::new(fast_period),
+            slow: EmaStage::new(slow_period),
+            signal: EmaStage::new(signal_period),
+            prev_line: None,
+        })
+    }
+}
+
+impl Indicator<3> for PPO {
+    type Input = Float;
+
+    type Output = [Float; 3];
+
+    fn name(&self) -> String {
+        format!(
+            "PPO({},{},{})",
+            self.fast_period, self.slow_period, self.signal_period
+        )
+    }
+
+    fn lookback(&self) -> usize {
+        (self.slow_period - 1) + (self.signal_period - 1)
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut ppo = Self::new(self.fast_period, self.slow_period, self.signal_period)?;
+        Ok(inputs.iter().map(|&x| ppo.next(x)).collect())
+    }
+
+    fn next(&mut self, input: Float) -> [Float; 3] {
+        let fast = self.fast.next(input);
+        let slow = self.slow.next(input);
+        if slow.is_nan() {
+            return [Float::NAN; 3];
+        }
+        let line = if slow == 0.0 {
+            self.prev_line.unwrap_or(0.0)
+        } else {
+            100.0 * (fast - slow) / slow
+        };
+        self.prev_line = Some(line);
+        let signal = self.signal.next(line);
+        if signal.is_nan() {
+            return [Float::NAN; 3];
+        }
+        [line, signal, line - signal]
+    }
+}
+
+impl Resettable for PPO {
+    fn reset(&mut self) {
+        self.fast = EmaStage::new(self.fast_period);
+        self.slow = EmaStage::new(self.slow_period);
+        self.signal = EmaStage::new(self.signal_period);
+        self.prev_line = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_reset_streaming_matches_batch;
+    use crate::TalibError;
+
+    fn prices() -> Vec<Float> {
+        (0..120)
+            .map(|i| 50.0 + (i as Float * 0.21).sin() * 6.0 + i as Float * 0.1)
+            .collect()
+    }
+
+    #[test]
+    fn test_histogram_is_line_minus_signal() {
+        let ppo = PPO::new(12, 26, 9).unwrap();
+        assert_eq!(ppo.lookback(), 33);
+        let out = ppo.compute_to_vec(&prices()).unwrap();
+        assert!(out[..33].iter().all(|v| v.iter().all(|x| x.is_nan())));
+        for [line, signal, histogram] in &out[33..] {
+            assert!(line.is_finite() && signal.is_finite());
+            assert_eq!(*histogram, line - signal);
+        }
+    }
+
+    #[test]
+    fn test_scale_invariant() {
+        let inputs = prices();
+        let scaled: Vec<Float> = inputs.iter().map(|x| x * 250.0).collect();
+        let ppo = PPO::new(12, 26, 9).unwrap();
+        let a = ppo.compute_to_vec(&inputs).unwrap();
+        let b = ppo.compute_to_vec(&scaled).unwrap();
+        // Scaling only changes rounding, so compare relative to each value's size
+        let close = |p: Float, q: Float| {
+            (p.is_nan() && q.is_nan()) || (p - q).abs() <= 1e3 * Float::EPSILON * p.abs().max(1.0)
+        };
+        for (x, y) in a.iter().zip(&b) {
+            assert!(x.iter().zip(y).all(|(&p, &q)| close(p, q)));
+        }
+    }
+
+    #[test]
+    fn test_zero_slow_ema() {
+        let out = PPO::new(2, 3, 2)
+            .unwrap()
+            .compute_to_vec(&[0.0; 8])
+            .unwrap();
+        assert!(out[4..].iter().all(|&v| v == [0.0; 3]));
+    }
+
+    #[test]
+    fn test_streaming_matches_batch() {
+        let ppo = PPO::new(3, 6, 4).unwrap();
+        assert_reset_streaming_matches_batch(ppo, &[1000.0], &prices(), 0.0);
+    }
+
+    #[test]
+    fn test_invalid_periods() {
+        assert!(matches!(
+            PPO::new(26, 12, 9),
+            Err(TalibError::InvalidParameter { .. })
+        ));
+    }
+}
@@ -0,0 +1,139 @@
+//! Implementation of the Relative Strength Index (RSI) indicator.
+
+use crate::{smoothing::EmaStage, Float, Indicator, Resettable, Result, TalibError};
+
+/// RSI indicator
+///
+/// Wilder's momentum oscillator bounded to `[0, 100]`:
+///
+/// ```text
+/// avg_gain = wilder(max(price[i] - price[i - 1], 0), period)
+/// avg_loss = wilder(max(price[i - 1] - price[i], 0), period)
+/// RSI = 100 - 100 / (1 + avg_gain / avg_loss)
+/// ```
+///
+/// The first averages are simple means of the first `period` price changes; later
+/// ones follow `(prev * (period - 1) + current) / period`, as in TA-Lib. When the
+/// average loss is zero the output is clamped to `100.0`.
+#[derive(Debug, Clone)]
+pub struct RSI {
+    period: usize,
+    prev: Float,
+    has_prev: bool,
+    gain: EmaStage,
+    loss: EmaStage,
+}
+
+impl RSI {
+    /// Create a new RSI with the given period, commonly 14.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if `period` is zero.
+    pub fn new(period: usize) -> Result<Self> {
+        if period == 0 {
+            return Err(TalibError::invalid_period(
+                period,
+                "period must be greater than zero",
+            ));
+        }
+        Ok(Self {
+            period,
+            prev: 0.0,
+            has_prev: false,
+            gain: EmaStage::wilder(period),
+            loss: EmaStage::wilder(period),
+        })
+    }
+}
+
+impl Indicator for RSI {
+    type Input = Float;
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        format!("RSI({})", self.period)
+    }
+
+    fn lookback(&self) -> usize {
+        // One bar for the first price change, then `period` changes to seed
+        self.period
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut rsi = Self::new(self.period)?;
+        Ok(inputs.iter().map(|&x| rsi.next(x)).collect())
+    }
+
+    fn next(&mut self, input: Float) -> Float {
+        if !self.has_prev {
+            self.prev = input;
+            self.has_prev = true;
+            return Float::NAN;
+        }
+        let change = input - self.prev;
+        self.prev = input;
+
+        let avg_gain = self.gain.next(change.max(0.0));
+        let avg_loss = self.loss.next((-change).max(0.0));
+        if avg_gain.is_nan() {
+            return Float::NAN;
+        }
+        if avg_loss == 0.0 {
+            100.0
+        } else {
+            100.0 - 100.0 / (1.0 + avg_gain / avg_loss)
+        }
+    }
+}
+
+impl Resettable for RSI {
+    fn reset(&mut self) {
+        self.has_prev = false;
+        self.gain = EmaStage::wilder(self.period);
+        self.loss = EmaStage::wilder(self.period);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_reset_streaming_matches_batch;
+
+    const CLOSES: [Float; 35] = [
+        44.34, 44.09, 44.15, 43.61, 44.33, 44.83, 45.10, 45.42, 45.84, 46.08, 45.89, 46.03, 45.61,
+        46.28, 46.28, 46.00, 46.03, 46.41, 46.22, 45.64, 46.21, 46.25, 45.71, 46.45, 45.78, 45.35,
+        44.03, 44.29, 44.83, 45.10, 44.67, 43.41, 42.90, 42.36, 42.89,
+    ];
+
+    #[test]
+    fn test_matches_talib_fixture() {
+        // TA-Lib RSI(14) on Wilder's sample closes
+        let expected = [
+            70.4641, 66.2496, 66.4809, 69.3469, 66.2947, 57.9150, 62.8807, 63.2088, 56.0116,
+            62.3399, 54.6710, 50.3868, 40.0194, 42.5278, 47.4438, 49.7579, 46.2642, 37.8727,
+            35.0977, 32.3915, 37.4863,
+        ];
+        let rsi = RSI::new(14).unwrap();
+        assert_eq!(rsi.lookback(), 14);
+        let out = rsi.compute_to_vec(&CLOSES).unwrap();
+        assert!(out[..14].iter().all(|v| v.is_nan()));
+        for (v, e) in out[14..].iter().zip(expected) {
+            assert!((v - e).abs() < 1e-3, "{} != {}", v, e);
+        }
+    }
+
+    #[test]
+    fn test_streaming_matches_batch() {
+        assert_reset_streaming_matches_batch(RSI::new(5).unwrap(), &[100.0], &CLOSES, 0.0);
+    }
+
+    #[test]
+    fn test_no_losses_clamps_to_100() {
+        let inputs: Vec<Float> = (0..10).map(|i| i as Float).collect();
+        let out = RSI::new(3).unwrap().compute_to_vec(&inputs).unwrap();
+        assert!(out[3..].iter().all(|&v| v == 100.0));
+        assert!(matches!(RSI::new(0), Err(TalibError::InvalidPeriod { .. })));
+    }
+}
@@ -0,0 +1,231 @@
+//! Implementation of the Stochastic Oscillator.
+
+use crate::{
+    overlap::SMA,
+    simd::{rolling_max, rolling_min},
+    Float, Indicator, Ohlc, Resettable, Result, TalibError,
+};
+
+/// Stochastic Oscillator
+///
+/// Produces `[%K, %D]` per bar:
+///
+/// ```text
+/// raw %K = 100 * (close - lowest_low) / (highest_high - lowest_low)
+/// %K     = SMA(raw %K, k_slowing)
+/// %D     = SMA(%K, d_period)
+/// ```
+///
+/// The lowest low and highest high are taken over the last `k_period` bars. When that
+/// range is zero the raw %K repeats its previous value, or 50 if there is none yet.
+/// Both outputs are `Float::NAN` until %D is warm.
+#[derive(Debug, Clone)]
+pub struct Stochastic {
+    k_period: usize,
+    k_slowing: usize,
+    d_period: usize,
+    highs: Vec<Float>,
+    lows: Vec<Float>,
+    index: usize,
+    is_full: bool,
+    prev_raw_k: Option<Float>,
+    k_smoother: SMA,
+    d_smoother: SMA,
+}
+
+impl Stochastic {
+    /// Create a new Stochastic Oscillator, commonly `Stochastic::new(14, 3, 3)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if any period is zero.
+    pub fn new(k_period: usize, k_slowing: usize, d_period: usize) -> Result<Self> {
+        for period in [k_period, k_slowing, d_period] {
+            if period == 0 {
+                return Err(TalibError::invalid_period(
+                    period,
+                    "periods must be greater than zero",
+                ));
+            }
+        }
+        Ok(Self {
+            k_period,
+            k_slowing,
+            d_period,
+            highs: vec![0.0; k_period],
+            lows: vec![0.0; k_period],
+            index: 0,
+            is_full: false,
+            prev_raw_k: None,
+            k_smoother: SMA::new(k_slowing),
+            d_smoother: SMA::new(d_period),
+        })
+    }
+
+    fn raw_k(&mut self, close: Float, lowest: Float, highest: Float) -> Float {
+        let range = highest - lowest;
+        let raw = if range == 0.0 {
+            self.prev_raw_k.unwrap_or(50.0)
+        } else {
+            100.0 * (close - lowest) / range
+        };
+        self.prev_raw_k = Some(raw);
+        raw
+    }
+
+    fn smooth(&mut self, raw_k: Float) -> [Float; 2] {
+        let k = self.k_smoother.next(raw_k);
+        if k.is_nan() {
+            return [Float::NAN; 2];
+        }
+        let d = self.d_smoother.next(k);
+        if d.is_nan() {
+            return [Float::NAN; 2];
+        }
+        [k, d]
+    }
+}
+
+impl Indicator<2> for Stochastic {
+    type Input = Ohlc;
+
+    type Output = [Float; 2];
+
+    fn name(&self) -> String {
+        format!(
+            "Stochastic({},{},{})",
+            self.k_period, self.k_slowing, self.d_period
+        )
+    }
+
+    fn lookback(&self) -> usize {
+        (self.k_period - 1) + (self.k_slowing - 1) + (self.d_period - 1)
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut stoch = Self::new(self.k_period, self.k_slowing, self.d_period)?;
+        let mut outputs = vec![[Float::NAN; 2]; inputs.len()];
+        if inputs.len() < self.k_period {
+            return Ok(outputs);
+        }
+        let highs: Vec<Float> = inputs.iter().map(|bar| bar.high).collect();
+        let lows: Vec<Float> = inputs.iter().map(|bar| bar.low).collect();
+        let highest = rolling_max(&highs, self.k_period);
+        let lowest = rolling_min(&lows, self.k_period);
+
+        let start = self.k_period - 1;
+        for (i, out) in outputs.iter_mut().enumerate().skip(start) {
+            let raw = stoch.raw_k(inputs[i].close, lowest[i - start], highest[i - start]);
+            *out = stoch.smooth(raw);
+        }
+        Ok(outputs)
+    }
+
+    fn next(&mut self, input: Ohlc) -> [Float; 2] {
+        self.highs[self.index] = input.high;
+        self.lows[self.index] = input.low;
+        self.index = (self.index + 1) % self.k_period;
+        if self.index == 0 {
+            self.is_full = true;
+        }
+        if !self.is_full {
+            return [Float::NAN; 2];
+        }
+
+        let highest = self.highs.iter().copied().fold(Float::MIN, Float::max);
+        let lowest = self.lows.iter().copied().fold(Float::MAX, Float::min);
+        let raw = self.raw_k(input.close, lowest, highest);
+        self.smooth(raw)
+    }
+}
+
+impl Resettable for Stochastic {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.is_full = false;
+        self.prev_raw_k = None;
+        self.k_smoother = SMA::new(self.k_slowing);
+        self.d_smoother = SMA::new(self.d_period);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_reset_streaming_matches_batch;
+
+    fn bar(high: Float, low: Float, close: Float) -> Ohlc {
+        Ohlc {
+            open: close,
+            high,
+            low,
+            close,
+            volume: 0.0,
+        }
+    }
+
+    fn bars() -> Vec<Ohlc> {
+        vec![
+            bar(10.0, 8.0, 9.0),
+            bar(11.0, 9.0, 10.0),
+            bar(12.0, 10.0, 11.0),
+            bar(11.0, 9.0, 10.5),
+            bar(13.0, 10.0, 12.5),
+            bar(12.0, 11.0, 11.5),
+        ]
+    }
+
+    #[test]
+    fn test_hand_computed_fixture() {
+        // Raw %K over 3 bars: 75, 50, 87.5, 62.5
+        // %K = SMA2: 62.5, 68.75, 75;  %D = SMA2 of %K: 65.625, 71.875
+        let stoch = Stochastic::new(3, 2, 2).unwrap();
+        assert_eq!(stoch.lookback(), 4);
+        let out = stoch.compute_to_vec(&bars()).unwrap();
+        assert!(out[..4].iter().all(|v| v.iter().all(|x| x.is_nan())));
+        let expected = [[68.75, 65.625], [75.0, 71.875]];
+        for (got, want) in out[4..].iter().zip(&expected) {
+            assert!((got[0] - want[0]).abs() < 1e-10);
+            assert!((got[1] - want[1]).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_streaming_matches_batch() {
+        let inputs: Vec<Ohlc> = (0..60)
+            .map(|i| {
+                let mid = 50.0 + (i as Float * 0.3).sin() * 5.0;
+                bar(mid + 1.0, mid - 1.0, mid + (i as Float * 0.7).cos())
+            })
+            .collect();
+        let stoch = Stochastic::new(14, 3, 3).unwrap();
+        assert_reset_streaming_matches_batch(stoch, &[bar(1000.0, 0.0, 500.0)], &inputs, 1e-10);
+    }
+
+    #[test]
+    fn test_zero_range() {
+        // A flat market has no previous %K to repeat, so it reads 50
+        let flat = vec![bar(5.0, 5.0, 5.0); 5];
+        let out = Stochastic::new(2, 1, 1)
+            .unwrap()
+            .compute_to_vec(&flat)
+            .unwrap();
+        assert!(out[0][0].is_nan());
+        assert!(out[1..].iter().all(|v| *v == [50.0, 50.0]));
+
+        // Once the range collapses the last raw %K is held
+        let mut stoch = Stochastic::new(2, 1, 1).unwrap();
+        stoch.next(bar(10.0, 6.0, 8.0));
+        assert_eq!(stoch.next(bar(9.0, 7.0, 9.0)), [75.0, 75.0]);
+        stoch.next(bar(9.0, 9.0, 9.0));
+        assert_eq!(stoch.next(bar(9.0, 9.0, 9.0)), [100.0, 100.0]);
+    }
+
+    #[test]
+    fn test_invalid_periods() {
+        assert!(matches!(
+            Stochastic::new(14, 0, 3),
+            Err(TalibError::InvalidPeriod { .. })
+        ));
+    }
+}
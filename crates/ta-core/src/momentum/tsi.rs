@@ -0,0 +1,161 @@
+//! Implementation of the True Strength Index (TSI) indicator.
+
+use crate::{smoothing::EmaStage, Float, Indicator, Result, TalibError};
+
+/// TSI indicator
+///
+/// Double-smoothed momentum oscillator bounded to `[-100, 100]`:
+///
+/// ```text
+/// momentum = price[i] - price[i - 1]
+/// TSI = 100 * EMA(EMA(momentum, long), short) / EMA(EMA(|momentum|, long), short)
+/// ```
+///
+/// Each EMA is seeded with the SMA of its first `period` inputs. When the smoothed
+/// absolute momentum is zero (a perfectly flat series) the output is `0.0`.
+#[derive(Debug, Clone)]
+pub struct TSI {
+    long_period: usize,
+    short_period: usize,
+    prev: Float,
+    has_prev: bool,
+    num_long: EmaStage,
+    num_short: EmaStage,
+    den_long: EmaStage,
+    den_short: EmaStage,
+}
+
+impl TSI {
+    /// Create a new TSI with the first (`long_period`) and second (`short_period`)
+    /// smoothing periods, commonly 25 and 13.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if either period is zero.
+    pub fn new(long_period: usize, short_period: usize) -> Result<Self> {
+        for period in [long_period, short_period] {
+            if period == 0 {
+                return Err(TalibError::invalid_period(
+                    period,
+                    "smoothing periods must be greater than zero",
+                ));
+            }
+        }
+        Ok(Self {
+            long_period,
+            short_period,
+            prev: 0.0,
+            has_prev: false,
+            num_long: EmaStage::new(long_period),
+            num_short: EmaStage::new(short_period),
+            den_long: EmaStage::new(long_period),
+            den_short: EmaStage::new(short_period),
+        })
+    }
+}
+
+impl Indicator for TSI {
+    type Input = Float;
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        format!("TSI({},{})", self.long_period, self.short_period)
+    }
+
+    fn lookback(&self) -> usize {
+        // One bar for the first momentum value, then the two stacked EMA warm-ups
+        1 + (self.long_period - 1) + (self.short_period - 1)
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        // TSI is recursive, so batch mode drives a fresh copy of the streaming state
+        let mut tsi = Self::new(self.long_period, self.short_period)?;
+        Ok(inputs.iter().map(|&x| tsi.next(x)).collect())
+    }
+
+    fn next(&mut self, input: Float) -> Float {
+        if !self.has_prev {
+            self.prev = input;
+            self.has_prev = true;
+            return Float::NAN;
+        }
+        let momentum = input - self.prev;
+        self.prev = input;
+
+        let first = self.num_long.next(momentum);
+        let first_abs = self.den_long.next(momentum.abs());
+        if first.is_nan() {
+            return Float::NAN;
+        }
+        let numerator = self.num_short.next(first);
+        let denominator = self.den_short.next(first_abs);
+        if numerator.is_nan() {
+            return Float::NAN;
+        }
+        if denominator == 0.0 {
+            0.0
+        } else {
+            100.0 * numerator / denominator
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookback() {
+        let tsi = TSI::new(25, 13).unwrap();
+        assert_eq!(tsi.lookback(), 37);
+        let outputs = tsi.compute_to_vec(&[1.0; 40]).unwrap();
+        assert!(outputs[..37].iter().all(|v| v.is_nan()));
+        assert!(outputs[37..].iter().all(|v| !v.is_nan()));
+    }
+
+    #[test]
+    fn test_bounded_on_trending_series() {
+        let inputs: Vec<Float> = (0..200)
+            .map(|i| 100.0 + i as Float * 0.5 + (i as Float * 0.9).sin() * 3.0)
+            .collect();
+        let outputs = TSI::new(25, 13).unwrap().compute_to_vec(&inputs).unwrap();
+        for &v in &outputs[37..] {
+            assert!((-100.0..=100.0).contains(&v), "out of bounds: {}", v);
+        }
+        // Net uptrend should give a positive reading
+        assert!(outputs[199] > 0.0);
+    }
+
+    #[test]
+    fn test_monotonic_rise_is_100() {
+        let inputs: Vec<Float> = (0..30).map(|i| i as Float * 2.0).collect();
+        let outputs = TSI::new(5, 3).unwrap().compute_to_vec(&inputs).unwrap();
+        for &v in &outputs[7..] {
+            assert!((v - 100.0).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_flat_series_yields_zero() {
+        let outputs = TSI::new(3, 2).unwrap().compute_to_vec(&[5.0; 10]).unwrap();
+        assert!(outputs[4..].iter().all(|&v| v == 0.0));
+    }
+
+    #[test]
+    fn test_streaming_matches_batch() {
+        let inputs: Vec<Float> = (0..80).map(|i| (i as Float * 0.3).sin() * 10.0).collect();
+        let batch = TSI::new(8, 4).unwrap().compute_to_vec(&inputs).unwrap();
+        let mut stream = TSI::new(8, 4).unwrap();
+        for (i, &x) in inputs.iter().enumerate() {
+            let value = stream.next(x);
+            assert!((value.is_nan() && batch[i].is_nan()) || value == batch[i]);
+        }
+    }
+
+    #[test]
+    fn test_invalid_periods() {
+        assert!(TSI::new(0, 13).is_err());
+        assert!(TSI::new(25, 0).is_err());
+    }
+}
@@ -0,0 +1,199 @@
+//! Implementation of the Williams %R oscillator.
+
+#[cfg(not(feature = "std"))]
+use alloc::collections::VecDeque;
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+
+use crate::{
+    simd::{rolling_max, rolling_min, scalar::push_extreme},
+    Float, Indicator, Ohlc, Resettable, Result, TalibError,
+};
+
+/// Williams %R
+///
+/// Where the close sits within the range of the last `period` bars, on a scale from
+/// -100 (at the lowest low) to 0 (at the highest high):
+///
+/// ```text
+/// %R = -100 * (highest_high - close) / (highest_high - lowest_low)
+/// ```
+///
+/// When the range is zero the previous value is repeated, or -50 if there is none
+/// yet. Outputs are clamped to `[-100, 0]`, so a close outside its bar's range
+/// cannot push the oscillator out of bounds. Streaming tracks the window extremes
+/// with monotonic deques, so each `next` is amortized O(1).
+#[derive(Debug, Clone)]
+pub struct WilliamsR {
+    period: usize,
+    count: usize,
+    highs: VecDeque<(usize, Float)>,
+    lows: VecDeque<(usize, Float)>,
+    prev: Option<Float>,
+}
+
+impl WilliamsR {
+    /// Create a new Williams %R over `period` bars, commonly 14.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if `period` is zero.
+    pub fn new(period: usize) -> Result<Self> {
+        if period == 0 {
+            return Err(TalibError::invalid_period(
+                period,
+                "period must be greater than zero",
+            ));
+        }
+        Ok(Self {
+            period,
+            count: 0,
+            highs: VecDeque::with_capacity(period),
+            lows: VecDeque::with_capacity(period),
+            prev: None,
+        })
+    }
+
+    fn value(&mut self, close: Float, lowest: Float, highest: Float) -> Float {
+        let range = highest - lowest;
+        let value = if range == 0.0 {
+            self.prev.unwrap_or(-50.0)
+        } else {
+            (-100.0 * (highest - close) / range).clamp(-100.0, 0.0)
+        };
+        self.prev = Some(value);
+        value
+    }
+}
+
+impl Indicator for WilliamsR {
+    type Input = Ohlc;
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        format!("WilliamsR({})", self.period)
+    }
+
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut willr = Self::new(self.period)?;
+        let mut outputs = vec![Float::NAN; inputs.len()];
+        if inputs.len() < self.period {
+            return Ok(outputs);
+        }
+        let highs: Vec<Float> = inputs.iter().map(|bar| bar.high).collect();
+        let lows: Vec<Float> = inputs.iter().map(|bar| bar.low).collect();
+        let highest = rolling_max(&highs, self.period);
+        let lowest = rolling_min(&lows, self.period);
+
+        let start = self.period - 1;
+        for (i, out) in outputs.iter_mut().enumerate().skip(start) {
+            *out = willr.value(inputs[i].close, lowest[i - start], highest[i - start]);
+        }
+        Ok(outputs)
+    }
+
+    fn next(&mut self, input: Ohlc) -> Float {
+        let index = self.count;
+        self.count += 1;
+        let (_, highest) = push_extreme(&mut self.highs, index, input.high, self.period, |v, x| {
+            v <= x
+        });
+        let (_, lowest) =
+            push_extreme(&mut self.lows, index, input.low, self.period, |v, x| v >= x);
+        if self.count < self.period {
+            return Float::NAN;
+        }
+        self.value(input.close, lowest, highest)
+    }
+}
+
+impl Resettable for WilliamsR {
+    fn reset(&mut self) {
+        self.count = 0;
+        self.highs.clear();
+        self.lows.clear();
+        self.prev = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_reset_streaming_matches_batch;
+
+    fn bar(high: Float, low: Float, close: Float) -> Ohlc {
+        Ohlc {
+            open: close,
+            high,
+            low,
+            close,
+            volume: 0.0,
+        }
+    }
+
+    fn bars() -> Vec<Ohlc> {
+        vec![
+            bar(10.0, 8.0, 9.0),
+            bar(11.0, 9.0, 10.0),
+            bar(12.0, 10.0, 11.0),
+            bar(11.0, 9.0, 10.5),
+            bar(13.0, 10.0, 12.5),
+            bar(12.0, 11.0, 11.5),
+        ]
+    }
+
+    #[test]
+    fn test_hand_computed_fixture() {
+        let willr = WilliamsR::new(3).unwrap();
+        assert_eq!(willr.lookback(), 2);
+        let out = willr.compute_to_vec(&bars()).unwrap();
+        assert!(out[..2].iter().all(|x| x.is_nan()));
+        for (got, want) in out[2..].iter().zip([-25.0, -50.0, -12.5, -37.5]) {
+            assert!((got - want).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_streaming_matches_batch() {
+        let inputs: Vec<Ohlc> = (0..80)
+            .map(|i| {
+                let mid = 20.0 + (i as Float * 0.25).sin() * 4.0;
+                bar(mid + 0.5, mid - 0.5, mid + 0.4 * (i as Float * 0.9).cos())
+            })
+            .collect();
+        let willr = WilliamsR::new(14).unwrap();
+        assert_reset_streaming_matches_batch(willr, &[bar(100.0, 0.0, 50.0)], &inputs, 1e-10);
+    }
+
+    #[test]
+    fn test_zero_range_and_clamping() {
+        let mut willr = WilliamsR::new(2).unwrap();
+        willr.next(bar(5.0, 5.0, 5.0));
+        assert_eq!(willr.next(bar(5.0, 5.0, 5.0)), -50.0);
+
+        willr.next(bar(10.0, 6.0, 8.0));
+        assert_eq!(willr.next(bar(9.0, 7.0, 9.0)), -25.0);
+        willr.next(bar(9.0, 9.0, 9.0));
+        assert_eq!(willr.next(bar(9.0, 9.0, 9.0)), 0.0);
+
+        // A close above the high is clamped rather than read as a positive %R
+        let out = WilliamsR::new(2)
+            .unwrap()
+            .compute_to_vec(&[bar(10.0, 8.0, 9.0), bar(10.0, 8.0, 11.0)])
+            .unwrap();
+        assert_eq!(out[1], 0.0);
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        assert!(matches!(
+            WilliamsR::new(0),
+            Err(TalibError::InvalidPeriod { .. })
+        ));
+    }
+}
@@ -0,0 +1,64 @@
+//! Parallel batch computation over many series.
+
+use rayon::prelude::*;
+
+use crate::{Indicator, Result};
+
+/// Compute `indicator` over every series in parallel on the rayon thread pool.
+///
+/// Each series is handled by its own `compute_to_vec` call, so the results are the
+/// same as computing them one by one and come back in the order of `series`. An
+/// error in one series does not affect the others.
+///
+/// ```rust
+/// use ta_core::overlap::{compute_batch, SMA};
+///
+/// let a = [1.0, 2.0, 3.0];
+/// let b = [4.0, 6.0, 8.0];
+/// let results = compute_batch(&SMA::new(2), &[&a[..], &b[..]]);
+/// assert_eq!(results[1].as_ref().unwrap()[2], 7.0);
+/// ```
+pub fn compute_batch<I, const N: usize>(
+    indicator: &I,
+    series: &[&[I::Input]],
+) -> Vec<Result<Vec<I::Output>>>
+where
+    I: Indicator<N> + Sync,
+    I::Input: Sync,
+    I::Output: Send,
+{
+    series
+        .par_iter()
+        .map(|inputs| indicator.compute_to_vec(inputs))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{overlap::SMA, Float};
+
+    #[test]
+    fn test_matches_sequential() {
+        let series: Vec<Vec<Float>> = (0..100)
+            .map(|s| {
+                (0..250)
+                    .map(|i| 100.0 + ((i + s) as Float * 0.05).sin() * s as Float)
+                    .collect()
+            })
+            .collect();
+        let slices: Vec<&[Float]> = series.iter().map(Vec::as_slice).collect();
+        let sma = SMA::new(20);
+
+        let parallel = compute_batch(&sma, &slices);
+        assert_eq!(parallel.len(), series.len());
+        for (result, inputs) in parallel.into_iter().zip(&series) {
+            let expected = sma.compute_to_vec(inputs).unwrap();
+            let got = result.unwrap();
+            assert!(got
+                .iter()
+                .zip(&expected)
+                .all(|(a, b)| (a.is_nan() && b.is_nan()) || a == b));
+        }
+    }
+}
@@ -0,0 +1,176 @@
+//! Implementation of Bollinger Bands.
+
+use super::SMA;
+use crate::{simd::dispatch, Float, Indicator, Resettable, Result, TalibError};
+
+/// Population variance of `window` around `mean`.
+///
+/// Centers the window into `scratch` and takes its SIMD dot product with itself.
+#[inline]
+fn population_variance(window: &[Float], mean: Float, scratch: &mut [Float]) -> Float {
+    for (centered, &value) in scratch.iter_mut().zip(window) {
+        *centered = value - mean;
+    }
+    dispatch::dot_product(scratch, scratch) / window.len() as Float
+}
+
+/// Bollinger Bands
+///
+/// Produces `[lower, middle, upper]` per input, where `middle` is the SMA of the
+/// last `period` values and the bands sit `num_std_dev` population standard
+/// deviations of the same window either side:
+///
+/// ```text
+/// lower = middle - k * stddev
+/// upper = middle + k * stddev
+/// ```
+#[derive(Debug, Clone)]
+pub struct BollingerBands {
+    period: usize,
+    num_std_dev: Float,
+    middle: SMA,
+    window: Vec<Float>,
+    scratch: Vec<Float>,
+    index: usize,
+}
+
+impl BollingerBands {
+    /// Create new Bollinger Bands, commonly `BollingerBands::new(20, 2.0)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if `period` is zero and
+    /// `TalibError::InvalidParameter` unless `num_std_dev` is finite and positive.
+    pub fn new(period: usize, num_std_dev: Float) -> Result<Self> {
+        if period == 0 {
+            return Err(TalibError::invalid_period(
+                period,
+                "period must be greater than zero",
+            ));
+        }
+        if !(num_std_dev.is_finite() && num_std_dev > 0.0) {
+            return Err(TalibError::invalid_parameter(
+                "num_std_dev".to_string(),
+                num_std_dev.to_string(),
+                "a finite value greater than zero".to_string(),
+            ));
+        }
+        Ok(Self {
+            period,
+            num_std_dev,
+            middle: SMA::new(period),
+            window: vec![0.0; period],
+            scratch: vec![0.0; period],
+            index: 0,
+        })
+    }
+}
+
+impl Indicator<3> for BollingerBands {
+    type Input = Float;
+
+    type Output = [Float; 3];
+
+    fn name(&self) -> String {
+        format!("BollingerBands({},{})", self.period, self.num_std_dev)
+    }
+
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut bands = Self::new(self.period, self.num_std_dev)?;
+        Ok(inputs.iter().map(|&x| bands.next(x)).collect())
+    }
+
+    fn next(&mut self, input: Float) -> [Float; 3] {
+        // Variance does not depend on order, so the ring buffer is used as-is
+        self.window[self.index] = input;
+        self.index = (self.index + 1) % self.period;
+        let middle = self.middle.next(input);
+        if middle.is_nan() {
+            return [Float::NAN; 3];
+        }
+        let width =
+            self.num_std_dev * population_variance(&self.window, middle, &mut self.scratch).sqrt();
+        let row = [middle - width, middle, middle + width];
+        if row.iter().all(|v| v.is_finite()) {
+            row
+        } else {
+            [Float::NAN; 3]
+        }
+    }
+}
+
+impl Resettable for BollingerBands {
+    fn reset(&mut self) {
+        self.middle = SMA::new(self.period);
+        self.index = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bands_symmetric_around_sma() {
+        let inputs: Vec<Float> = (0..60)
+            .map(|i| 30.0 + (i as Float * 0.4).sin() * 3.0)
+            .collect();
+        let sma = SMA::new(10).compute_to_vec(&inputs).unwrap();
+        let out = BollingerBands::new(10, 2.0)
+            .unwrap()
+            .compute_to_vec(&inputs)
+            .unwrap();
+        assert!(out[..9].iter().all(|r| r.iter().all(|v| v.is_nan())));
+        // Rounding error scales with the prices, which stay near 30
+        let tolerance = 1e2 * Float::EPSILON * 30.0;
+        for i in 9..inputs.len() {
+            let [lower, middle, upper] = out[i];
+            assert_eq!(middle, sma[i]);
+            assert!(((upper - middle) - (middle - lower)).abs() < tolerance);
+
+            let window = &inputs[i - 9..=i];
+            let variance: Float = window.iter().map(|x| (x - middle).powi(2)).sum::<Float>() / 10.0;
+            assert!(((upper - middle) - 2.0 * variance.sqrt()).abs() < tolerance);
+        }
+    }
+
+    #[test]
+    fn test_constant_series_has_zero_width() {
+        let out = BollingerBands::new(5, 2.0)
+            .unwrap()
+            .compute_to_vec(&[7.0; 12])
+            .unwrap();
+        for &[lower, middle, upper] in &out[4..] {
+            assert_eq!(middle, 7.0);
+            assert_eq!(lower, 7.0);
+            assert_eq!(upper, 7.0);
+        }
+    }
+
+    #[test]
+    fn test_non_finite_input_gives_whole_nan_row() {
+        for bad in [Float::INFINITY, Float::NAN] {
+            let mut bands = BollingerBands::new(3, 2.0).unwrap();
+            bands.next(1.0);
+            bands.next(2.0);
+            assert!(bands.next(bad).iter().all(|v| v.is_nan()));
+        }
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(matches!(
+            BollingerBands::new(20, 0.0),
+            Err(TalibError::InvalidParameter { .. })
+        ));
+        assert!(BollingerBands::new(20, Float::NAN).is_err());
+        assert!(matches!(
+            BollingerBands::new(0, 2.0),
+            Err(TalibError::InvalidPeriod { .. })
+        ));
+    }
+}
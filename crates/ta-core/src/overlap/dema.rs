@@ -0,0 +1,102 @@
+//! Implementation of the Double Exponential Moving Average (DEMA).
+
+use crate::{smoothing::EmaChain, Float, Indicator, Resettable, Result, TalibError};
+
+/// DEMA indicator
+///
+/// Cancels most of an EMA's lag by subtracting the EMA of the EMA:
+///
+/// ```text
+/// dema = 2 * EMA(price) - EMA(EMA(price))
+/// ```
+///
+/// Both EMAs use `period` and are seeded with an SMA, so the first output needs
+/// two warm-ups back to back and `lookback() == 2 * (period - 1)`.
+#[derive(Debug, Clone)]
+pub struct DEMA {
+    period: usize,
+    chain: EmaChain<2>,
+}
+
+impl DEMA {
+    /// Create a new DEMA with the given period.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if `period` is zero.
+    pub fn new(period: usize) -> Result<Self> {
+        if period == 0 {
+            return Err(TalibError::invalid_period(
+                period,
+                "period must be greater than zero",
+            ));
+        }
+        Ok(Self {
+            period,
+            chain: EmaChain::new(period),
+        })
+    }
+}
+
+impl Indicator for DEMA {
+    type Input = Float;
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        format!("DEMA({})", self.period)
+    }
+
+    fn lookback(&self) -> usize {
+        2 * (self.period - 1)
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut dema = Self::new(self.period)?;
+        Ok(inputs.iter().map(|&x| dema.next(x)).collect())
+    }
+
+    fn next(&mut self, input: Float) -> Float {
+        let [ema, ema2] = self.chain.next(input);
+        2.0 * ema - ema2
+    }
+}
+
+impl Resettable for DEMA {
+    fn reset(&mut self) {
+        self.chain = EmaChain::new(self.period);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::overlap::EMA;
+    use crate::test_util::assert_reset_streaming_matches_batch;
+
+    #[test]
+    fn test_lookback() {
+        let dema = DEMA::new(5).unwrap();
+        assert_eq!(dema.lookback(), 8);
+        let out = dema.compute_to_vec(&[1.0; 12]).unwrap();
+        assert!(out[..8].iter().all(|x| x.is_nan()));
+        assert!(out[8..].iter().all(|&x| (x - 1.0).abs() < 1e-12));
+    }
+
+    #[test]
+    fn test_tracks_ramp_closer_than_ema() {
+        let ramp: Vec<Float> = (0..40).map(|i| i as Float).collect();
+        let dema = DEMA::new(6).unwrap().compute_to_vec(&ramp).unwrap();
+        let ema = EMA::new(6).unwrap().compute_to_vec(&ramp).unwrap();
+        for i in 10..ramp.len() {
+            assert!((dema[i] - ramp[i]).abs() < (ema[i] - ramp[i]).abs());
+            assert!((dema[i] - ramp[i]).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_streaming_matches_batch() {
+        let inputs: Vec<Float> = (0..50).map(|i| (i as Float * 0.4).sin() * 3.0).collect();
+        assert_reset_streaming_matches_batch(DEMA::new(4).unwrap(), &[100.0], &inputs, 0.0);
+    }
+}
@@ -0,0 +1,278 @@
+//! Implementation of the Exponential Moving Average (EMA) indicator.
+
+use crate::{smoothing::EmaStage, Float, Indicator, Resettable, Result, TalibError};
+
+/// EMA indicator
+///
+/// Applies `ema = ema_prev + alpha * (input - ema_prev)`. With [`EMA::new`] the
+/// smoothing factor is `2 / (period + 1)` and, as in TA-Lib, the first output is the
+/// simple average of the first `period` inputs, so `lookback() == period - 1`.
+///
+/// A NaN input resets the state: `next` returns `Float::NAN` and the EMA warms up
+/// again from the following input, instead of carrying NaN forward indefinitely.
+#[derive(Debug, Clone)]
+pub struct EMA {
+    /// Number of inputs averaged to seed the recurrence
+    seed_period: usize,
+    /// Set by [`EMA::with_half_life`], which has no period to report in the name
+    half_life: Option<Float>,
+    alpha: Float,
+    stage: EmaStage,
+}
+
+/// Builder for [`EMA`] with named options
+///
+/// Set exactly one of `period` or `half_life`.
+///
+/// ```rust
+/// use ta_core::overlap::EMA;
+///
+/// let ema = EMA::builder().half_life(1.0).build().unwrap();
+/// assert!((ema.alpha() - 0.5).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct EmaBuilder {
+    period: Option<usize>,
+    half_life: Option<Float>,
+}
+
+impl EmaBuilder {
+    /// Period, giving `alpha = 2 / (period + 1)` and an SMA seed
+    pub fn period(mut self, period: usize) -> Self {
+        self.period = Some(period);
+        self
+    }
+
+    /// Half-life in inputs, as in [`EMA::with_half_life`]
+    pub fn half_life(mut self, half_life: Float) -> Self {
+        self.half_life = Some(half_life);
+        self
+    }
+
+    /// Validate the options and create the EMA.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`EMA::new`] and [`EMA::with_half_life`], plus
+    /// `TalibError::InvalidPeriod` if neither option was set and
+    /// `TalibError::InvalidParameter` if both were.
+    pub fn build(self) -> Result<EMA> {
+        match (self.period, self.half_life) {
+            (Some(period), None) => EMA::new(period),
+            (None, Some(half_life)) => EMA::with_half_life(half_life),
+            (None, None) => Err(TalibError::invalid_period(
+                0,
+                "either period or half_life must be set",
+            )),
+            (Some(period), Some(half_life)) => Err(TalibError::invalid_parameter(
+                "half_life".to_string(),
+                half_life.to_string(),
+                format!("unset when period ({}) is given", period),
+            )),
+        }
+    }
+}
+
+impl EMA {
+    /// Start building an EMA with named options.
+    pub fn builder() -> EmaBuilder {
+        EmaBuilder::default()
+    }
+
+    /// Create a new EMA with the given period.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if `period` is zero.
+    pub fn new(period: usize) -> Result<Self> {
+        if period == 0 {
+            return Err(TalibError::invalid_period(
+                period,
+                "period must be greater than zero",
+            ));
+        }
+        Ok(Self::with_alpha(period, 2.0 / (period as Float + 1.0)))
+    }
+
+    /// Create an EMA whose weights halve every `half_life` inputs.
+    ///
+    /// Uses `alpha = 1 - exp(ln(0.5) / half_life)` and seeds with the first input,
+    /// so there is no warm-up. This matches pandas
+    /// `Series.ewm(halflife=half_life, adjust=False).mean()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidParameter` unless `half_life` is finite and positive.
+    pub fn with_half_life(half_life: Float) -> Result<Self> {
+        if !(half_life.is_finite() && half_life > 0.0) {
+            return Err(TalibError::invalid_parameter(
+                "half_life".to_string(),
+                half_life.to_string(),
+                "a finite value greater than zero".to_string(),
+            ));
+        }
+        let alpha = 1.0 - ((0.5 as Float).ln() / half_life).exp();
+        Ok(Self {
+            half_life: Some(half_life),
+            ..Self::with_alpha(1, alpha)
+        })
+    }
+
+    fn with_alpha(seed_period: usize, alpha: Float) -> Self {
+        Self {
+            seed_period,
+            half_life: None,
+            alpha,
+            stage: EmaStage::with_alpha(seed_period, alpha),
+        }
+    }
+
+    /// The smoothing factor applied to each new input.
+    pub fn alpha(&self) -> Float {
+        self.alpha
+    }
+}
+
+impl Indicator for EMA {
+    type Input = Float;
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        match self.half_life {
+            Some(half_life) => format!("EMA(hl={})", half_life),
+            None => format!("EMA({})", self.seed_period),
+        }
+    }
+
+    fn lookback(&self) -> usize {
+        self.seed_period - 1
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut ema = Self::with_alpha(self.seed_period, self.alpha);
+        Ok(inputs.iter().map(|&x| ema.next(x)).collect())
+    }
+
+    fn next(&mut self, input: Float) -> Float {
+        if input.is_nan() {
+            self.reset();
+            return Float::NAN;
+        }
+        self.stage.next(input)
+    }
+}
+
+impl Resettable for EMA {
+    fn reset(&mut self) {
+        self.stage = EmaStage::with_alpha(self.seed_period, self.alpha);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_half_life_matches_pandas() {
+        // pd.Series([1, 2, 3, 4, 5, 4]).ewm(halflife=1, adjust=False).mean()
+        let ema = EMA::with_half_life(1.0).unwrap();
+        assert!((ema.alpha() - 0.5).abs() < 1e-12);
+        let out = ema.compute_to_vec(&[1.0, 2.0, 3.0, 4.0, 5.0, 4.0]).unwrap();
+        let expected = [1.0, 1.5, 2.25, 3.125, 4.0625, 4.03125];
+        for (v, e) in out.iter().zip(expected) {
+            assert!((v - e).abs() < 1e-12);
+        }
+
+        // halflife=2: alpha = 1 - 0.5^(1/2)
+        let ema = EMA::with_half_life(2.0).unwrap();
+        let alpha = 1.0 - (0.5 as Float).sqrt();
+        assert!((ema.alpha() - alpha).abs() < 1e-12);
+        let out = ema.compute_to_vec(&[10.0, 20.0]).unwrap();
+        assert!((out[1] - (10.0 + alpha * 10.0)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_period_ema_seeds_with_sma() {
+        let mut ema = EMA::new(3).unwrap();
+        assert_eq!(ema.lookback(), 2);
+        assert!(ema.next(1.0).is_nan());
+        assert!(ema.next(2.0).is_nan());
+        assert_eq!(ema.next(3.0), 2.0);
+        assert_eq!(ema.next(6.0), 4.0);
+        ema.reset();
+        assert!(ema.next(1.0).is_nan());
+    }
+
+    #[test]
+    fn test_hand_computed_values() {
+        // alpha = 2 / (4 + 1) = 0.4, seeded with mean(2, 4, 6, 8) = 5
+        let out = EMA::new(4)
+            .unwrap()
+            .compute_to_vec(&[2.0, 4.0, 6.0, 8.0, 10.0, 5.0, 0.0])
+            .unwrap();
+        assert!(out[..3].iter().all(|v| v.is_nan()));
+        let expected = [5.0, 7.0, 6.2, 3.72];
+        for (v, e) in out[3..].iter().zip(expected) {
+            assert!((v - e).abs() < 16.0 * Float::EPSILON * e, "{} != {}", v, e);
+        }
+    }
+
+    #[test]
+    fn test_streaming_matches_batch() {
+        let inputs: Vec<Float> = (0..50)
+            .map(|i| 20.0 + (i as Float * 0.5).sin() * 4.0)
+            .collect();
+        let batch = EMA::new(9).unwrap().compute_to_vec(&inputs).unwrap();
+        let mut stream = EMA::new(9).unwrap();
+        for (&x, expected) in inputs.iter().zip(&batch) {
+            let v = stream.next(x);
+            assert!((v.is_nan() && expected.is_nan()) || v == *expected);
+        }
+    }
+
+    #[test]
+    fn test_nan_input_resets() {
+        let mut ema = EMA::new(2).unwrap();
+        ema.next(1.0);
+        assert_eq!(ema.next(3.0), 2.0);
+        assert!(ema.next(Float::NAN).is_nan());
+        // Warm-up starts over from the next input
+        assert!(ema.next(10.0).is_nan());
+        assert_eq!(ema.next(20.0), 15.0);
+    }
+
+    #[test]
+    fn test_builder() {
+        assert!(matches!(
+            EMA::builder().build(),
+            Err(TalibError::InvalidPeriod { .. })
+        ));
+        assert!(matches!(
+            EMA::builder().period(3).half_life(2.0).build(),
+            Err(TalibError::InvalidParameter { .. })
+        ));
+        let mut ema = EMA::builder().period(3).build().unwrap();
+        assert_eq!(ema.alpha(), 0.5);
+        ema.next(1.0);
+        ema.next(2.0);
+        assert_eq!(ema.next(3.0), 2.0);
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(EMA::new(10).unwrap().name(), "EMA(10)");
+        assert_eq!(EMA::with_half_life(2.5).unwrap().name(), "EMA(hl=2.5)");
+        assert_eq!(
+            EMA::builder().half_life(4.0).build().unwrap().name(),
+            "EMA(hl=4)"
+        );
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(EMA::new(0).is_err());
+        assert!(EMA::with_half_life(0.0).is_err());
+        assert!(EMA::with_half_life(Float::INFINITY).is_err());
+    }
+}
@@ -0,0 +1,154 @@
+//! Implementation of the moving average envelope.
+
+use super::MaKind;
+use crate::{smoothing::MaStage, Float, Indicator, Resettable, Result, TalibError};
+
+/// Moving average envelope
+///
+/// Produces `[lower, basis, upper]` per input, where `basis` is a moving average of
+/// the configured [`MaKind`] and the bands sit a fixed fraction away from it:
+///
+/// ```text
+/// lower = basis * (1 - percent)
+/// upper = basis * (1 + percent)
+/// ```
+///
+/// `percent` is a fraction, so `0.025` gives bands 2.5% either side. A `percent` of
+/// zero collapses both bands onto the basis.
+#[derive(Debug, Clone)]
+pub struct MaEnvelope {
+    period: usize,
+    kind: MaKind,
+    percent: Float,
+    basis: MaStage,
+}
+
+impl MaEnvelope {
+    /// Create a new envelope around a `kind` moving average of `period` values.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if `period` is zero and
+    /// `TalibError::InvalidParameter` unless `percent` is finite and non-negative.
+    pub fn new(period: usize, kind: MaKind, percent: Float) -> Result<Self> {
+        if period == 0 {
+            return Err(TalibError::invalid_period(
+                period,
+                "period must be greater than zero",
+            ));
+        }
+        if !(percent.is_finite() && percent >= 0.0) {
+            return Err(TalibError::invalid_parameter(
+                "percent".to_string(),
+                percent.to_string(),
+                "a finite, non-negative fraction".to_string(),
+            ));
+        }
+        Ok(Self {
+            period,
+            kind,
+            percent,
+            basis: MaStage::new(kind, period),
+        })
+    }
+}
+
+impl Indicator<3> for MaEnvelope {
+    type Input = Float;
+
+    type Output = [Float; 3];
+
+    fn name(&self) -> String {
+        format!(
+            "MaEnvelope({},{:?},{})",
+            self.period, self.kind, self.percent
+        )
+    }
+
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut envelope = Self::new(self.period, self.kind, self.percent)?;
+        Ok(inputs.iter().map(|&x| envelope.next(x)).collect())
+    }
+
+    fn next(&mut self, input: Float) -> [Float; 3] {
+        let basis = self.basis.next(input);
+        if !basis.is_finite() {
+            return [Float::NAN; 3];
+        }
+        [
+            basis * (1.0 - self.percent),
+            basis,
+            basis * (1.0 + self.percent),
+        ]
+    }
+}
+
+impl Resettable for MaEnvelope {
+    fn reset(&mut self) {
+        self.basis = MaStage::new(self.kind, self.period);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::overlap::SMA;
+
+    fn prices() -> Vec<Float> {
+        (0..40)
+            .map(|i| 20.0 + (i as Float * 0.5).sin() * 2.0)
+            .collect()
+    }
+
+    #[test]
+    fn test_basis_is_sma_and_width_scales_with_percent() {
+        let inputs = prices();
+        let sma = SMA::new(5).compute_to_vec(&inputs).unwrap();
+        let narrow = MaEnvelope::new(5, MaKind::Sma, 0.01)
+            .unwrap()
+            .compute_to_vec(&inputs)
+            .unwrap();
+        let wide = MaEnvelope::new(5, MaKind::Sma, 0.03)
+            .unwrap()
+            .compute_to_vec(&inputs)
+            .unwrap();
+        assert!(narrow[..4].iter().all(|r| r.iter().all(|v| v.is_nan())));
+        for i in 4..inputs.len() {
+            // The widths are differences of bands at price level, so is their rounding
+            let tolerance = 16.0 * Float::EPSILON * sma[i];
+            assert!((narrow[i][1] - sma[i]).abs() < tolerance);
+            let narrow_width = narrow[i][2] - narrow[i][0];
+            let wide_width = wide[i][2] - wide[i][0];
+            assert!((narrow_width - 0.02 * sma[i]).abs() < tolerance);
+            assert!((wide_width - 3.0 * narrow_width).abs() < tolerance);
+        }
+    }
+
+    #[test]
+    fn test_zero_percent_collapses_onto_basis() {
+        for kind in [MaKind::Sma, MaKind::Ema, MaKind::Wilder] {
+            let rows = MaEnvelope::new(6, kind, 0.0)
+                .unwrap()
+                .compute_to_vec(&prices())
+                .unwrap();
+            for row in &rows[5..] {
+                assert_eq!(row[0], row[1]);
+                assert_eq!(row[2], row[1]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(MaEnvelope::new(0, MaKind::Ema, 0.02).is_err());
+        assert!(matches!(
+            MaEnvelope::new(10, MaKind::Ema, -0.01),
+            Err(TalibError::InvalidParameter { .. })
+        ));
+        assert!(MaEnvelope::new(10, MaKind::Ema, Float::NAN).is_err());
+    }
+}
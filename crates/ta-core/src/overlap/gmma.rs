@@ -0,0 +1,112 @@
+//! Implementation of the Guppy Multiple Moving Average (GMMA).
+
+use crate::{smoothing::EmaStage, Float, Result};
+
+/// Short-term (trader) group EMA periods
+pub const GMMA_SHORT_PERIODS: [usize; 6] = [3, 5, 8, 10, 12, 15];
+/// Long-term (investor) group EMA periods
+pub const GMMA_LONG_PERIODS: [usize; 6] = [30, 35, 40, 45, 50, 60];
+
+/// GMMA convenience helper
+///
+/// Computes the twelve standard Guppy EMAs in one call: the short-term group
+/// (3, 5, 8, 10, 12, 15) followed by the long-term group (30, 35, 40, 45, 50, 60).
+/// Every series has the same length as the input and is aligned index-for-index
+/// with it; each one carries its own `Float::NAN` warm-up prefix of `period - 1`
+/// values.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GMMA;
+
+impl GMMA {
+    /// Create the GMMA helper.
+    pub fn new() -> Self {
+        GMMA
+    }
+
+    /// All twelve EMA periods, short-term group first.
+    pub fn periods() -> [usize; 12] {
+        let mut periods = [0; 12];
+        periods[..6].copy_from_slice(&GMMA_SHORT_PERIODS);
+        periods[6..].copy_from_slice(&GMMA_LONG_PERIODS);
+        periods
+    }
+
+    /// Compute the twelve EMA series in [`GMMA::periods`] order.
+    pub fn compute(&self, inputs: &[Float]) -> Result<[Vec<Float>; 12]> {
+        let mut stages = Self::periods().map(EmaStage::new);
+        let mut series: [Vec<Float>; 12] =
+            core::array::from_fn(|_| Vec::with_capacity(inputs.len()));
+        // Single pass over the input feeding every stage
+        for &x in inputs {
+            for (stage, out) in stages.iter_mut().zip(series.iter_mut()) {
+                out.push(stage.next(x));
+            }
+        }
+        Ok(series)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Textbook EMA: the SMA of the first `period` values, then
+    /// `ema = alpha * x + (1 - alpha) * ema` with `alpha = 2 / (period + 1)`.
+    fn reference_ema(inputs: &[Float], period: usize) -> Vec<Float> {
+        let alpha = 2.0 / (period as Float + 1.0);
+        let mut out = vec![Float::NAN; inputs.len()];
+        if inputs.len() < period {
+            return out;
+        }
+        let mut ema = inputs[..period].iter().sum::<Float>() / period as Float;
+        out[period - 1] = ema;
+        for (i, &x) in inputs.iter().enumerate().skip(period) {
+            ema = alpha * x + (1.0 - alpha) * ema;
+            out[i] = ema;
+        }
+        out
+    }
+
+    #[test]
+    fn test_hand_computed_short_series() {
+        let inputs = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let series = GMMA::new().compute(&inputs).unwrap();
+        // EMA(3): SMA(1, 2, 3) = 2, then alpha = 1/2 halves the gap to each input
+        let ema3 = &series[0];
+        assert!(ema3[..2].iter().all(|v| v.is_nan()));
+        assert_eq!(&ema3[2..], &[2.0, 3.0, 4.0, 5.0]);
+        // EMA(5): SMA(1..=5) = 3, then 3 + (6 - 3) / 3 = 4
+        let ema5 = &series[1];
+        assert!(ema5[..4].iter().all(|v| v.is_nan()));
+        assert_eq!(ema5[4], 3.0);
+        assert!((ema5[5] - 4.0).abs() < 4.0 * Float::EPSILON);
+        assert!(series[2..].iter().all(|s| s.iter().all(|v| v.is_nan())));
+    }
+
+    #[test]
+    fn test_each_series_matches_reference_ema() {
+        let inputs: Vec<Float> = (0..150)
+            .map(|i| 50.0 + (i as Float * 0.11).sin() * 4.0 + i as Float * 0.05)
+            .collect();
+        let series = GMMA::new().compute(&inputs).unwrap();
+        for (period, values) in GMMA::periods().iter().zip(series.iter()) {
+            let expected = reference_ema(&inputs, *period);
+            assert_eq!(values.len(), inputs.len());
+            for (a, b) in values.iter().zip(expected.iter()) {
+                // The recurrences round differently on values near 60
+                assert!((a.is_nan() && b.is_nan()) || (a - b).abs() < 1e2 * Float::EPSILON * 60.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_series_aligned_by_warm_up() {
+        let series = GMMA::new().compute(&[1.0; 80]).unwrap();
+        for (period, values) in GMMA::periods().iter().zip(series.iter()) {
+            assert!(values[..period - 1].iter().all(|v| v.is_nan()));
+            assert!(values[period - 1..]
+                .iter()
+                .all(|&v| (v - 1.0).abs() < 1e-12));
+        }
+    }
+}
@@ -0,0 +1,162 @@
+//! Implementation of the Hull Moving Average (HMA).
+
+use super::WMA;
+use crate::{Float, Indicator, Resettable, Result, TalibError};
+
+/// HMA indicator
+///
+/// Removes most of a WMA's lag by extrapolating from a faster WMA, then smooths the
+/// result with a short WMA:
+///
+/// ```text
+/// hma = WMA(2 * WMA(price, period / 2) - WMA(price, period), round(sqrt(period)))
+/// ```
+///
+/// `period / 2` rounds down. The outer WMA starts once the slow inner WMA is warm,
+/// so `lookback() == (period - 1) + (round(sqrt(period)) - 1)`.
+#[derive(Debug, Clone)]
+pub struct HMA {
+    fast: WMA,
+    slow: WMA,
+    smooth: WMA,
+}
+
+impl HMA {
+    /// Create a new HMA with the given period.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if `period` is less than 2.
+    pub fn new(period: usize) -> Result<Self> {
+        if period < 2 {
+            return Err(TalibError::invalid_period(
+                period,
+                "period must be at least 2",
+            ));
+        }
+        Ok(Self {
+            fast: WMA::new(period / 2)?,
+            slow: WMA::new(period)?,
+            smooth: WMA::new(Self::smooth_period(period))?,
+        })
+    }
+
+    /// `round(sqrt(period))` in integer arithmetic, so the lookback does not depend
+    /// on the float precision.
+    fn smooth_period(period: usize) -> usize {
+        // Correct the float estimate so `root` is exactly floor(sqrt(period))
+        let mut root = (period as f64).sqrt() as usize;
+        while root * root > period {
+            root -= 1;
+        }
+        while (root + 1) * (root + 1) <= period {
+            root += 1;
+        }
+        // sqrt(period) >= root + 0.5 exactly when period > root * (root + 1)
+        if period > root * (root + 1) {
+            root + 1
+        } else {
+            root.max(1)
+        }
+    }
+}
+
+impl Indicator for HMA {
+    type Input = Float;
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        format!("HMA({})", self.slow.lookback() + 1)
+    }
+
+    fn lookback(&self) -> usize {
+        self.slow.lookback() + self.smooth.lookback()
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut result = vec![Float::NAN; inputs.len()];
+        let start = self.slow.lookback();
+        if inputs.len() <= start {
+            return Ok(result);
+        }
+        let fast = self.fast.compute_to_vec(inputs)?;
+        let slow = self.slow.compute_to_vec(inputs)?;
+        let raw: Vec<Float> = (start..inputs.len())
+            .map(|i| 2.0 * fast[i] - slow[i])
+            .collect();
+        let smoothed = self.smooth.compute_to_vec(&raw)?;
+        result[start..].copy_from_slice(&smoothed);
+        Ok(result)
+    }
+
+    fn next(&mut self, input: Float) -> Float {
+        let fast = self.fast.next(input);
+        let slow = self.slow.next(input);
+        if slow.is_nan() {
+            return Float::NAN;
+        }
+        self.smooth.next(2.0 * fast - slow)
+    }
+}
+
+impl Resettable for HMA {
+    fn reset(&mut self) {
+        self.fast.reset();
+        self.slow.reset();
+        self.smooth.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::overlap::SMA;
+    use crate::test_util::assert_reset_streaming_matches_batch;
+
+    #[test]
+    fn test_lookback() {
+        // round(sqrt(p)) flips at p = k * (k + 1): 20 -> 4, 21 -> 5
+        for (period, lookback) in [(2, 1), (9, 10), (16, 18), (20, 22), (21, 24)] {
+            let hma = HMA::new(period).unwrap();
+            assert_eq!(hma.lookback(), lookback, "period {}", period);
+            let out = hma.compute_to_vec(&[1.0; 40]).unwrap();
+            assert!(out[..lookback].iter().all(|x| x.is_nan()));
+            assert!(out[lookback..]
+                .iter()
+                .all(|&x| (x - 1.0).abs() < 16.0 * Float::EPSILON));
+        }
+    }
+
+    #[test]
+    fn test_lags_less_than_sma_on_ramp() {
+        let ramp: Vec<Float> = (0..60).map(|i| i as Float).collect();
+        let hma = HMA::new(16).unwrap().compute_to_vec(&ramp).unwrap();
+        let sma = SMA::new(16).compute_to_vec(&ramp).unwrap();
+        for i in 18..ramp.len() {
+            // SMA(16) trails a unit ramp by 7.5; HMA(16) by 2/3
+            assert!((ramp[i] - hma[i]) < (ramp[i] - sma[i]));
+            // Rounding scales with the ramp's values, not the lag
+            assert!((ramp[i] - hma[i] - 2.0 / 3.0).abs() < 1e2 * Float::EPSILON * ramp[i]);
+        }
+    }
+
+    #[test]
+    fn test_streaming_matches_batch() {
+        let inputs: Vec<Float> = (0..120)
+            .map(|i| 40.0 + (i as Float * 0.15).sin() * 6.0)
+            .collect();
+        let tolerance = 1e2 * Float::EPSILON * 50.0;
+        assert_reset_streaming_matches_batch(HMA::new(9).unwrap(), &[500.0], &inputs, tolerance);
+    }
+
+    #[test]
+    fn test_period_too_small() {
+        for period in [0, 1] {
+            assert!(matches!(
+                HMA::new(period),
+                Err(TalibError::InvalidPeriod { .. })
+            ));
+        }
+    }
+}
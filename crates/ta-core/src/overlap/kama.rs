@@ -0,0 +1,195 @@
+//! Implementation of the Kaufman Adaptive Moving Average (KAMA).
+
+#[cfg(not(feature = "std"))]
+use alloc::collections::VecDeque;
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
+
+use crate::{Float, Indicator, Resettable, Result, TalibError};
+
+/// Kaufman Adaptive Moving Average
+///
+/// An EMA whose smoothing constant follows the market's efficiency. The efficiency
+/// ratio over the last `period` changes is the net move divided by the path length:
+///
+/// ```text
+/// er   = |price - price[period]| / sum(|price[i] - price[i - 1]|)
+/// sc   = (er * (fast_sc - slow_sc) + slow_sc)^2
+/// kama = kama_prev + sc * (price - kama_prev)
+/// ```
+///
+/// with `fast_sc = 2 / (fast + 1)` and `slow_sc = 2 / (slow + 1)`. A clean trend
+/// (`er` near 1) tracks price almost like an EMA(`fast`), while a choppy market
+/// (`er` near 0) barely moves it. Following TA-Lib, KAMA is seeded with the price
+/// at index `period - 1`, the first output is on index `period`, and a window with
+/// no movement counts as fully efficient. The path length is kept as a running
+/// sum, so each `next` is O(1).
+#[derive(Debug, Clone)]
+pub struct KAMA {
+    period: usize,
+    fast: usize,
+    slow: usize,
+    fast_sc: Float,
+    slow_sc: Float,
+    prices: VecDeque<Float>,
+    noise: Float,
+    efficiency: Float,
+    kama: Float,
+}
+
+impl KAMA {
+    /// Create a new KAMA, commonly `KAMA::new(10, 2, 30)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if any period is zero.
+    pub fn new(period: usize, fast: usize, slow: usize) -> Result<Self> {
+        for p in [period, fast, slow] {
+            if p == 0 {
+                return Err(TalibError::invalid_period(
+                    p,
+                    "periods must be greater than zero",
+                ));
+            }
+        }
+        Ok(Self::with_validated(period, fast, slow))
+    }
+
+    fn with_validated(period: usize, fast: usize, slow: usize) -> Self {
+        Self {
+            period,
+            fast,
+            slow,
+            fast_sc: 2.0 / (fast as Float + 1.0),
+            slow_sc: 2.0 / (slow as Float + 1.0),
+            prices: VecDeque::with_capacity(period + 1),
+            noise: 0.0,
+            efficiency: Float::NAN,
+            kama: Float::NAN,
+        }
+    }
+
+    /// The efficiency ratio used by the latest output, or `Float::NAN` during warm-up.
+    pub fn efficiency_ratio(&self) -> Float {
+        self.efficiency
+    }
+}
+
+impl Default for KAMA {
+    /// `KAMA::new(10, 2, 30)`
+    fn default() -> Self {
+        Self::with_validated(10, 2, 30)
+    }
+}
+
+impl Indicator for KAMA {
+    type Input = Float;
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        format!("KAMA({},{},{})", self.period, self.fast, self.slow)
+    }
+
+    fn lookback(&self) -> usize {
+        self.period
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut kama = Self::with_validated(self.period, self.fast, self.slow);
+        Ok(inputs.iter().map(|&x| kama.next(x)).collect())
+    }
+
+    fn next(&mut self, input: Float) -> Float {
+        if let Some(&last) = self.prices.back() {
+            self.noise += (input - last).abs();
+        }
+        self.prices.push_back(input);
+        if self.prices.len() > self.period + 1 {
+            let old = self.prices.pop_front().unwrap_or(input);
+            self.noise -= (self.prices[0] - old).abs();
+        }
+        if self.prices.len() <= self.period {
+            if self.prices.len() == self.period {
+                self.kama = input;
+            }
+            return Float::NAN;
+        }
+
+        let change = (input - self.prices[0]).abs();
+        self.efficiency = if self.noise <= change || self.noise == 0.0 {
+            1.0
+        } else {
+            change / self.noise
+        };
+        let sc = self.efficiency * (self.fast_sc - self.slow_sc) + self.slow_sc;
+        self.kama += sc * sc * (input - self.kama);
+        self.kama
+    }
+}
+
+impl Resettable for KAMA {
+    fn reset(&mut self) {
+        self.prices.clear();
+        self.noise = 0.0;
+        self.efficiency = Float::NAN;
+        self.kama = Float::NAN;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_reset_streaming_matches_batch;
+
+    #[test]
+    fn test_trending_series_adapts_fast() {
+        let inputs: Vec<Float> = (0..30).map(|i| 100.0 + 2.0 * i as Float).collect();
+        let mut kama = KAMA::default();
+        assert_eq!(kama.lookback(), 10);
+        let fast_sc2 = (2.0 / 3.0 as Float).powi(2);
+        let mut prev = inputs[9];
+        for (i, &x) in inputs.iter().enumerate() {
+            let v = kama.next(x);
+            if i < 10 {
+                assert!(v.is_nan());
+                continue;
+            }
+            assert!((kama.efficiency_ratio() - 1.0).abs() < 1e-12);
+            assert!((v - (prev + fast_sc2 * (x - prev))).abs() < 1e-9);
+            prev = v;
+        }
+        // Steady state lags a linear trend by step * (1 - sc) / sc
+        assert!((inputs[29] - prev - 2.0 * 1.25).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_choppy_series_barely_moves() {
+        let inputs: Vec<Float> = (0..40)
+            .map(|i| if i % 2 == 0 { 100.0 } else { 110.0 })
+            .collect();
+        let out = KAMA::default().compute_to_vec(&inputs).unwrap();
+        // Seeded at 110 (index 9); no net move over 10 changes keeps ER at 0
+        let slow_sc2 = (2.0 / 31.0 as Float).powi(2);
+        assert!((out[10] - (110.0 + slow_sc2 * (100.0 - 110.0))).abs() < 1e-12);
+        assert!(out[10..].iter().all(|&v| (100.0..=110.0).contains(&v)));
+        assert!((out[39] - out[10]).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_streaming_matches_batch() {
+        let inputs: Vec<Float> = (0..80)
+            .map(|i| 50.0 + (i as Float * 0.2).sin() * 8.0 + (i as Float * 1.7).cos())
+            .collect();
+        let kama = KAMA::new(10, 2, 30).unwrap();
+        assert_reset_streaming_matches_batch(kama, &[1e6], &inputs, 1e-9);
+    }
+
+    #[test]
+    fn test_invalid_periods() {
+        assert!(matches!(
+            KAMA::new(10, 0, 30),
+            Err(TalibError::InvalidPeriod { .. })
+        ));
+    }
+}
@@ -0,0 +1,16 @@
+//! Selection of the moving average used inside composite indicators.
+
+/// Moving average used as a smoothing stage
+///
+/// Every kind has a warm-up of `period - 1` values; the exponential kinds are seeded
+/// with the simple average of their first `period` inputs, as in TA-Lib.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MaKind {
+    /// Simple moving average
+    #[default]
+    Sma,
+    /// Exponential moving average, `alpha = 2 / (period + 1)`
+    Ema,
+    /// Wilder's smoothing, an exponential average with `alpha = 1 / period`
+    Wilder,
+}
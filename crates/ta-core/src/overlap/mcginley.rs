@@ -0,0 +1,168 @@
+//! Implementation of the McGinley Dynamic.
+
+use crate::{Float, Indicator, Resettable, Result, TalibError};
+
+/// Default McGinley constant, 60% of the nominal period
+pub const MCGINLEY_DEFAULT_K: Float = 0.6;
+
+/// McGinley Dynamic
+///
+/// A self-adjusting moving average following the recurrence
+///
+/// ```text
+/// md = md_prev + (price - md_prev) / (k * period * (price / md_prev)^4)
+/// ```
+///
+/// The fourth-power ratio speeds the line up when price falls away from it and
+/// slows it down when price runs ahead. The line is seeded with the first price,
+/// so there is no warm-up. If `md_prev` is zero or the adjustment is not finite,
+/// the line snaps to the current price instead.
+///
+/// The recurrence depends on every previous value, so `next` is the primary API and
+/// `compute_to_vec` simply streams a fresh copy over the inputs.
+#[derive(Debug, Clone)]
+pub struct McGinleyDynamic {
+    period: usize,
+    k: Float,
+    value: Option<Float>,
+}
+
+impl McGinleyDynamic {
+    /// Create a McGinley Dynamic with the given nominal `period` and constant `k`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if `period` is zero and
+    /// `TalibError::InvalidParameter` unless `k` is finite and positive.
+    pub fn new(period: usize, k: Float) -> Result<Self> {
+        if period == 0 {
+            return Err(TalibError::invalid_period(
+                period,
+                "period must be greater than zero",
+            ));
+        }
+        if !(k.is_finite() && k > 0.0) {
+            return Err(TalibError::invalid_parameter(
+                "k".to_string(),
+                k.to_string(),
+                "a finite value greater than zero".to_string(),
+            ));
+        }
+        Ok(Self {
+            period,
+            k,
+            value: None,
+        })
+    }
+
+    /// Create a McGinley Dynamic using [`MCGINLEY_DEFAULT_K`].
+    pub fn with_period(period: usize) -> Result<Self> {
+        Self::new(period, MCGINLEY_DEFAULT_K)
+    }
+}
+
+impl Indicator for McGinleyDynamic {
+    type Input = Float;
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        format!("McGinleyDynamic({},{})", self.period, self.k)
+    }
+
+    fn lookback(&self) -> usize {
+        0
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut md = Self::new(self.period, self.k)?;
+        Ok(inputs.iter().map(|&x| md.next(x)).collect())
+    }
+
+    fn next(&mut self, input: Float) -> Float {
+        if !input.is_finite() {
+            return Float::NAN;
+        }
+        let next = match self.value {
+            Some(prev) if prev != 0.0 => {
+                let ratio = input / prev;
+                let step = (input - prev) / (self.k * self.period as Float * ratio.powi(4));
+                if step.is_finite() {
+                    prev + step
+                } else {
+                    input
+                }
+            }
+            _ => input,
+        };
+        self.value = Some(next);
+        next
+    }
+}
+
+impl Resettable for McGinleyDynamic {
+    fn reset(&mut self) {
+        self.value = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recurrence() {
+        let mut md = McGinleyDynamic::new(10, 0.6).unwrap();
+        assert_eq!(md.next(100.0), 100.0);
+        let ratio: Float = 102.0 / 100.0;
+        let expected = 100.0 + 2.0 / (0.6 * 10.0 * ratio.powi(4));
+        assert!((md.next(102.0) - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_hugs_price_tighter_than_ema_in_fast_drop() {
+        // Sharp sell-off after a flat base
+        let prices: Vec<Float> = (0..40)
+            .map(|i| {
+                if i < 10 {
+                    100.0
+                } else {
+                    100.0 * (0.96 as Float).powi(i - 9)
+                }
+            })
+            .collect();
+        let md = McGinleyDynamic::with_period(10)
+            .unwrap()
+            .compute_to_vec(&prices)
+            .unwrap();
+
+        let alpha = 2.0 / 11.0;
+        let mut ema = prices[0];
+        let mut md_error = 0.0;
+        let mut ema_error = 0.0;
+        for (i, &price) in prices.iter().enumerate().skip(1) {
+            ema += alpha * (price - ema);
+            if i >= 10 {
+                md_error += (md[i] - price).abs();
+                ema_error += (ema - price).abs();
+            }
+        }
+        assert!(md_error < ema_error, "{md_error} >= {ema_error}");
+    }
+
+    #[test]
+    fn test_zero_seed_snaps_to_price() {
+        let mut md = McGinleyDynamic::new(5, 0.6).unwrap();
+        assert_eq!(md.next(0.0), 0.0);
+        assert_eq!(md.next(3.0), 3.0);
+        assert!(md.next(Float::NAN).is_nan());
+        assert!(md.next(3.0).is_finite());
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(McGinleyDynamic::new(0, 0.6).is_err());
+        assert!(McGinleyDynamic::new(10, 0.0).is_err());
+        assert!(McGinleyDynamic::new(10, Float::NAN).is_err());
+    }
+}
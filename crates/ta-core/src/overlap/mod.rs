@@ -1,6 +1,38 @@
 //! Contains various technical indicators.
 //! This module groups together different indicator implementations like SMA, EMA, etc.
 
+#[cfg(feature = "parallel")]
+mod batch;
+mod bollinger;
+mod dema;
+mod ema;
+mod envelope;
+mod gmma;
+mod hma;
+mod kama;
+mod kind;
+mod mcginley;
+mod run;
+mod sar;
 mod sma;
+mod tema;
+mod trima;
+mod wma;
 
-pub use sma::SMA;
+#[cfg(feature = "parallel")]
+pub use batch::compute_batch;
+pub use bollinger::BollingerBands;
+pub use dema::DEMA;
+pub use ema::{EmaBuilder, EMA};
+pub use envelope::MaEnvelope;
+pub use gmma::{GMMA, GMMA_LONG_PERIODS, GMMA_SHORT_PERIODS};
+pub use hma::HMA;
+pub use kama::KAMA;
+pub use kind::MaKind;
+pub use mcginley::{McGinleyDynamic, MCGINLEY_DEFAULT_K};
+pub use run::run;
+pub use sar::SAR;
+pub use sma::{Sma, SmaBuilder, SmaState, SMA};
+pub use tema::TEMA;
+pub use trima::TRIMA;
+pub use wma::WMA;
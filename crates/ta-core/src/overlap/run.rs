@@ -0,0 +1,53 @@
+//! One-shot streaming over a slice.
+
+use crate::{IndicatorOutput, IterIndicator};
+
+/// Feed `inputs` through `indicator.next` and collect the valid outputs.
+///
+/// Consumes the indicator, so it is a one-liner for turning a price slice into
+/// post-warm-up values through the streaming path. Unlike `compute_to_vec`, the
+/// result has no warm-up placeholders and starts from the indicator's current
+/// state, which also makes it usable with pre-warmed indicators.
+///
+/// ```rust
+/// use ta_core::overlap::{run, SMA};
+///
+/// assert_eq!(run(SMA::new(3), &[1.0, 2.0, 3.0, 4.0, 5.0]), [2.0, 3.0, 4.0]);
+/// ```
+pub fn run<I, const N: usize>(mut indicator: I, inputs: &[I::Input]) -> Vec<I::Output>
+where
+    I: IterIndicator<N>,
+    I::Input: Copy,
+    I::Output: IndicatorOutput,
+{
+    indicator.iter_valid(inputs).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        overlap::{BollingerBands, SMA},
+        Float, Indicator,
+    };
+
+    #[test]
+    fn test_run_sma() {
+        assert_eq!(
+            run(SMA::new(3), &[1.0, 2.0, 3.0, 4.0, 5.0]),
+            [2.0, 3.0, 4.0]
+        );
+    }
+
+    #[test]
+    fn test_run_matches_compute_to_vec() {
+        let inputs: Vec<Float> = (0..100).map(|i| (i as Float * 0.3).sin() * 4.0).collect();
+        let bands = BollingerBands::new(10, 2.0).unwrap();
+        let batch = bands.compute_to_vec(&inputs).unwrap();
+        let streamed = run(bands, &inputs);
+        assert_eq!(streamed.len(), inputs.len() - 9);
+        for (got, want) in streamed.iter().zip(&batch[9..]) {
+            assert!(got.iter().zip(want).all(|(a, b)| (a - b).abs() < 1e-9));
+        }
+    }
+}
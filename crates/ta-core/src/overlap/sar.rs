@@ -0,0 +1,272 @@
+//! Implementation of the Parabolic SAR (stop and reverse).
+
+use crate::{Float, Indicator, Ohlc, Resettable, Result, TalibError};
+
+/// Parabolic SAR
+///
+/// A trailing stop that follows the trend and flips sides when price crosses it.
+/// While long the stop sits below price and moves toward the extreme point (the
+/// highest high of the trend) by the acceleration factor each bar:
+///
+/// ```text
+/// sar = sar_prev + af * (ep - sar_prev)
+/// ```
+///
+/// `af` starts at `accel_start` and grows by `accel_start` every time a new extreme
+/// is made, up to `accel_max`. The stop never moves inside the last two bars'
+/// range. When a low touches the stop the position reverses: the stop jumps to the
+/// extreme point and the same rules apply mirrored. Shorts work symmetrically.
+///
+/// Following TA-Lib, the initial direction comes from the directional movement of
+/// the first two bars and the first output is on index 1. The indicator is purely
+/// sequential, so batch computation drives `next`.
+#[derive(Debug, Clone)]
+pub struct SAR {
+    accel_start: Float,
+    accel_max: Float,
+    prev_bar: Option<(Float, Float)>,
+    started: bool,
+    is_long: bool,
+    sar: Float,
+    extreme: Float,
+    af: Float,
+}
+
+impl SAR {
+    /// Create a new Parabolic SAR, commonly `SAR::new(0.02, 0.2)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidParameter` unless
+    /// `0 < accel_start <= accel_max` with both values finite.
+    pub fn new(accel_start: Float, accel_max: Float) -> Result<Self> {
+        if !(accel_start.is_finite() && accel_start > 0.0) {
+            return Err(TalibError::invalid_parameter(
+                "accel_start".to_string(),
+                accel_start.to_string(),
+                "finite value greater than 0".to_string(),
+            ));
+        }
+        if !(accel_max.is_finite() && accel_max >= accel_start) {
+            return Err(TalibError::invalid_parameter(
+                "accel_max".to_string(),
+                accel_max.to_string(),
+                format!("finite value of at least accel_start ({})", accel_start),
+            ));
+        }
+        Ok(Self::with_validated(accel_start, accel_max))
+    }
+
+    fn with_validated(accel_start: Float, accel_max: Float) -> Self {
+        Self {
+            accel_start,
+            accel_max,
+            prev_bar: None,
+            started: false,
+            is_long: true,
+            sar: 0.0,
+            extreme: 0.0,
+            af: accel_start,
+        }
+    }
+
+    /// Whether the current position is long (the stop is below price).
+    pub fn is_long(&self) -> bool {
+        self.is_long
+    }
+
+    /// Pick the initial side from the first two bars: short only when the second
+    /// bar's down move exceeds its up move.
+    fn start(&mut self, first: (Float, Float), bar: &Ohlc) {
+        let (first_high, first_low) = first;
+        let up = bar.high - first_high;
+        let down = first_low - bar.low;
+        self.is_long = !(down > 0.0 && down > up);
+        if self.is_long {
+            self.extreme = bar.high;
+            self.sar = first_low;
+        } else {
+            self.extreme = bar.low;
+            self.sar = first_high;
+        }
+        self.af = self.accel_start;
+        self.started = true;
+    }
+}
+
+impl Default for SAR {
+    /// `SAR::new(0.02, 0.2)`
+    fn default() -> Self {
+        Self::with_validated(0.02, 0.2)
+    }
+}
+
+impl Indicator for SAR {
+    type Input = Ohlc;
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        format!("SAR({},{})", self.accel_start, self.accel_max)
+    }
+
+    fn lookback(&self) -> usize {
+        1
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut sar = Self::new(self.accel_start, self.accel_max)?;
+        Ok(inputs.iter().map(|&bar| sar.next(bar)).collect())
+    }
+
+    fn next(&mut self, input: Ohlc) -> Float {
+        let Some(prev) = self.prev_bar.replace((input.high, input.low)) else {
+            return Float::NAN;
+        };
+        // On the first step the bar before is the one that set the extreme point
+        let (prev_high, prev_low) = if self.started {
+            prev
+        } else {
+            self.start(prev, &input);
+            (input.high, input.low)
+        };
+
+        let output;
+        if self.is_long {
+            if input.low <= self.sar {
+                self.is_long = false;
+                output = self.extreme.max(prev_high).max(input.high);
+                self.af = self.accel_start;
+                self.extreme = input.low;
+                self.sar = output + self.af * (self.extreme - output);
+                self.sar = self.sar.max(prev_high).max(input.high);
+            } else {
+                output = self.sar;
+                if input.high > self.extreme {
+                    self.extreme = input.high;
+                    self.af = (self.af + self.accel_start).min(self.accel_max);
+                }
+                self.sar += self.af * (self.extreme - self.sar);
+                self.sar = self.sar.min(prev_low).min(input.low);
+            }
+        } else if input.high >= self.sar {
+            self.is_long = true;
+            output = self.extreme.min(prev_low).min(input.low);
+            self.af = self.accel_start;
+            self.extreme = input.high;
+            self.sar = output + self.af * (self.extreme - output);
+            self.sar = self.sar.min(prev_low).min(input.low);
+        } else {
+            output = self.sar;
+            if input.low < self.extreme {
+                self.extreme = input.low;
+                self.af = (self.af + self.accel_start).min(self.accel_max);
+            }
+            self.sar += self.af * (self.extreme - self.sar);
+            self.sar = self.sar.max(prev_high).max(input.high);
+        }
+        output
+    }
+}
+
+impl Resettable for SAR {
+    fn reset(&mut self) {
+        self.prev_bar = None;
+        self.started = false;
+        self.is_long = true;
+        self.af = self.accel_start;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_reset_streaming_matches_batch;
+
+    fn bar(high: Float, low: Float) -> Ohlc {
+        let close = (high + low) / 2.0;
+        Ohlc {
+            open: close,
+            high,
+            low,
+            close,
+            volume: 0.0,
+        }
+    }
+
+    fn reversal() -> Vec<Ohlc> {
+        vec![
+            bar(10.0, 9.0),
+            bar(11.0, 9.5),
+            bar(12.0, 10.5),
+            bar(13.0, 11.5),
+            bar(14.0, 12.5),
+            bar(13.5, 12.0),
+            bar(12.0, 10.5),
+            bar(11.0, 9.5),
+            bar(10.5, 9.0),
+        ]
+    }
+
+    #[test]
+    fn test_uptrend_to_downtrend_reversal() {
+        // Long from the first low (9); each new high adds 0.02 to af. At index 7 the
+        // low (9.5) crosses the stop, which jumps to the extreme point of the
+        // uptrend (14) and then falls toward the new low: 14 + 0.02 * (9.5 - 14).
+        let sar = SAR::default();
+        assert_eq!(sar.lookback(), 1);
+        let out = sar.compute_to_vec(&reversal()).unwrap();
+        // Rounded to 6 decimals
+        let expected = [
+            9.0, 9.04, 9.1584, 9.388896, 9.757784, 10.097162, 14.0, 13.91,
+        ];
+        assert!(out[0].is_nan());
+        for (got, want) in out[1..].iter().zip(expected) {
+            assert!(
+                (got - want).abs() < 1e-6 + 1e2 * Float::EPSILON * 14.0,
+                "{} vs {}",
+                got,
+                want
+            );
+        }
+    }
+
+    #[test]
+    fn test_stop_stays_on_the_right_side() {
+        let mut sar = SAR::new(0.2, 0.4).unwrap();
+        for b in reversal() {
+            let stop = sar.next(b);
+            if stop.is_nan() {
+                continue;
+            }
+            if sar.is_long() {
+                assert!(stop <= b.low);
+            } else {
+                assert!(stop >= b.high);
+            }
+        }
+        assert!(!sar.is_long());
+    }
+
+    #[test]
+    fn test_streaming_matches_batch() {
+        let inputs: Vec<Ohlc> = (0..60)
+            .map(|i| {
+                let mid = 30.0 + (i as Float * 0.2).sin() * 5.0;
+                bar(mid + 0.6, mid - 0.6)
+            })
+            .collect();
+        let junk = [bar(100.0, 1.0), bar(50.0, 20.0)];
+        assert_reset_streaming_matches_batch(SAR::default(), &junk, &inputs, 0.0);
+    }
+
+    #[test]
+    fn test_invalid_acceleration() {
+        for (start, max) in [(0.0, 0.2), (-0.02, 0.2), (0.3, 0.2), (Float::NAN, 0.2)] {
+            assert!(matches!(
+                SAR::new(start, max),
+                Err(TalibError::InvalidParameter { .. })
+            ));
+        }
+    }
+}
@@ -1,123 +1,596 @@
 //! Implementation of the Simple Moving Average (SMA) indicator.
 
 use crate::{
-    simd::{FastFloat, LANES},
-    Float, Indicator,
+    types::{InvalidPolicy, Real},
+    Float, Indicator, Resettable, Result, StatefulIndicator, TalibError,
 };
 use aligned_vec::AVec;
+use core::mem::MaybeUninit;
 
+#[cfg(feature = "fixed")]
+use crate::types::Fixed;
+
+/// Version tag written as the first byte of [`SMA::to_bytes`].
+const STATE_VERSION: u8 = 2;
+/// Bytes before the float payload: version, float width, period, index, is_full, policy.
+const STATE_HEADER_LEN: usize = 1 + 1 + 8 + 8 + 1 + 1;
+/// Floats stored besides the ring buffer: running sum and last output.
+const STATE_SCALARS: usize = 2;
+const FLOAT_BYTES: usize = core::mem::size_of::<Float>();
+
+/// SIMD sum of the first window, shared by every batch path so they round identically.
 #[inline]
-pub fn compute_sma(inputs: &[Float], period: usize, outputs: &mut [Float]) {
-    let n = inputs.len();
-    let window_size = period;
-    let inv_period = 1.0 / period as Float;
-    let mut window_sum = 0.0;
-    let mut i = 0;
-    while i + LANES <= window_size {
-        let slice = &inputs[i..i + LANES];
-        let chunk = FastFloat::from(slice);
-        window_sum += chunk.reduce_add();
-        i += LANES;
-    }
-    while i < window_size {
-        window_sum += inputs[i];
-        i += 1;
+fn window_sum<T: Real>(window: &[T]) -> T {
+    T::sum(window)
+}
+
+/// Slide the window sum ending at `i - 1` forward to end at `i`.
+///
+/// A NaN or infinity leaving the window would keep poisoning an add/subtract update,
+/// so the sum is rebuilt from the window instead.
+#[inline(always)]
+fn slide_window_sum<T: Real>(sum: T, inputs: &[T], i: usize, period: usize) -> T {
+    let old = inputs[i - period];
+    if old.is_finite() {
+        sum - old + inputs[i]
+    } else {
+        window_sum(&inputs[i + 1 - period..=i])
     }
+}
+
+/// Hand every window average of `inputs` to `write`, with the index it belongs at.
+///
+/// [`compute_sma`] and `compute_uninit` both write through this one sliding-sum loop.
+/// `inputs` must hold at least `period` values.
+#[inline]
+fn for_each_window_average<T: Real>(inputs: &[T], period: usize, mut write: impl FnMut(usize, T)) {
+    let inv_period = T::ONE / T::from_usize(period);
+    let mut sum = window_sum(&inputs[..period]);
     // First window result
-    outputs[window_size - 1] = window_sum * inv_period;
+    write(period - 1, sum * inv_period);
     // Use sliding window technique: subtract old element, add new element
-    for i in window_size..n {
-        window_sum = window_sum - inputs[i - window_size] + inputs[i];
-        outputs[i] = window_sum * inv_period;
+    for i in period..inputs.len() {
+        sum = slide_window_sum(sum, inputs, i, period);
+        write(i, sum * inv_period);
+    }
+}
+
+#[inline]
+pub fn compute_sma<T: Real>(inputs: &[T], period: usize, outputs: &mut [T]) {
+    for_each_window_average(inputs, period, |i, value| outputs[i] = value);
+}
+
+/// Streaming state of an [`SMA`], captured by [`StatefulIndicator::snapshot`]
+///
+/// With the `serde` feature the state can be persisted and later restored into an
+/// SMA of the same period.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SmaState {
+    buffer: Vec<Float>,
+    index: usize,
+    is_full: bool,
+    current_sum: Float,
+    /// `None` while warming up, so formats without NaN (like JSON) can hold it
+    last_output: Option<Float>,
+}
+
+/// Builder for [`SMA`] with named options
+///
+/// ```rust
+/// use ta_core::{overlap::SMA, InvalidPolicy};
+///
+/// let sma = SMA::builder()
+///     .period(20)
+///     .invalid_policy(InvalidPolicy::Hold)
+///     .build()
+///     .unwrap();
+/// assert_eq!(sma.invalid_policy(), InvalidPolicy::Hold);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SmaBuilder {
+    period: Option<usize>,
+    invalid_policy: InvalidPolicy,
+}
+
+impl SmaBuilder {
+    /// Number of values averaged (required)
+    pub fn period(mut self, period: usize) -> Self {
+        self.period = Some(period);
+        self
+    }
+
+    /// Policy `next` applies to non-finite inputs (default `Propagate`)
+    pub fn invalid_policy(mut self, policy: InvalidPolicy) -> Self {
+        self.invalid_policy = policy;
+        self
+    }
+
+    /// Validate the options and create the SMA.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if no period was set or it is zero.
+    pub fn build(self) -> Result<SMA> {
+        let period = self.period.unwrap_or(0);
+        if period == 0 {
+            return Err(TalibError::invalid_period(
+                period,
+                "period must be set and greater than zero",
+            ));
+        }
+        Ok(SMA::with_invalid_policy(period, self.invalid_policy))
     }
 }
-/// SMA indicator
-pub struct SMA {
+
+/// SMA indicator over `f32` or `f64` values
+///
+/// Generic over the element type, so series of both widths can be processed in the
+/// same program, e.g. `Sma::<f32>::new(20)` next to `Sma::<f64>::new(20)`. [`SMA`]
+/// is the instantiation for the crate-wide [`Float`] and adds the methods that are
+/// tied to it, such as serialization and fixed-point computation.
+#[derive(Debug, Clone)]
+pub struct Sma<T> {
     period: usize,
-    inv_period: Float,
+    inv_period: T,
     // 只保留计算必须的原始数据缓冲区
-    buffer: AVec<Float>,
+    buffer: AVec<T>,
     index: usize,
     is_full: bool,
-    current_sum: Float,
+    current_sum: T,
 
     // For performance
     mask: usize,
     is_power_of_two: bool,
+
+    invalid_policy: InvalidPolicy,
+    last_output: T,
 }
 
-impl SMA {
+/// SMA indicator over the crate-wide [`Float`]
+pub type SMA = Sma<Float>;
+
+impl<T: Real> Sma<T> {
     /// Create a new SMA indicator with the given period.
     pub fn new(period: usize) -> Self {
         assert!(period > 0, "Period must be greater than 0");
         let is_power_of_two = period > 0 && (period & (period - 1)) == 0;
-        let inv_period = 1.0 / period as Float;
-        let buffer = AVec::with_capacity(64, period);
+        let inv_period = T::ONE / T::from_usize(period);
+        let mut buffer = AVec::with_capacity(64, period);
+        buffer.resize(period, T::ZERO);
 
-        SMA {
+        Sma {
             period,
             inv_period,
             buffer,
             index: 0,
             is_full: false,
-            current_sum: 0.0,
+            current_sum: T::ZERO,
             mask: if period > 0 { period - 1 } else { 0 },
             is_power_of_two,
+            invalid_policy: InvalidPolicy::Propagate,
+            last_output: T::NAN,
         }
     }
+
+    /// Create a new SMA whose `next` handles non-finite inputs according to `policy`.
+    ///
+    /// With [`InvalidPolicy::Propagate`] (what [`SMA::new`] uses) a non-finite input
+    /// enters the buffer and the running sum like any other value, so every window
+    /// that contains it yields a non-finite output; once it leaves the window the sum
+    /// is rebuilt and outputs recover, matching the batch methods. With
+    /// [`InvalidPolicy::Hold`] the input
+    /// is dropped before it touches the buffer, the write index or the running sum,
+    /// and `next` returns the last value it emitted (NaN if still warming
+    /// up). The window therefore spans the last `period` finite inputs. With
+    /// [`InvalidPolicy::Reset`] the whole window is discarded and `next` returns
+    /// NaN until `period` further inputs have arrived. Batch methods are
+    /// unaffected by the policy.
+    pub fn with_invalid_policy(period: usize, policy: InvalidPolicy) -> Self {
+        let mut sma = Self::new(period);
+        sma.invalid_policy = policy;
+        sma
+    }
+
+    /// The policy `next` applies to non-finite inputs.
+    pub fn invalid_policy(&self) -> InvalidPolicy {
+        self.invalid_policy
+    }
+
+    /// Drop the streaming window, as if no input had been seen yet.
+    fn clear(&mut self) {
+        self.buffer.fill(T::ZERO);
+        self.index = 0;
+        self.is_full = false;
+        self.current_sum = T::ZERO;
+        self.last_output = T::NAN;
+    }
     /// warm up sma state
-    pub fn from_data(period: usize, data: &[Float]) -> Self {
+    pub fn from_data(period: usize, data: &[T]) -> Self {
         let mut sma = Self::new(period);
+        sma.warm_start(data);
+        sma
+    }
+
+    /// Warm the streaming state from historical data.
+    ///
+    /// The history is fed through `next` with the outputs discarded, so once at
+    /// least `period` values have been supplied the next live `next()` call
+    /// returns a valid value instead of NaN. Only the most recent
+    /// `period` values can influence the state, so older history is skipped.
+    pub fn warm_start(&mut self, history: &[T]) {
         // 我们只需要最近的 period 个价格来填充状态
-        let start = data.len().saturating_sub(period);
-        let relevant_prices = &data[start..];
-        for &p in relevant_prices {
-            // 更新 buffer 和 sum
-            sma.buffer[sma.index] = p;
-            sma.current_sum += p;
-
-            // 检查是否填满
-            if !sma.is_full && sma.index == sma.period - 1 {
-                sma.is_full = true;
+        let start = history.len().saturating_sub(self.period);
+        for &p in &history[start..] {
+            self.next(p);
+        }
+    }
+    /// Batch computation that hands outputs to `sink` in fixed-size chunks.
+    ///
+    /// Produces the same values as `compute_to_vec` (including the NaN
+    /// warm-up prefix), but only ever holds `chunk_size` outputs in memory. A single
+    /// buffer is reused for every chunk, so `sink` must copy out anything it keeps.
+    /// The final chunk may be shorter than `chunk_size`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidParameter` if `chunk_size` is zero.
+    pub fn compute_chunked<F: FnMut(&[T])>(
+        &self,
+        inputs: &[T],
+        chunk_size: usize,
+        mut sink: F,
+    ) -> Result<()> {
+        if chunk_size == 0 {
+            return Err(TalibError::invalid_parameter(
+                "chunk_size".to_string(),
+                chunk_size.to_string(),
+                "greater than 0".to_string(),
+            ));
+        }
+        let mut chunk = vec![T::NAN; chunk_size.min(inputs.len())];
+        let mut filled = 0;
+        let mut sum = T::ZERO;
+        for i in 0..inputs.len() {
+            let value = if i + 1 < self.period {
+                T::NAN
+            } else {
+                sum = if i + 1 == self.period {
+                    window_sum(&inputs[..self.period])
+                } else {
+                    slide_window_sum(sum, inputs, i, self.period)
+                };
+                sum * self.inv_period
+            };
+            chunk[filled] = value;
+            filled += 1;
+            if filled == chunk.len() {
+                sink(&chunk);
+                filled = 0;
             }
+        }
+        if filled > 0 {
+            sink(&chunk[..filled]);
+        }
+        Ok(())
+    }
+
+    /// Lazy batch computation yielding one output at a time.
+    ///
+    /// Produces the same values as `compute_to_vec` (including the NaN
+    /// warm-up prefix) without allocating an output vector, so outputs can be folded
+    /// or filtered as they are produced. Each step after the first window is an O(1)
+    /// sliding-sum update. Items are `Result`s to match the fallible batch API; the
+    /// SMA itself never yields an error.
+    pub fn compute_iter<'a>(&'a self, inputs: &'a [T]) -> impl Iterator<Item = Result<T>> + 'a {
+        let mut sum = T::ZERO;
+        (0..inputs.len()).map(move |i| {
+            if i + 1 < self.period {
+                return Ok(T::NAN);
+            }
+            sum = if i + 1 == self.period {
+                window_sum(&inputs[..self.period])
+            } else {
+                slide_window_sum(sum, inputs, i, self.period)
+            };
+            Ok(sum * self.inv_period)
+        })
+    }
+}
+
+impl SMA {
+    /// Start building an SMA with named options.
+    pub fn builder() -> SmaBuilder {
+        SmaBuilder::default()
+    }
+
+    /// Deterministic fixed-point batch SMA
+    ///
+    /// Computes the SMA using integer [`Fixed`] arithmetic, so the output is
+    /// bit-identical across architectures. Unlike `compute_to_vec`, only valid
+    /// outputs are returned (length `valid_output_len(inputs.len())`), since
+    /// fixed-point values have no NaN placeholder.
+    /// Only available with the experimental `fixed` feature.
+    #[cfg(feature = "fixed")]
+    pub fn compute_fixed(&self, inputs: &[Fixed]) -> Vec<Fixed> {
+        if inputs.len() < self.period {
+            return Vec::new();
+        }
+        let mut outputs = Vec::with_capacity(self.valid_output_len(inputs.len()));
+        let mut sum = crate::simd::scalar::sum_fixed(&inputs[..self.period]);
+        outputs.push(sum.div_int(self.period));
+        for i in self.period..inputs.len() {
+            sum += inputs[i];
+            sum -= inputs[i - self.period];
+            outputs.push(sum.div_int(self.period));
+        }
+        outputs
+    }
 
-            // 移动指针
-            if sma.is_power_of_two {
-                sma.index = (sma.index + 1) & sma.mask;
+    /// Zero-copy batch computation that reports overflow instead of emitting it
+    ///
+    /// Writes the same values as `compute_to_vec` into `outputs` (with the
+    /// `Float::NAN` warm-up prefix), but checks the running window sum and every
+    /// output as it goes. This is mainly a safety net for the `f32` build, where
+    /// accumulation can silently saturate to infinity.
+    ///
+    /// # Returns
+    ///
+    /// The number of valid (post warm-up) outputs written.
+    ///
+    /// # Errors
+    ///
+    /// - `TalibError::OutputBufferTooSmall` if `outputs` is shorter than `inputs`
+    /// - `TalibError::ComputationError` naming the first input index at which the
+    ///   accumulator or output became non-finite
+    pub fn compute_checked(&self, inputs: &[Float], outputs: &mut [Float]) -> Result<usize> {
+        if outputs.len() < inputs.len() {
+            return Err(TalibError::output_buffer_too_small(
+                inputs.len(),
+                outputs.len(),
+            ));
+        }
+        outputs[..inputs.len()].fill(Float::NAN);
+        if inputs.len() < self.period {
+            return Ok(0);
+        }
+        let check = |index: usize, sum: Float, value: Float| -> Result<()> {
+            if sum.is_finite() && value.is_finite() {
+                Ok(())
             } else {
-                sma.index = (sma.index + 1) % sma.period;
+                Err(TalibError::computation_error(format!(
+                    "non-finite SMA accumulator at index {}",
+                    index
+                )))
             }
+        };
+
+        let mut sum = window_sum(&inputs[..self.period]);
+        let first = self.period - 1;
+        outputs[first] = sum * self.inv_period;
+        check(first, sum, outputs[first])?;
+        for i in self.period..inputs.len() {
+            sum = sum - inputs[i - self.period] + inputs[i];
+            outputs[i] = sum * self.inv_period;
+            check(i, sum, outputs[i])?;
         }
-        sma
+        Ok(self.valid_output_len(inputs.len()))
+    }
+
+    /// Serialize the streaming state into a fixed little-endian byte layout.
+    ///
+    /// Layout (all integers and floats little-endian):
+    ///
+    /// | offset | size | field |
+    /// |--------|------|-------|
+    /// | 0 | 1 | format version |
+    /// | 1 | 1 | `size_of::<Float>()` |
+    /// | 2 | 8 | period (`u64`) |
+    /// | 10 | 8 | ring buffer index (`u64`) |
+    /// | 18 | 1 | buffer full flag |
+    /// | 19 | 1 | invalid policy (0 `Propagate`, 1 `Hold`, 2 `Reset`) |
+    /// | 20 | `Float` | running sum |
+    /// | ... | `Float` | last output (NaN while warming up) |
+    /// | ... | `period * Float` | ring buffer contents |
+    ///
+    /// This is intended for lightweight checkpointing where `serde` is not available.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes =
+            Vec::with_capacity(STATE_HEADER_LEN + (self.period + STATE_SCALARS) * FLOAT_BYTES);
+        bytes.push(STATE_VERSION);
+        bytes.push(FLOAT_BYTES as u8);
+        bytes.extend_from_slice(&(self.period as u64).to_le_bytes());
+        bytes.extend_from_slice(&(self.index as u64).to_le_bytes());
+        bytes.push(self.is_full as u8);
+        bytes.push(policy_to_byte(self.invalid_policy));
+        bytes.extend_from_slice(&self.current_sum.to_le_bytes());
+        bytes.extend_from_slice(&self.last_output.to_le_bytes());
+        for value in self.buffer.iter() {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Restore an SMA from bytes produced by [`SMA::to_bytes`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidInput` if the buffer is truncated, has trailing
+    /// data, was written by an unknown format version, float width or invalid
+    /// policy, or does not match `period`.
+    pub fn from_bytes(period: usize, bytes: &[u8]) -> Result<Self> {
+        let expected_len = period
+            .checked_add(STATE_SCALARS)
+            .and_then(|floats| floats.checked_mul(FLOAT_BYTES))
+            .and_then(|len| len.checked_add(STATE_HEADER_LEN))
+            .ok_or_else(|| {
+                TalibError::invalid_input(format!(
+                    "SMA state for period {} would overflow usize",
+                    period
+                ))
+            })?;
+        if bytes.len() < STATE_HEADER_LEN {
+            return Err(TalibError::invalid_input(format!(
+                "SMA state truncated: expected {} bytes, got {}",
+                expected_len,
+                bytes.len()
+            )));
+        }
+        if bytes[0] != STATE_VERSION {
+            return Err(TalibError::invalid_input(format!(
+                "unsupported SMA state version {}",
+                bytes[0]
+            )));
+        }
+        if bytes[1] as usize != FLOAT_BYTES {
+            return Err(TalibError::invalid_input(format!(
+                "SMA state float width mismatch: expected {} bytes, got {}",
+                FLOAT_BYTES, bytes[1]
+            )));
+        }
+        let stored_period = read_u64(&bytes[2..10]) as usize;
+        if stored_period != period {
+            return Err(TalibError::invalid_input(format!(
+                "SMA state period mismatch: expected {}, got {}",
+                period, stored_period
+            )));
+        }
+        if bytes.len() != expected_len {
+            return Err(TalibError::invalid_input(format!(
+                "SMA state length mismatch: expected {} bytes, got {}",
+                expected_len,
+                bytes.len()
+            )));
+        }
+        let index = read_u64(&bytes[10..18]) as usize;
+        if index >= period {
+            return Err(TalibError::invalid_input(format!(
+                "SMA state index {} out of range for period {}",
+                index, period
+            )));
+        }
+
+        let invalid_policy = policy_from_byte(bytes[19]).ok_or_else(|| {
+            TalibError::invalid_input(format!("unknown SMA invalid policy {}", bytes[19]))
+        })?;
+
+        let mut sma = Self::with_invalid_policy(period, invalid_policy);
+        sma.index = index;
+        sma.is_full = bytes[18] != 0;
+        let mut floats = bytes[STATE_HEADER_LEN..].chunks_exact(FLOAT_BYTES);
+        // Length was validated above, so the iterator yields exactly period + 2 values
+        sma.current_sum = read_float(floats.next().unwrap_or_default());
+        sma.last_output = read_float(floats.next().unwrap_or_default());
+        for (slot, chunk) in sma.buffer.iter_mut().zip(floats) {
+            *slot = read_float(chunk);
+        }
+        Ok(sma)
     }
 }
 
-impl Indicator for SMA {
-    type Input = Float;
+#[inline]
+fn read_u64(bytes: &[u8]) -> u64 {
+    let mut raw = [0u8; 8];
+    raw.copy_from_slice(bytes);
+    u64::from_le_bytes(raw)
+}
+
+#[inline]
+fn read_float(bytes: &[u8]) -> Float {
+    let mut raw = [0u8; FLOAT_BYTES];
+    raw.copy_from_slice(bytes);
+    Float::from_le_bytes(raw)
+}
+
+fn policy_to_byte(policy: InvalidPolicy) -> u8 {
+    match policy {
+        InvalidPolicy::Propagate => 0,
+        InvalidPolicy::Hold => 1,
+        InvalidPolicy::Reset => 2,
+    }
+}
+
+fn policy_from_byte(byte: u8) -> Option<InvalidPolicy> {
+    match byte {
+        0 => Some(InvalidPolicy::Propagate),
+        1 => Some(InvalidPolicy::Hold),
+        2 => Some(InvalidPolicy::Reset),
+        _ => None,
+    }
+}
 
-    type Output = Float;
+impl<T: Real> Indicator for Sma<T> {
+    type Input = T;
+
+    type Output = T;
+
+    fn name(&self) -> String {
+        format!("SMA({})", self.period)
+    }
 
     fn lookback(&self) -> usize {
         self.period.saturating_sub(1)
     }
 
     fn compute_to_vec(&self, inputs: &[Self::Input]) -> crate::Result<Vec<Self::Output>> {
-        let mut result = vec![Float::NAN; inputs.len()];
-        compute_sma(inputs, self.period, &mut result);
+        let mut result = vec![T::NAN; self.output_len(inputs.len())];
+        if inputs.len() >= self.period {
+            compute_sma(inputs, self.period, &mut result);
+        }
         Ok(result)
     }
 
+    /// Writes the warm-up placeholders and every window average straight into
+    /// `out`, without an intermediate vector or zero-fill.
+    fn compute_uninit(
+        &self,
+        inputs: &[Self::Input],
+        out: &mut [MaybeUninit<Self::Output>],
+    ) -> crate::Result<usize> {
+        let n = inputs.len();
+        if out.len() < n {
+            return Err(TalibError::output_buffer_too_small(n, out.len()));
+        }
+        let warm_up = self.lookback().min(n);
+        for slot in &mut out[..warm_up] {
+            slot.write(T::NAN);
+        }
+        if n < self.period {
+            return Ok(n);
+        }
+        for_each_window_average(inputs, self.period, |i, value| {
+            out[i].write(value);
+        });
+        Ok(n)
+    }
+
     #[inline(always)]
-    fn next(&mut self, input: Float) -> Float {
+    fn next(&mut self, input: T) -> T {
+        // 0. 按策略处理非有限输入：保持上次输出，或清空窗口重新预热
+        if !input.is_finite() {
+            match self.invalid_policy {
+                InvalidPolicy::Propagate => {}
+                InvalidPolicy::Hold => return self.last_output,
+                InvalidPolicy::Reset => {
+                    self.clear();
+                    return T::NAN;
+                }
+            }
+        }
+
         // 1. 获取即将被替换的旧值 (O(1) 访问)
         let old_val = self.buffer[self.index];
 
-        // 2. 更新累加和：加新减旧 (无循环)
-        self.current_sum = self.current_sum - old_val + input;
-
-        // 3. 将新值存入缓冲区
+        // 2. 将新值存入缓冲区
         self.buffer[self.index] = input;
 
+        // 3. 更新累加和：加新减旧 (无循环)；非有限旧值离开窗口时重算，避免其永久污染累加和
+        self.current_sum = if old_val.is_finite() {
+            self.current_sum - old_val + input
+        } else {
+            T::sum(&self.buffer)
+        };
+
         // 4. 检查是否刚填满缓冲区（关键优化：用 bool 替代 usize 计数器）
         if !self.is_full && self.index == self.period - 1 {
             self.is_full = true;
@@ -134,10 +607,624 @@ impl Indicator for SMA {
         }
 
         // 6. 返回结果：使用预计算的倒数进行乘法 (比除法快 10 倍以上)
-        if self.is_full {
+        self.last_output = if self.is_full {
             self.current_sum * self.inv_period
         } else {
-            Float::NAN
+            T::NAN
+        };
+        self.last_output
+    }
+}
+
+impl<T: Real> Resettable for Sma<T> {
+    fn reset(&mut self) {
+        self.clear();
+    }
+}
+
+impl StatefulIndicator for SMA {
+    type State = SmaState;
+
+    fn snapshot(&self) -> SmaState {
+        SmaState {
+            buffer: self.buffer.to_vec(),
+            index: self.index,
+            is_full: self.is_full,
+            current_sum: self.current_sum,
+            last_output: (!self.last_output.is_nan()).then_some(self.last_output),
+        }
+    }
+
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidInput` if `state` was captured from an SMA with a
+    /// different period or its ring buffer index is out of range; `self` is left
+    /// unchanged.
+    fn restore(&mut self, state: SmaState) -> Result<()> {
+        if state.buffer.len() != self.period {
+            return Err(TalibError::invalid_input(format!(
+                "SMA state period mismatch: expected {}, got {}",
+                self.period,
+                state.buffer.len()
+            )));
+        }
+        if state.index >= self.period {
+            return Err(TalibError::invalid_input(format!(
+                "SMA state index {} out of range for period {}",
+                state.index, self.period
+            )));
+        }
+        self.buffer.copy_from_slice(&state.buffer);
+        self.index = state.index;
+        self.is_full = state.is_full;
+        self.current_sum = state.current_sum;
+        self.last_output = state.last_output.unwrap_or(Float::NAN);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_warm_start_makes_first_live_tick_valid() {
+        let mut sma = SMA::new(3);
+        sma.warm_start(&[1.0, 2.0, 3.0]);
+        let value = sma.next(4.0);
+        assert!(!value.is_nan());
+        assert!((value - 3.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_warm_start_short_history_still_warming() {
+        let mut sma = SMA::new(3);
+        sma.warm_start(&[1.0]);
+        assert!(sma.next(2.0).is_nan());
+        assert!((sma.next(3.0) - 2.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_warm_start_matches_streaming_full_history() {
+        let history: Vec<Float> = (1..=50).map(|i| i as Float).collect();
+        let mut warmed = SMA::new(5);
+        warmed.warm_start(&history);
+
+        let mut streamed = SMA::new(5);
+        for &p in &history {
+            streamed.next(p);
+        }
+
+        assert!((warmed.next(51.0) - streamed.next(51.0)).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let mut original = SMA::new(4);
+        for p in [1.0, 2.0, 3.0, 4.0, 5.0, 6.0] {
+            original.next(p);
+        }
+        let bytes = original.to_bytes();
+        assert_eq!(bytes[0], STATE_VERSION);
+
+        let mut restored = SMA::from_bytes(4, &bytes).unwrap();
+        for p in [7.0, 8.0, 9.0] {
+            assert_eq!(original.next(p), restored.next(p));
+        }
+    }
+
+    #[test]
+    fn test_bytes_round_trip_keeps_hold_policy_and_last_output() {
+        let mut original = SMA::with_invalid_policy(3, InvalidPolicy::Hold);
+        for p in [3.0, 6.0, 9.0] {
+            original.next(p);
+        }
+        assert_eq!(original.next(Float::NAN), 6.0);
+
+        let mut restored = SMA::from_bytes(3, &original.to_bytes()).unwrap();
+        assert_eq!(restored.invalid_policy(), InvalidPolicy::Hold);
+        assert_eq!(restored.next(Float::INFINITY), 6.0);
+        assert_eq!(restored.next(12.0), original.next(12.0));
+
+        let mut bad_policy = original.to_bytes();
+        bad_policy[19] = 3;
+        assert!(matches!(
+            SMA::from_bytes(3, &bad_policy),
+            Err(TalibError::InvalidInput { .. })
+        ));
+    }
+
+    #[test]
+    fn test_bytes_round_trip_mid_warm_up() {
+        let mut original = SMA::new(3);
+        original.next(10.0);
+        let mut restored = SMA::from_bytes(3, &original.to_bytes()).unwrap();
+        assert!(restored.next(20.0).is_nan());
+        assert!((restored.next(30.0) - 20.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_from_bytes_truncated_errors() {
+        let bytes = SMA::new(4).to_bytes();
+        for len in [0, 5, bytes.len() - 1] {
+            let result = SMA::from_bytes(4, &bytes[..len]);
+            assert!(matches!(result, Err(TalibError::InvalidInput { .. })));
+        }
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_mismatched_state() {
+        let bytes = SMA::new(4).to_bytes();
+        assert!(SMA::from_bytes(5, &bytes).is_err());
+
+        let mut bad_version = bytes.clone();
+        bad_version[0] = STATE_VERSION + 1;
+        assert!(SMA::from_bytes(4, &bad_version).is_err());
+
+        let mut trailing = bytes;
+        trailing.push(0);
+        assert!(SMA::from_bytes(4, &trailing).is_err());
+    }
+
+    #[test]
+    fn test_from_bytes_huge_period_errors() {
+        let mut bytes = SMA::new(4).to_bytes();
+        bytes[2..10].copy_from_slice(&u64::MAX.to_le_bytes());
+        for period in [usize::MAX, usize::MAX / FLOAT_BYTES] {
+            assert!(matches!(
+                SMA::from_bytes(period, &bytes),
+                Err(TalibError::InvalidInput { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_compute_chunked_reassembles_to_compute_to_vec() {
+        let inputs: Vec<Float> = (0..103).map(|i| (i as Float * 0.37).sin() * 10.0).collect();
+        let sma = SMA::new(9);
+        let expected = sma.compute_to_vec(&inputs).unwrap();
+        for chunk_size in [1, 7, 16, 103, 500] {
+            let mut reassembled = Vec::new();
+            let mut calls = 0;
+            sma.compute_chunked(&inputs, chunk_size, |chunk| {
+                assert!(chunk.len() <= chunk_size);
+                reassembled.extend_from_slice(chunk);
+                calls += 1;
+            })
+            .unwrap();
+            assert_eq!(calls, inputs.len().div_ceil(chunk_size));
+            assert_eq!(reassembled.len(), expected.len());
+            for (a, b) in reassembled.iter().zip(expected.iter()) {
+                assert!(a.to_bits() == b.to_bits() || (a.is_nan() && b.is_nan()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_compute_chunked_rejects_zero_chunk() {
+        let result = SMA::new(3).compute_chunked(&[1.0, 2.0, 3.0], 0, |_| {});
+        assert!(matches!(result, Err(TalibError::InvalidParameter { .. })));
+    }
+
+    #[test]
+    fn test_compute_to_vec_shorter_than_period_is_all_nan() {
+        let outputs = SMA::new(5).compute_to_vec(&[1.0, 2.0, 3.0]).unwrap();
+        assert_eq!(outputs.len(), 3);
+        assert!(outputs.iter().all(|v| v.is_nan()));
+    }
+
+    #[test]
+    fn test_compute_quantized_cent_tick() {
+        let sma = SMA::new(3);
+        let outputs = sma
+            .compute_quantized(&[1.0, 1.1, 1.25, 1.333, 2.0], 0.01)
+            .unwrap();
+        assert!(outputs[..2].iter().all(|v| v.is_nan()));
+        // Raw SMA: 1.1166.., 1.2276.., 1.5276..
+        for (value, expected) in outputs[2..].iter().zip([1.12, 1.23, 1.53]) {
+            assert!(
+                (value - expected).abs() < 1e-12,
+                "{} != {}",
+                value,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_compute_quantized_quarter_tick_halfway() {
+        let sma = SMA::new(2);
+        // Raw SMA: 0.125, 0.375, -0.125, 1.1
+        let outputs = sma
+            .compute_quantized(&[0.0, 0.25, 0.5, -0.75, 2.95], 0.25)
+            .unwrap();
+        assert!(outputs[0].is_nan());
+        assert_eq!(&outputs[1..], &[0.25, 0.5, -0.25, 1.0]);
+    }
+
+    #[test]
+    fn test_compute_map_rounds_to_integers() {
+        let sma = SMA::new(2);
+        let rounded = sma
+            .compute_map(&[1.0, 2.0, 4.0, 7.0], |v| {
+                (!v.is_nan()).then(|| v.round() as i64)
+            })
+            .unwrap();
+        assert_eq!(rounded, vec![None, Some(2), Some(3), Some(6)]);
+    }
+
+    #[test]
+    fn test_compute_quantized_rejects_bad_tick() {
+        let sma = SMA::new(2);
+        for tick in [0.0, -0.01, Float::NAN, Float::INFINITY] {
+            assert!(matches!(
+                sma.compute_quantized(&[1.0, 2.0], tick),
+                Err(TalibError::InvalidParameter { .. })
+            ));
+        }
+    }
+
+    #[cfg(feature = "fixed")]
+    #[test]
+    fn test_compute_fixed_bit_identical() {
+        let inputs: Vec<Fixed> = [1.1, 2.2, 3.3, 4.4, 5.5]
+            .iter()
+            .map(|&x| Fixed::from_float(x))
+            .collect();
+        let outputs = SMA::new(3).compute_fixed(&inputs);
+        assert_eq!(outputs.len(), 3);
+        // The expected bits are derived with plain integer arithmetic, so they hold on
+        // every target regardless of its floating-point unit
+        for (k, output) in outputs.iter().enumerate() {
+            let raw: i128 = inputs[k..k + 3].iter().map(|x| x.to_bits()).sum();
+            assert_eq!(output.to_bits(), raw / 3);
+        }
+        assert!((outputs[0].to_float() - 2.2).abs() < 1e-9);
+        assert!(SMA::new(10).compute_fixed(&inputs).is_empty());
+    }
+
+    #[test]
+    fn test_compute_checked_matches_compute_to_vec() {
+        let inputs: Vec<Float> = (0..50).map(|i| (i as Float * 0.3).sin()).collect();
+        let sma = SMA::new(6);
+        let mut outputs = vec![0.0; inputs.len()];
+        assert_eq!(sma.compute_checked(&inputs, &mut outputs).unwrap(), 45);
+        let expected = sma.compute_to_vec(&inputs).unwrap();
+        for (a, b) in outputs.iter().zip(expected.iter()) {
+            assert!((a.is_nan() && b.is_nan()) || a == b);
+        }
+    }
+
+    #[test]
+    fn test_compute_checked_detects_overflow() {
+        let inputs = [1.0, Float::MAX, Float::MAX, 1.0];
+        let mut outputs = [0.0; 4];
+        let err = SMA::new(2)
+            .compute_checked(&inputs, &mut outputs)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            TalibError::computation_error("non-finite SMA accumulator at index 2")
+        );
+    }
+
+    #[cfg(feature = "f32")]
+    #[test]
+    fn test_compute_checked_detects_f32_saturation() {
+        // Each value fits in f32, but the window sum of 3e38 + 3e38 does not
+        let inputs: [Float; 3] = [3.0e38, 3.0e38, 1.0];
+        let mut outputs = [0.0; 3];
+        let result = SMA::new(2).compute_checked(&inputs, &mut outputs);
+        match result {
+            Err(TalibError::ComputationError { message }) => assert!(message.contains("index 1")),
+            other => panic!("expected overflow error, got {:?}", other),
         }
     }
+
+    #[test]
+    fn test_compute_checked_small_output_buffer() {
+        let mut outputs = [0.0; 2];
+        assert_eq!(
+            SMA::new(2).compute_checked(&[1.0, 2.0, 3.0], &mut outputs),
+            Err(TalibError::output_buffer_too_small(3, 2))
+        );
+    }
+
+    #[test]
+    fn test_compute_checked_exact_output_buffer() {
+        let mut outputs = [0.0; 3];
+        assert_eq!(
+            SMA::new(2).compute_checked(&[1.0, 2.0, 3.0], &mut outputs),
+            Ok(2)
+        );
+        assert!(outputs[0].is_nan());
+        assert_eq!(&outputs[1..], &[1.5, 2.5]);
+    }
+
+    #[test]
+    fn test_from_data_uses_warm_start() {
+        let mut sma = SMA::from_data(4, &[1.0, 2.0, 3.0, 4.0, 5.0]);
+        assert!((sma.next(6.0) - 4.5).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_compute_uninit_matches_compute_to_vec() {
+        let inputs: Vec<Float> = (0..50).map(|i| (i as Float * 0.4).sin() * 10.0).collect();
+        for len in [0, 3, 7, 50] {
+            let sma = SMA::new(7);
+            let expected = sma.compute_to_vec(&inputs[..len]).unwrap();
+            let mut out = vec![MaybeUninit::<Float>::uninit(); 60];
+            let count = sma.compute_uninit(&inputs[..len], &mut out).unwrap();
+            assert_eq!(count, len);
+            // SAFETY: the first `count` elements were written
+            let values: Vec<Float> = out[..count]
+                .iter()
+                .map(|v| unsafe { v.assume_init() })
+                .collect();
+            assert!(values
+                .iter()
+                .zip(&expected)
+                .all(|(a, b)| (a.is_nan() && b.is_nan()) || a == b));
+        }
+
+        let mut small = [MaybeUninit::<Float>::uninit(); 4];
+        assert_eq!(
+            SMA::new(3).compute_uninit(&inputs[..5], &mut small),
+            Err(TalibError::output_buffer_too_small(5, 4))
+        );
+    }
+
+    #[test]
+    fn test_valid_output_len() {
+        let sma = SMA::new(20);
+        assert_eq!(sma.valid_output_len(25), 6);
+        assert_eq!(sma.valid_output_len(20), 1);
+        assert_eq!(sma.valid_output_len(19), 0);
+        assert_eq!(sma.valid_output_len(10), 0);
+
+        let prices: Vec<Float> = (0..25).map(|i| i as Float).collect();
+        let outputs = sma.compute_to_vec(&prices).unwrap();
+        assert_eq!(outputs.iter().filter(|v| !v.is_nan()).count(), 6);
+    }
+
+    #[test]
+    fn test_compute_to_vec_has_no_excess_capacity() {
+        let sma = SMA::new(5);
+        for len in [0, 3, 5, 100] {
+            let inputs = vec![1.0; len];
+            let outputs = sma.compute_to_vec(&inputs).unwrap();
+            assert_eq!(outputs.len(), sma.output_len(len));
+            assert_eq!(outputs.capacity(), outputs.len());
+        }
+    }
+
+    #[test]
+    fn test_hold_policy_repeats_last_output_on_nan() {
+        let mut sma = SMA::with_invalid_policy(3, InvalidPolicy::Hold);
+        assert_eq!(sma.invalid_policy(), InvalidPolicy::Hold);
+        assert!(sma.next(Float::NAN).is_nan());
+        sma.next(1.0);
+        sma.next(2.0);
+        assert_eq!(sma.next(3.0), 2.0);
+        assert_eq!(sma.next(Float::NAN), 2.0);
+        assert_eq!(sma.next(Float::INFINITY), 2.0);
+        // The gap did not enter the buffer: window is [2, 3, 7]
+        assert_eq!(sma.next(7.0), 4.0);
+        assert_eq!(sma.next(8.0), 6.0);
+    }
+
+    #[test]
+    fn test_hold_policy_gap_does_not_delay_warm_up() {
+        let mut sma = SMA::with_invalid_policy(3, InvalidPolicy::Hold);
+        sma.next(1.0);
+        sma.next(Float::NAN);
+        sma.next(2.0);
+        // Third valid tick completes the window despite the gap
+        assert_eq!(sma.next(3.0), 2.0);
+    }
+
+    #[test]
+    fn test_propagate_policy_recovers_once_gap_leaves_window() {
+        let inputs = [
+            1.0,
+            Float::NAN,
+            2.0,
+            3.0,
+            4.0,
+            5.0,
+            Float::INFINITY,
+            6.0,
+            7.0,
+            8.0,
+            9.0,
+        ];
+        let mut sma = SMA::new(3);
+        let streamed: Vec<Float> = inputs.iter().map(|&x| sma.next(x)).collect();
+        assert!(streamed[..4].iter().all(|v| v.is_nan()));
+        assert_eq!(&streamed[4..6], &[3.0, 4.0]);
+        assert!(streamed[6..9].iter().all(|v| !v.is_finite()));
+        assert_eq!(&streamed[9..], &[7.0, 8.0]);
+
+        let sma = SMA::new(3);
+        let batch = sma.compute_to_vec(&inputs).unwrap();
+        let lazy: Vec<Float> = sma.compute_iter(&inputs).map(|v| v.unwrap()).collect();
+        let mut chunked = Vec::new();
+        sma.compute_chunked(&inputs, 4, |chunk| chunked.extend_from_slice(chunk))
+            .unwrap();
+        for other in [&batch, &lazy, &chunked] {
+            for (s, b) in streamed.iter().zip(other.iter()) {
+                assert!(s == b || (s.is_nan() && b.is_nan()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_reset_policy_restarts_warm_up() {
+        let mut sma = SMA::with_invalid_policy(2, InvalidPolicy::Reset);
+        sma.next(1.0);
+        assert_eq!(sma.next(3.0), 2.0);
+        assert!(sma.next(Float::NAN).is_nan());
+        assert!(sma.next(10.0).is_nan());
+        assert_eq!(sma.next(20.0), 15.0);
+    }
+
+    #[test]
+    fn test_clone_mid_stream_is_independent() {
+        let mut a = SMA::new(3);
+        for x in [1.0, 2.0, 3.0] {
+            a.next(x);
+        }
+        let mut b = a.clone();
+        let tolerance = 16.0 * Float::EPSILON * 10.0;
+        assert!((a.next(10.0) - 5.0).abs() < tolerance);
+        assert!((b.next(4.0) - 3.0).abs() < tolerance);
+        assert!((a.next(10.0) - 23.0 / 3.0).abs() < tolerance);
+        assert!((b.next(4.0) - 11.0 / 3.0).abs() < tolerance);
+    }
+
+    #[test]
+    fn test_restore_reproduces_next() {
+        let mut sma = SMA::new(4);
+        for x in [5.0, 1.0, 7.0, 2.0, 9.0] {
+            sma.next(x);
+        }
+        let checkpoint = sma.snapshot();
+        let first = sma.next(3.0);
+        sma.next(100.0);
+        sma.next(-50.0);
+        sma.restore(checkpoint.clone()).unwrap();
+        assert_eq!(sma.next(3.0), first);
+        assert_eq!(sma.snapshot(), {
+            let mut again = SMA::new(4);
+            again.restore(checkpoint).unwrap();
+            again.next(3.0);
+            again.snapshot()
+        });
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_state_json_round_trip() {
+        let mut sma = SMA::new(3);
+        for x in [4.0, 8.0, 6.0, 2.0] {
+            sma.next(x);
+        }
+        let json = serde_json::to_string(&sma.snapshot()).unwrap();
+        let mut resumed = SMA::new(3);
+        resumed
+            .restore(serde_json::from_str(&json).unwrap())
+            .unwrap();
+        assert_eq!(resumed.next(7.0), sma.next(7.0));
+
+        // Mid warm-up state survives JSON too
+        let mut warming = SMA::new(3);
+        warming.next(1.0);
+        let json = serde_json::to_string(&warming.snapshot()).unwrap();
+        let mut resumed = SMA::new(3);
+        resumed
+            .restore(serde_json::from_str(&json).unwrap())
+            .unwrap();
+        assert!(resumed.next(2.0).is_nan());
+        assert_eq!(resumed.next(3.0), 2.0);
+    }
+
+    #[test]
+    fn test_restore_rejects_mismatched_state() {
+        let state = SMA::new(3).snapshot();
+        assert!(matches!(
+            SMA::new(5).restore(state.clone()),
+            Err(TalibError::InvalidInput { .. })
+        ));
+
+        let mut sma = SMA::new(3);
+        sma.next(1.0);
+        let bad_index = SmaState { index: 3, ..state };
+        assert!(matches!(
+            sma.restore(bad_index),
+            Err(TalibError::InvalidInput { .. })
+        ));
+        // A rejected state leaves the indicator untouched
+        assert!(sma.next(2.0).is_nan());
+        assert_eq!(sma.next(3.0), 2.0);
+    }
+
+    #[test]
+    fn test_builder_requires_period() {
+        assert!(matches!(
+            SMA::builder().build(),
+            Err(TalibError::InvalidPeriod { .. })
+        ));
+        assert!(matches!(
+            SMA::builder().period(0).build(),
+            Err(TalibError::InvalidPeriod { .. })
+        ));
+    }
+
+    #[test]
+    fn test_builder_options_apply() {
+        let mut sma = SMA::builder()
+            .invalid_policy(InvalidPolicy::Hold)
+            .period(2)
+            .build()
+            .unwrap();
+        assert_eq!(sma.lookback(), 1);
+        sma.next(1.0);
+        assert_eq!(sma.next(3.0), 2.0);
+        assert_eq!(sma.next(Float::NAN), 2.0);
+    }
+
+    #[test]
+    fn test_propagate_policy_is_default() {
+        let mut sma = SMA::new(2);
+        assert_eq!(sma.invalid_policy(), InvalidPolicy::Propagate);
+        sma.next(1.0);
+        assert!(sma.next(Float::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_compute_iter_matches_compute_to_vec() {
+        let inputs: Vec<Float> = (0..200).map(|i| (i as Float * 0.3).sin() * 10.0).collect();
+        for period in [1, 7, 64] {
+            let sma = SMA::new(period);
+            let expected = sma.compute_to_vec(&inputs).unwrap();
+            let lazy: Vec<Float> = sma.compute_iter(&inputs).collect::<Result<_>>().unwrap();
+            assert_eq!(lazy.len(), expected.len());
+            for (a, b) in lazy.iter().zip(&expected) {
+                assert!((a.is_nan() && b.is_nan()) || a == b);
+            }
+        }
+        assert_eq!(SMA::new(5).compute_iter(&inputs[..3]).count(), 3);
+    }
+
+    #[test]
+    fn test_f32_and_f64_side_by_side() {
+        let wide: Vec<f64> = (0..40).map(|i| 100.0 + (i as f64 * 0.2).cos()).collect();
+        let narrow: Vec<f32> = wide.iter().map(|&x| x as f32).collect();
+
+        let sma64 = Sma::<f64>::new(20);
+        let sma32 = Sma::<f32>::new(20);
+        let out64 = sma64.compute_to_vec(&wide).unwrap();
+        let out32 = sma32.compute_to_vec(&narrow).unwrap();
+        assert!(out32[..19].iter().all(|x| x.is_nan()));
+        for (&a, &b) in out32[19..].iter().zip(&out64[19..]) {
+            assert!((a as f64 - b).abs() < 1e-4);
+        }
+
+        let mut stream32 = Sma::<f32>::new(20);
+        let mut stream64 = Sma::<f64>::new(20);
+        for (i, (&x32, &x64)) in narrow.iter().zip(&wide).enumerate() {
+            let (a, b) = (stream32.next(x32), stream64.next(x64));
+            if i >= 19 {
+                assert!((a as f64 - b).abs() < 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    fn test_alias_matches_generic() {
+        let inputs: Vec<Float> = (0..50).map(|i| i as Float * 0.5).collect();
+        let alias = SMA::new(8).compute_to_vec(&inputs).unwrap();
+        let generic = Sma::<Float>::new(8).compute_to_vec(&inputs).unwrap();
+        assert_eq!(alias[7..], generic[7..]);
+    }
 }
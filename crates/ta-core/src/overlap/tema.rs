@@ -0,0 +1,102 @@
+//! Implementation of the Triple Exponential Moving Average (TEMA).
+
+use crate::{smoothing::EmaChain, Float, Indicator, Resettable, Result, TalibError};
+
+/// TEMA indicator
+///
+/// Extends [`DEMA`](super::DEMA) with a third EMA to remove the remaining lag:
+///
+/// ```text
+/// tema = 3 * EMA(price) - 3 * EMA(EMA(price)) + EMA(EMA(EMA(price)))
+/// ```
+///
+/// All three EMAs use `period` and are seeded with an SMA, so
+/// `lookback() == 3 * (period - 1)`.
+#[derive(Debug, Clone)]
+pub struct TEMA {
+    period: usize,
+    chain: EmaChain<3>,
+}
+
+impl TEMA {
+    /// Create a new TEMA with the given period.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if `period` is zero.
+    pub fn new(period: usize) -> Result<Self> {
+        if period == 0 {
+            return Err(TalibError::invalid_period(
+                period,
+                "period must be greater than zero",
+            ));
+        }
+        Ok(Self {
+            period,
+            chain: EmaChain::new(period),
+        })
+    }
+}
+
+impl Indicator for TEMA {
+    type Input = Float;
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        format!("TEMA({})", self.period)
+    }
+
+    fn lookback(&self) -> usize {
+        3 * (self.period - 1)
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut tema = Self::new(self.period)?;
+        Ok(inputs.iter().map(|&x| tema.next(x)).collect())
+    }
+
+    fn next(&mut self, input: Float) -> Float {
+        let [ema, ema2, ema3] = self.chain.next(input);
+        3.0 * (ema - ema2) + ema3
+    }
+}
+
+impl Resettable for TEMA {
+    fn reset(&mut self) {
+        self.chain = EmaChain::new(self.period);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::overlap::EMA;
+    use crate::test_util::assert_reset_streaming_matches_batch;
+
+    #[test]
+    fn test_lookback() {
+        let tema = TEMA::new(4).unwrap();
+        assert_eq!(tema.lookback(), 9);
+        let out = tema.compute_to_vec(&[2.5; 12]).unwrap();
+        assert!(out[..9].iter().all(|x| x.is_nan()));
+        assert!(out[9..].iter().all(|&x| (x - 2.5).abs() < 1e-12));
+    }
+
+    #[test]
+    fn test_tracks_ramp_closer_than_ema() {
+        let ramp: Vec<Float> = (0..40).map(|i| 2.0 * i as Float).collect();
+        let tema = TEMA::new(5).unwrap().compute_to_vec(&ramp).unwrap();
+        let ema = EMA::new(5).unwrap().compute_to_vec(&ramp).unwrap();
+        for i in 12..ramp.len() {
+            assert!((tema[i] - ramp[i]).abs() < (ema[i] - ramp[i]).abs());
+            assert!((tema[i] - ramp[i]).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_streaming_matches_batch() {
+        let inputs: Vec<Float> = (0..50).map(|i| (i as Float * 0.4).cos() * 3.0).collect();
+        assert_reset_streaming_matches_batch(TEMA::new(3).unwrap(), &[-100.0], &inputs, 0.0);
+    }
+}
@@ -0,0 +1,150 @@
+//! Implementation of the Triangular Moving Average (TRIMA).
+
+use super::SMA;
+use crate::{Float, Indicator, Resettable, Result, TalibError};
+
+/// TRIMA indicator
+///
+/// An SMA of an SMA, which weights the window triangularly with the peak at its
+/// center. Following TA-Lib the two sub-periods are
+///
+/// ```text
+/// first  = ceil(period / 2)
+/// second = period + 1 - first
+/// ```
+///
+/// so an odd period uses two equal SMAs and an even period one longer by one. The
+/// combined window spans exactly `period` inputs and `lookback() == period - 1`.
+#[derive(Debug, Clone)]
+pub struct TRIMA {
+    period: usize,
+    first: SMA,
+    second: SMA,
+}
+
+impl TRIMA {
+    /// Create a new TRIMA with the given period.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if `period` is zero.
+    pub fn new(period: usize) -> Result<Self> {
+        if period == 0 {
+            return Err(TalibError::invalid_period(
+                period,
+                "period must be greater than zero",
+            ));
+        }
+        let (first, second) = Self::sub_periods(period);
+        Ok(Self {
+            period,
+            first: SMA::new(first),
+            second: SMA::new(second),
+        })
+    }
+
+    fn sub_periods(period: usize) -> (usize, usize) {
+        let first = period.div_ceil(2);
+        (first, period + 1 - first)
+    }
+}
+
+impl Indicator for TRIMA {
+    type Input = Float;
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        format!("TRIMA({})", self.period)
+    }
+
+    fn lookback(&self) -> usize {
+        self.first.lookback() + self.second.lookback()
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut result = vec![Float::NAN; inputs.len()];
+        let start = self.first.lookback();
+        if inputs.len() <= start {
+            return Ok(result);
+        }
+        let first = self.first.compute_to_vec(inputs)?;
+        let second = self.second.compute_to_vec(&first[start..])?;
+        result[start..].copy_from_slice(&second);
+        Ok(result)
+    }
+
+    fn next(&mut self, input: Float) -> Float {
+        let first = self.first.next(input);
+        if first.is_nan() {
+            return Float::NAN;
+        }
+        self.second.next(first)
+    }
+}
+
+impl Resettable for TRIMA {
+    fn reset(&mut self) {
+        let (first, second) = Self::sub_periods(self.period);
+        self.first = SMA::new(first);
+        self.second = SMA::new(second);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_reset_streaming_matches_batch;
+
+    /// Output weights of each window position, read off an impulse response
+    fn weights(period: usize) -> Vec<Float> {
+        let mut impulse = vec![0.0; 3 * period];
+        impulse[period] = 1.0;
+        let out = TRIMA::new(period)
+            .unwrap()
+            .compute_to_vec(&impulse)
+            .unwrap();
+        out[period..2 * period].to_vec()
+    }
+
+    #[test]
+    fn test_weights_are_triangular() {
+        let expected: [Float; 5] = [1.0, 2.0, 3.0, 2.0, 1.0];
+        for (w, e) in weights(5).iter().zip(expected) {
+            assert!((w - e / 9.0).abs() < 4.0 * Float::EPSILON);
+        }
+        let expected: [Float; 4] = [1.0, 2.0, 2.0, 1.0];
+        for (w, e) in weights(4).iter().zip(expected) {
+            assert!((w - e / 6.0).abs() < 4.0 * Float::EPSILON);
+        }
+        for period in [6, 7, 12] {
+            let w = weights(period);
+            let tolerance = period as Float * Float::EPSILON;
+            assert!((w.iter().sum::<Float>() - 1.0).abs() < tolerance);
+            let peak = w.iter().cloned().fold(Float::MIN, Float::max);
+            assert_eq!(w[(period - 1) / 2], peak);
+        }
+    }
+
+    #[test]
+    fn test_lookback() {
+        for period in [1, 4, 5, 30] {
+            let trima = TRIMA::new(period).unwrap();
+            assert_eq!(trima.lookback(), period - 1);
+            let out = trima.compute_to_vec(&[2.0; 40]).unwrap();
+            assert!(out[..period - 1].iter().all(|x| x.is_nan()));
+            assert!(out[period - 1..].iter().all(|&x| (x - 2.0).abs() < 1e-12));
+        }
+    }
+
+    #[test]
+    fn test_streaming_matches_batch() {
+        let inputs: Vec<Float> = (0..100)
+            .map(|i| 10.0 + (i as Float * 0.35).cos() * 2.0)
+            .collect();
+        for period in [4, 7] {
+            let trima = TRIMA::new(period).unwrap();
+            assert_reset_streaming_matches_batch(trima, &[99.0], &inputs, 1e-9);
+        }
+    }
+}
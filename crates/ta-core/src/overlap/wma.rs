@@ -0,0 +1,168 @@
+//! Implementation of the Weighted Moving Average (WMA) indicator.
+
+use crate::{simd::dispatch, Float, Indicator, Resettable, Result, TalibError};
+
+/// WMA indicator
+///
+/// Linearly weighted average of the last `period` inputs, with weight `k + 1` for
+/// the `k`-th oldest value so the newest input counts `period` times:
+///
+/// ```text
+/// wma = sum(price[k] * (k + 1)) / (period * (period + 1) / 2)
+/// ```
+///
+/// Batch computation takes a SIMD dot product of each window with the weights.
+/// Streaming keeps a running weighted sum and a running plain sum, so each `next`
+/// is O(1) regardless of the period. Both are recomputed from the buffer once per
+/// `period` updates so rounding error cannot accumulate.
+#[derive(Debug, Clone)]
+pub struct WMA {
+    period: usize,
+    weights: Vec<Float>,
+    inv_weight_sum: Float,
+    buffer: Vec<Float>,
+    index: usize,
+    count: usize,
+    weighted_sum: Float,
+    plain_sum: Float,
+}
+
+impl WMA {
+    /// Create a new WMA with the given period.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if `period` is zero.
+    pub fn new(period: usize) -> Result<Self> {
+        if period == 0 {
+            return Err(TalibError::invalid_period(
+                period,
+                "period must be greater than zero",
+            ));
+        }
+        let n = period as Float;
+        Ok(Self {
+            period,
+            weights: (1..=period).map(|k| k as Float).collect(),
+            inv_weight_sum: 2.0 / (n * (n + 1.0)),
+            buffer: vec![0.0; period],
+            index: 0,
+            count: 0,
+            weighted_sum: 0.0,
+            plain_sum: 0.0,
+        })
+    }
+}
+
+impl Indicator for WMA {
+    type Input = Float;
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        format!("WMA({})", self.period)
+    }
+
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut result = vec![Float::NAN; inputs.len()];
+        for (i, window) in inputs.windows(self.period).enumerate() {
+            result[i + self.period - 1] =
+                dispatch::dot_product(&self.weights, window) * self.inv_weight_sum;
+        }
+        Ok(result)
+    }
+
+    fn next(&mut self, input: Float) -> Float {
+        if self.count < self.period {
+            // Filling up: the new value takes the next weight
+            self.count += 1;
+            self.weighted_sum += self.count as Float * input;
+        } else {
+            // Every weight drops by one, which removes the oldest value (weight 1)
+            // along with one copy of each remaining value
+            self.weighted_sum += self.period as Float * input - self.plain_sum;
+            self.plain_sum -= self.buffer[self.index];
+        }
+        self.plain_sum += input;
+        self.buffer[self.index] = input;
+        self.index = (self.index + 1) % self.period;
+
+        if self.count < self.period {
+            return Float::NAN;
+        }
+        if self.index == 0 {
+            // The buffer is in chronological order whenever the index wraps
+            self.weighted_sum = dispatch::dot_product(&self.weights, &self.buffer);
+            self.plain_sum = dispatch::sum(&self.buffer);
+        }
+        self.weighted_sum * self.inv_weight_sum
+    }
+}
+
+impl Resettable for WMA {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.weighted_sum = 0.0;
+        self.plain_sum = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_three_period_value() {
+        let out = WMA::new(3)
+            .unwrap()
+            .compute_to_vec(&[1.0, 2.0, 3.0])
+            .unwrap();
+        assert!(out[0].is_nan() && out[1].is_nan());
+        let expected: Float = (1.0 * 1.0 + 2.0 * 2.0 + 3.0 * 3.0) / 6.0;
+        assert!((out[2] - expected).abs() < 4.0 * Float::EPSILON * expected);
+
+        let mut stream = WMA::new(3).unwrap();
+        stream.next(1.0);
+        stream.next(2.0);
+        assert!((stream.next(3.0) - expected).abs() < 4.0 * Float::EPSILON * expected);
+    }
+
+    #[test]
+    fn test_streaming_matches_batch() {
+        let inputs: Vec<Float> = (0..500)
+            .map(|i| 100.0 + (i as Float * 0.17).sin() * 9.0 + i as Float * 0.05)
+            .collect();
+        // Rounding error scales with the values, which stay below 125
+        let tolerance = 1e2 * Float::EPSILON * 125.0;
+        for period in [1, 2, 7, 30] {
+            let batch = WMA::new(period).unwrap().compute_to_vec(&inputs).unwrap();
+            let mut stream = WMA::new(period).unwrap();
+            for (&x, expected) in inputs.iter().zip(&batch) {
+                let v = stream.next(x);
+                assert!(
+                    (v.is_nan() && expected.is_nan()) || (v - expected).abs() < tolerance,
+                    "period {}: {} != {}",
+                    period,
+                    v,
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_reset_and_invalid_period() {
+        let mut wma = WMA::new(2).unwrap();
+        wma.next(5.0);
+        wma.next(7.0);
+        wma.reset();
+        assert!(wma.next(1.0).is_nan());
+        assert!((wma.next(4.0) - 3.0).abs() < 4.0 * Float::EPSILON * 3.0);
+        assert!(matches!(WMA::new(0), Err(TalibError::InvalidPeriod { .. })));
+    }
+}
@@ -0,0 +1,173 @@
+//! Trading signal helpers
+//!
+//! Turn indicator outputs into discrete events, such as one line crossing another.
+//! Comparisons involving `NaN` (for example warm-up placeholders) never signal.
+
+use crate::{types::Float, Resettable};
+
+/// Direction of a cross between two lines
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrossEvent {
+    /// `a` moved from at or below `b` to above it
+    Over,
+    /// `a` moved from at or above `b` to below it
+    Under,
+}
+
+/// Mark every index where `a` crosses above `b`
+///
+/// `true` at `i` when `a[i - 1] <= b[i - 1]` and `a[i] > b[i]`. The result has the
+/// length of the shorter input and always starts with `false`.
+///
+/// # Example
+///
+/// ```rust
+/// use ta_core::signals::crossover;
+///
+/// let fast = [1.0, 2.0, 4.0, 5.0];
+/// let slow = [3.0, 3.0, 3.0, 6.0];
+/// assert_eq!(crossover(&fast, &slow), [false, false, true, false]);
+/// ```
+pub fn crossover(a: &[Float], b: &[Float]) -> Vec<bool> {
+    cross(a, b, CrossEvent::Over)
+}
+
+/// Mark every index where `a` crosses below `b`
+///
+/// `true` at `i` when `a[i - 1] >= b[i - 1]` and `a[i] < b[i]`. The result has the
+/// length of the shorter input and always starts with `false`.
+pub fn crossunder(a: &[Float], b: &[Float]) -> Vec<bool> {
+    cross(a, b, CrossEvent::Under)
+}
+
+fn cross(a: &[Float], b: &[Float], event: CrossEvent) -> Vec<bool> {
+    let len = a.len().min(b.len());
+    let mut result = vec![false; len];
+    for i in 1..len {
+        result[i] = detect((a[i - 1], b[i - 1]), (a[i], b[i])) == Some(event);
+    }
+    result
+}
+
+#[inline]
+fn detect(prev: (Float, Float), current: (Float, Float)) -> Option<CrossEvent> {
+    let ((prev_a, prev_b), (a, b)) = (prev, current);
+    if prev_a <= prev_b && a > b {
+        Some(CrossEvent::Over)
+    } else if prev_a >= prev_b && a < b {
+        Some(CrossEvent::Under)
+    } else {
+        None
+    }
+}
+
+/// Streaming cross detection for two lines
+///
+/// Remembers the previous pair, so each [`next`](CrossDetector::next) call reports
+/// whether the lines crossed since the last one, with the same rules as
+/// [`crossover`] and [`crossunder`]. [`Resettable::reset`] forgets the pair.
+///
+/// # Example
+///
+/// ```rust
+/// use ta_core::signals::{CrossDetector, CrossEvent};
+///
+/// let mut detector = CrossDetector::new();
+/// assert_eq!(detector.next(1.0, 2.0), None);
+/// assert_eq!(detector.next(3.0, 2.0), Some(CrossEvent::Over));
+/// assert_eq!(detector.next(4.0, 2.0), None);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CrossDetector {
+    prev: Option<(Float, Float)>,
+}
+
+impl CrossDetector {
+    /// Create a detector with no previous pair.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the current values of both lines and report a cross, if any.
+    ///
+    /// The first call, and any call where either side of the comparison is `NaN`,
+    /// returns `None`.
+    pub fn next(&mut self, a: Float, b: Float) -> Option<CrossEvent> {
+        self.prev
+            .replace((a, b))
+            .and_then(|prev| detect(prev, (a, b)))
+    }
+}
+
+impl Resettable for CrossDetector {
+    /// Forget the previous pair.
+    fn reset(&mut self) {
+        self.prev = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{overlap::SMA, Indicator};
+
+    /// Falls for 15 bars, then rises for 15
+    fn prices() -> Vec<Float> {
+        (0..30)
+            .map(|i| {
+                if i < 15 {
+                    30.0 - i as Float
+                } else {
+                    i as Float
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_sma_crossover_sequence() {
+        let prices = prices();
+        let fast = SMA::new(3).compute_to_vec(&prices).unwrap();
+        let slow = SMA::new(8).compute_to_vec(&prices).unwrap();
+
+        let over = crossover(&fast, &slow);
+        let under = crossunder(&fast, &slow);
+        assert_eq!(over.len(), prices.len());
+        let over_at: Vec<usize> = (0..over.len()).filter(|&i| over[i]).collect();
+        assert_eq!(over_at.len(), 1);
+        let i = over_at[0];
+        assert!(i > 15 && fast[i - 1] <= slow[i - 1] && fast[i] > slow[i]);
+        // The warm-up NaNs never produce a signal
+        assert!(!under.iter().any(|&x| x));
+
+        let mut detector = CrossDetector::new();
+        for (j, (&f, &s)) in fast.iter().zip(&slow).enumerate() {
+            let event = detector.next(f, s);
+            assert_eq!(event == Some(CrossEvent::Over), over[j]);
+            assert_eq!(event == Some(CrossEvent::Under), under[j]);
+        }
+    }
+
+    #[test]
+    fn test_crossunder_and_lengths() {
+        assert_eq!(
+            crossunder(&[3.0, 2.0, 1.0], &[2.0, 2.0, 2.0, 2.0]),
+            [false, false, true]
+        );
+        assert!(crossover(&[], &[1.0]).is_empty());
+    }
+
+    #[test]
+    fn test_nan_never_signals() {
+        let a = [1.0, Float::NAN, 3.0];
+        let b = [2.0, 2.0, 2.0];
+        assert_eq!(crossover(&a, &b), [false, false, false]);
+
+        let mut detector = CrossDetector::new();
+        detector.next(1.0, 2.0);
+        assert_eq!(detector.next(Float::NAN, 2.0), None);
+        assert_eq!(detector.next(3.0, 2.0), None);
+        detector.reset();
+        assert_eq!(detector.next(3.0, 2.0), None);
+    }
+}
@@ -25,3 +25,11 @@ pub unsafe fn dot_product(a: &[Float], b: &[Float]) -> Result<Float> {
     }
     Ok(sum)
 }
+
+#[cfg(not(feature = "f32"))]
+type Vector = wide::f64x2;
+#[cfg(feature = "f32")]
+type Vector = wide::f32x4;
+
+extreme_kernels!(Vector, "neon");
+nan_kernels!(Vector, "neon");
@@ -1,3 +1,129 @@
+use crate::types::Float;
+
+/// Load one `chunks_exact` chunk of `LANES` values into the `wide` vector `V`.
+///
+/// `wide` only converts slices into vectors of four or more lanes, but every width
+/// converts from an array, so the 2-lane `f64` vectors of NEON and SIMD128 load
+/// through one.
+#[allow(dead_code)]
+#[inline(always)]
+fn load<V: From<[Float; LANES]>, const LANES: usize>(chunk: &[Float]) -> V {
+    let lanes: [Float; LANES] = chunk.try_into().expect("chunk holds one vector");
+    V::from(lanes)
+}
+
+/// Expands to `min` and `max` kernels over the `wide` vector type `$vec`, compiled
+/// with `$feature` enabled.
+///
+/// Whole vectors are folded with the lane-wise `min`/`max` of `wide`, which picks
+/// the other lane when one is NaN, then the lanes and the scalar tail are reduced
+/// with `Float::min`/`Float::max`, which do the same. NaN values are therefore
+/// skipped, and an empty or all-NaN slice returns the identity of the reduction.
+macro_rules! extreme_kernels {
+    ($vec:ty, $feature:literal) => {
+        extreme_kernels!(@kernel min, INFINITY, $vec, $feature);
+        extreme_kernels!(@kernel max, NEG_INFINITY, $vec, $feature);
+    };
+    (@kernel $op:ident, $identity:ident, $vec:ty, $feature:literal) => {
+        #[doc = concat!("Horizontal `", stringify!($op), "` of `data`, skipping NaN values.")]
+        ///
+        #[doc = concat!("Returns `Float::", stringify!($identity), "` for an empty slice.")]
+        ///
+        /// # Safety
+        ///
+        #[doc = concat!("The CPU must support `", $feature, "`.")]
+        #[inline(never)]
+        #[target_feature(enable = $feature)]
+        #[allow(dead_code)]
+        pub unsafe fn $op(data: &[Float]) -> Float {
+            use $crate::simd::arch::load;
+            const VECTOR_LANES: usize =
+                core::mem::size_of::<$vec>() / core::mem::size_of::<Float>();
+            let mut acc = <$vec>::splat(Float::$identity);
+            let mut chunks = data.chunks_exact(VECTOR_LANES);
+            for chunk in &mut chunks {
+                acc = acc.$op(load::<$vec, VECTOR_LANES>(chunk));
+            }
+            acc.to_array()
+                .iter()
+                .chain(chunks.remainder())
+                .fold(Float::$identity, |a, &b| a.$op(b))
+        }
+    };
+}
+
+/// Expands to the NaN-aware `nansum` and `nandot` kernels over the `wide` vector
+/// type `$vec`, compiled with `$feature` enabled.
+///
+/// Each vector is ANDed with a finite-lane mask before accumulating, which clears
+/// NaN and infinite lanes to `0.0` so they contribute nothing. The scalar tail is
+/// filtered the same way.
+///
+/// The mask is built as `!(x - x).is_nan()`, as `x - x` is NaN exactly when `x` is
+/// not finite. `wide`'s own `is_finite` is wrong for `f32x16` in some releases, and
+/// `blend` is deprecated in newer ones.
+macro_rules! nan_kernels {
+    ($vec:ty, $feature:literal) => {
+        /// Sum of `data`, treating NaN and infinite values as zero.
+        ///
+        /// # Safety
+        ///
+        #[doc = concat!("The CPU must support `", $feature, "`.")]
+        #[inline(never)]
+        #[target_feature(enable = $feature)]
+        #[allow(dead_code)]
+        pub unsafe fn nansum(data: &[Float]) -> Float {
+            use $crate::simd::arch::load;
+            const VECTOR_LANES: usize =
+                core::mem::size_of::<$vec>() / core::mem::size_of::<Float>();
+            let mut acc = <$vec>::splat(0.0);
+            let mut chunks = data.chunks_exact(VECTOR_LANES);
+            for chunk in &mut chunks {
+                let v = load::<$vec, VECTOR_LANES>(chunk);
+                acc += v & !(v - v).is_nan();
+            }
+            let tail: Float = chunks.remainder().iter().filter(|x| x.is_finite()).sum();
+            acc.to_array().iter().sum::<Float>() + tail
+        }
+
+        /// Dot product of `a` and `b`, treating NaN and infinite values as zero.
+        ///
+        /// # Safety
+        ///
+        #[doc = concat!("The CPU must support `", $feature, "`.")]
+        #[inline(never)]
+        #[target_feature(enable = $feature)]
+        #[allow(dead_code)]
+        pub unsafe fn nandot(a: &[Float], b: &[Float]) -> Result<Float> {
+            use $crate::simd::arch::load;
+            if a.len() != b.len() {
+                return Err(crate::TalibError::InvalidInput {
+                    message: "Dot product requires vectors of equal length".into(),
+                });
+            }
+            const VECTOR_LANES: usize =
+                core::mem::size_of::<$vec>() / core::mem::size_of::<Float>();
+            let mut acc = <$vec>::splat(0.0);
+            let chunks_a = a.chunks_exact(VECTOR_LANES);
+            let chunks_b = b.chunks_exact(VECTOR_LANES);
+            let tail_a = chunks_a.remainder();
+            let tail_b = chunks_b.remainder();
+            for (ca, cb) in chunks_a.zip(chunks_b) {
+                let x = load::<$vec, VECTOR_LANES>(ca);
+                let y = load::<$vec, VECTOR_LANES>(cb);
+                acc += (x & !(x - x).is_nan()) * (y & !(y - y).is_nan());
+            }
+            let tail: Float = tail_a
+                .iter()
+                .zip(tail_b)
+                .filter(|(x, y)| x.is_finite() && y.is_finite())
+                .map(|(x, y)| x * y)
+                .sum();
+            Ok(acc.to_array().iter().sum::<Float>() + tail)
+        }
+    };
+}
+
 #[cfg(all(target_arch = "x86_64", feature = "std"))]
 pub mod x86_64;
 
@@ -6,3 +132,42 @@ pub mod aarch64;
 
 #[cfg(target_arch = "wasm32")]
 pub mod wasm32;
+
+/// The kernels expanded over the 128-bit `wide` types of the NEON and SIMD128
+/// levels, built with SSE2 (baseline on x86_64) so that those expansions are
+/// compiled and tested on x86_64 hosts too.
+#[cfg(all(test, target_arch = "x86_64"))]
+mod narrow {
+    use crate::{simd::scalar, types::Float, Result};
+
+    #[cfg(not(feature = "f32"))]
+    type Vector = wide::f64x2;
+    #[cfg(feature = "f32")]
+    type Vector = wide::f32x4;
+
+    extreme_kernels!(Vector, "sse2");
+    nan_kernels!(Vector, "sse2");
+
+    #[test]
+    fn test_128_bit_kernels_match_scalar() {
+        let mut a: Vec<Float> = (0..37).map(|i| (i as Float * 0.7).sin() * 10.0).collect();
+        let b: Vec<Float> = (0..37).map(|i| (i as Float * 0.3).cos()).collect();
+        a[4] = Float::NAN;
+        a[9] = Float::INFINITY;
+        a[36] = Float::NAN;
+        let finite: Vec<Float> = a
+            .iter()
+            .map(|x| if x.is_finite() { *x } else { 0.0 })
+            .collect();
+        let tolerance = 1e2 * Float::EPSILON * 10.0;
+        unsafe {
+            assert_eq!(min(&a), scalar::min(&a));
+            assert_eq!(max(&a), scalar::max(&a));
+            assert_eq!((min(&[]), max(&[])), (Float::INFINITY, Float::NEG_INFINITY));
+            assert!((nansum(&a) - scalar::sum(&finite)).abs() < tolerance);
+            let dot = nandot(&a, &b).unwrap();
+            assert!((dot - scalar::dot_product(&finite, &b)).abs() < tolerance);
+            assert!(nandot(&a, &b[1..]).is_err());
+        }
+    }
+}
@@ -61,6 +61,9 @@ pub unsafe fn dot_product(a: &[Float], b: &[Float]) -> Result<Float> {
     Ok(sum)
 }
 
+extreme_kernels!(SimdVec, "simd128");
+nan_kernels!(SimdVec, "simd128");
+
 #[cfg(test)]
 #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
 mod tests {
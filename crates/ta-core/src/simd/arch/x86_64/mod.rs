@@ -5,3 +5,10 @@ pub mod avx2;
 
 #[cfg(all(target_arch = "x86_64", feature = "std"))]
 pub mod avx512;
+
+/// Independent accumulator vectors used by the unrolled kernels.
+///
+/// One accumulator serializes every add behind the previous one; four in flight
+/// cover the latency of the vector add so the loop is throughput-bound instead.
+#[allow(dead_code)]
+pub(super) const ACCUMULATORS: usize = 4;
@@ -14,8 +14,11 @@ use once_cell::sync::OnceCell as OnceLock;
 #[cfg(feature = "std")]
 use std::sync::OnceLock;
 
-use super::scalar;
-use crate::types::Float;
+#[cfg(feature = "std")]
+use core::cell::Cell;
+
+use super::{scalar, FastFloat, SimdLevel, LANES};
+use crate::{types::Float, Result, TalibError};
 
 #[cfg(all(target_arch = "x86_64", feature = "std"))]
 #[allow(unused_imports)]
@@ -39,42 +42,329 @@ pub type SumFn = fn(&[Float]) -> Float;
 /// This type alias represents a function that computes the dot product of two Float slices.
 pub type DotProductFn = fn(&[Float], &[Float]) -> Float;
 
+/// Function pointer type for the squared-deviation pass of a variance.
+///
+/// This type alias represents a function that computes `sum((x - mean)^2)` over a slice.
+pub type SumSqDevFn = fn(&[Float], Float) -> Float;
+
+/// Function pointer type for minimum reductions.
+///
+/// This type alias represents a function that computes the minimum of a slice, skipping NaN.
+pub type MinFn = fn(&[Float]) -> Float;
+
+/// Function pointer type for maximum reductions.
+///
+/// This type alias represents a function that computes the maximum of a slice, skipping NaN.
+pub type MaxFn = fn(&[Float]) -> Float;
+
+/// Function pointer type for fused window statistics.
+///
+/// This type alias represents a function that computes mean, standard deviation, min and
+/// max of a non-empty slice in a single pass.
+pub type WindowStatsFn = fn(&[Float]) -> WindowStats;
+
 /// Dispatch table containing function pointers for all SIMD operations.
 ///
 /// This struct holds function pointers for each operation, initialized with the
 /// best available implementation based on CPU feature detection.
 #[derive(Debug, Clone, Copy)]
 pub struct DispatchTable {
+    /// SIMD level whose kernels the function pointers point to
+    pub level: SimdLevel,
     /// Function pointer for sum operations
     pub sum: SumFn,
     /// Function pointer for dot product operations
     pub dot_product: DotProductFn,
+    /// Function pointer for the squared-deviation pass of variance operations
+    pub sum_sq_dev: SumSqDevFn,
+    /// Function pointer for minimum reductions
+    pub min: MinFn,
+    /// Function pointer for maximum reductions
+    pub max: MaxFn,
+    /// Function pointer for fused single-pass window statistics
+    pub window_stats: WindowStatsFn,
+    /// Function pointer for NaN-aware sum operations
+    pub nansum: SumFn,
+    /// Function pointer for NaN-aware dot product operations
+    pub nandot: DotProductFn,
 }
 
 impl DispatchTable {
     /// Create a new dispatch table with the given function pointers.
+    ///
+    /// Every SIMD level shares the portable `wide` squared-deviation and window
+    /// statistics kernels.
     #[inline]
     #[allow(dead_code)]
-    const fn new(sum: SumFn, dot_product: DotProductFn) -> Self {
-        Self { sum, dot_product }
+    const fn new(
+        level: SimdLevel,
+        sum: SumFn,
+        dot_product: DotProductFn,
+        min: MinFn,
+        max: MaxFn,
+        nansum: SumFn,
+        nandot: DotProductFn,
+    ) -> Self {
+        Self {
+            level,
+            sum,
+            dot_product,
+            sum_sq_dev: wide_sum_sq_dev,
+            min,
+            max,
+            window_stats: wide_window_stats,
+            nansum,
+            nandot,
+        }
     }
 
     /// Create a scalar dispatch table (no SIMD acceleration).
     #[inline]
     const fn scalar() -> Self {
         Self {
+            level: SimdLevel::Scalar,
             sum: scalar::sum,
             dot_product: scalar::dot_product,
+            sum_sq_dev: scalar::sum_squared_deviations,
+            min: scalar::min,
+            max: scalar::max,
+            window_stats: scalar_window_stats,
+            nansum: scalar::nansum,
+            nandot: scalar::nandot,
         }
     }
 }
 
+/// `sum((x - mean)^2)` accumulated in `wide` vectors of [`LANES`] values.
+fn wide_sum_sq_dev(data: &[Float], mean: Float) -> Float {
+    let center = FastFloat::splat(mean);
+    let mut acc = FastFloat::splat(0.0);
+    let mut chunks = data.chunks_exact(LANES);
+    for chunk in &mut chunks {
+        let deviation = FastFloat::from(chunk) - center;
+        acc += deviation * deviation;
+    }
+    let tail = scalar::sum_squared_deviations(chunks.remainder(), mean);
+    acc.to_array().iter().sum::<Float>() + tail
+}
+
+/// Running state of a fused window pass: sums of `x - shift` and of its square,
+/// plus the extremes.
+#[derive(Clone, Copy)]
+struct ShiftedSums {
+    sum: Float,
+    sum_sq: Float,
+    min: Float,
+    max: Float,
+}
+
+impl ShiftedSums {
+    const EMPTY: Self = Self {
+        sum: 0.0,
+        sum_sq: 0.0,
+        min: Float::INFINITY,
+        max: Float::NEG_INFINITY,
+    };
+
+    fn extend(mut self, data: &[Float], shift: Float) -> Self {
+        for &x in data {
+            let d = x - shift;
+            self.sum += d;
+            self.sum_sq += d * d;
+            self.min = self.min.min(x);
+            self.max = self.max.max(x);
+        }
+        self
+    }
+
+    /// Shifting by a value inside the window keeps `sum_sq - sum^2 / n` from
+    /// cancelling when the mean is large relative to the spread.
+    fn finish(self, shift: Float, len: usize) -> WindowStats {
+        let n = len as Float;
+        let variance = (self.sum_sq - self.sum * self.sum / n) / n;
+        WindowStats {
+            mean: shift + self.sum / n,
+            std: variance.max(0.0).sqrt(),
+            min: self.min,
+            max: self.max,
+        }
+    }
+}
+
+/// Mean, standard deviation, min and max of `data` in one scalar pass.
+fn scalar_window_stats(data: &[Float]) -> WindowStats {
+    let shift = data.first().copied().unwrap_or(0.0);
+    ShiftedSums::EMPTY
+        .extend(data, shift)
+        .finish(shift, data.len())
+}
+
+/// Mean, standard deviation, min and max of `data` in one pass of `wide` vectors.
+fn wide_window_stats(data: &[Float]) -> WindowStats {
+    let shift = data.first().copied().unwrap_or(0.0);
+    let center = FastFloat::splat(shift);
+    let mut sum = FastFloat::splat(0.0);
+    let mut sum_sq = FastFloat::splat(0.0);
+    let mut min = FastFloat::splat(Float::INFINITY);
+    let mut max = FastFloat::splat(Float::NEG_INFINITY);
+    let mut chunks = data.chunks_exact(LANES);
+    for chunk in &mut chunks {
+        let values = FastFloat::from(chunk);
+        let deviation = values - center;
+        sum += deviation;
+        sum_sq += deviation * deviation;
+        // `min`/`max` return `self` for a NaN lane of `rhs`, so NaN is skipped
+        min = min.min(values);
+        max = max.max(values);
+    }
+    let lanes = ShiftedSums {
+        sum: sum.to_array().iter().sum(),
+        sum_sq: sum_sq.to_array().iter().sum(),
+        min: min
+            .to_array()
+            .iter()
+            .copied()
+            .fold(Float::INFINITY, Float::min),
+        max: max
+            .to_array()
+            .iter()
+            .copied()
+            .fold(Float::NEG_INFINITY, Float::max),
+    };
+    lanes
+        .extend(chunks.remainder(), shift)
+        .finish(shift, data.len())
+}
+
 /// Global dispatch table initialized once at startup.
 ///
 /// This `OnceLock` ensures thread-safe one-time initialization of the dispatch table.
 /// After initialization, accessing the dispatch table is as fast as a global variable.
 static DISPATCH: OnceLock<DispatchTable> = OnceLock::new();
 
+/// Scalar dispatch table, also the fallback for unsupported forced levels.
+static SCALAR_DISPATCH: DispatchTable = DispatchTable::scalar();
+
+/// Dispatch tables of the SIMD levels, each built on first request.
+///
+/// Forced levels look their table up here, so a [`LevelGuard`] or a `*_with` call
+/// costs no more than the detected dispatch after the first use.
+static LEVEL_DISPATCH: [OnceLock<Option<DispatchTable>>; 4] = [
+    OnceLock::new(),
+    OnceLock::new(),
+    OnceLock::new(),
+    OnceLock::new(),
+];
+
+/// Get the dispatch table for a specific SIMD level.
+///
+/// Returns `None` if the level is not available on this target or CPU, so callers
+/// can never end up running kernels the hardware does not support.
+fn table_for_level(level: SimdLevel) -> Option<&'static DispatchTable> {
+    let slot = match level {
+        SimdLevel::Scalar => return Some(&SCALAR_DISPATCH),
+        SimdLevel::Avx2 => 0,
+        SimdLevel::Avx512 => 1,
+        SimdLevel::Neon => 2,
+        SimdLevel::Simd128 => 3,
+    };
+    LEVEL_DISPATCH[slot]
+        .get_or_init(|| build_table(level))
+        .as_ref()
+}
+
+/// Build the dispatch table for a specific SIMD level.
+///
+/// Returns `None` if the level is not available on this target or CPU.
+fn build_table(level: SimdLevel) -> Option<DispatchTable> {
+    match level {
+        SimdLevel::Scalar => Some(DispatchTable::scalar()),
+        #[cfg(all(target_arch = "x86_64", feature = "std"))]
+        SimdLevel::Avx512 if std::is_x86_feature_detected!("avx512f") => Some(DispatchTable::new(
+            SimdLevel::Avx512,
+            |data| unsafe { x86_64::avx512::sum(data) },
+            |a, b| unsafe {
+                match x86_64::avx512::dot_product(a, b) {
+                    Ok(result) => result,
+                    Err(e) => panic!("dot_product error: {}", e),
+                }
+            },
+            |data| unsafe { x86_64::avx512::min(data) },
+            |data| unsafe { x86_64::avx512::max(data) },
+            |data| unsafe { x86_64::avx512::nansum(data) },
+            |a, b| unsafe {
+                match x86_64::avx512::nandot(a, b) {
+                    Ok(result) => result,
+                    Err(e) => panic!("nandot error: {}", e),
+                }
+            },
+        )),
+        #[cfg(all(target_arch = "x86_64", feature = "std"))]
+        SimdLevel::Avx2 if std::is_x86_feature_detected!("avx2") => Some(DispatchTable::new(
+            SimdLevel::Avx2,
+            |data| unsafe { x86_64::avx2::sum(data) },
+            |a, b| unsafe {
+                match x86_64::avx2::dot_product(a, b) {
+                    Ok(result) => result,
+                    Err(e) => panic!("dot_product error: {}", e),
+                }
+            },
+            |data| unsafe { x86_64::avx2::min(data) },
+            |data| unsafe { x86_64::avx2::max(data) },
+            |data| unsafe { x86_64::avx2::nansum(data) },
+            |a, b| unsafe {
+                match x86_64::avx2::nandot(a, b) {
+                    Ok(result) => result,
+                    Err(e) => panic!("nandot error: {}", e),
+                }
+            },
+        )),
+        // NEON is always available on AArch64
+        #[cfg(target_arch = "aarch64")]
+        SimdLevel::Neon => Some(DispatchTable::new(
+            SimdLevel::Neon,
+            |data| unsafe { aarch64::neon::sum(data) },
+            |a, b| unsafe {
+                match aarch64::neon::dot_product(a, b) {
+                    Ok(result) => result,
+                    Err(e) => panic!("dot_product error: {}", e),
+                }
+            },
+            |data| unsafe { aarch64::neon::min(data) },
+            |data| unsafe { aarch64::neon::max(data) },
+            |data| unsafe { aarch64::neon::nansum(data) },
+            |a, b| unsafe {
+                match aarch64::neon::nandot(a, b) {
+                    Ok(result) => result,
+                    Err(e) => panic!("nandot error: {}", e),
+                }
+            },
+        )),
+        // SIMD128 is enabled at compile-time
+        #[cfg(target_arch = "wasm32")]
+        SimdLevel::Simd128 => Some(DispatchTable::new(
+            SimdLevel::Simd128,
+            |data| unsafe { wasm32::simd128::sum(data) },
+            |a, b| unsafe {
+                match wasm32::simd128::dot_product(a, b) {
+                    Ok(result) => result,
+                    Err(e) => panic!("dot_product error: {}", e),
+                }
+            },
+            |data| unsafe { wasm32::simd128::min(data) },
+            |data| unsafe { wasm32::simd128::max(data) },
+            |data| unsafe { wasm32::simd128::nansum(data) },
+            |a, b| unsafe {
+                match wasm32::simd128::nandot(a, b) {
+                    Ok(result) => result,
+                    Err(e) => panic!("nandot error: {}", e),
+                }
+            },
+        )),
+        _ => None,
+    }
+}
+
 /// Initialize the dispatch table with the best available SIMD implementation.
 ///
 /// This function performs CPU feature detection and selects optimal implementation.
@@ -92,66 +382,84 @@ static DISPATCH: OnceLock<DispatchTable> = OnceLock::new();
 #[cold]
 #[inline(always)]
 fn init_dispatch() -> DispatchTable {
-    #[cfg(all(target_arch = "x86_64", feature = "std"))]
-    {
-        // Runtime feature detection for AVX-512F
-        let has_avx512 = { std::is_x86_feature_detected!("avx512f") };
-        if has_avx512 {
-            return DispatchTable::new(
-                |data| unsafe { x86_64::avx512::sum(data) },
-                |a, b| unsafe {
-                    match x86_64::avx512::dot_product(a, b) {
-                        Ok(result) => result,
-                        Err(e) => panic!("dot_product error: {}", e),
-                    }
-                },
-            );
-        }
-        // Runtime feature detection for AVX2
-        let has_avx2 = { std::is_x86_feature_detected!("avx2") };
-        if has_avx2 {
-            return DispatchTable::new(
-                |data| unsafe { x86_64::avx2::sum(data) },
-                |a, b| unsafe {
-                    match x86_64::avx2::dot_product(a, b) {
-                        Ok(result) => result,
-                        Err(e) => panic!("dot_product error: {}", e),
-                    }
-                },
-            );
-        }
-    }
+    [
+        SimdLevel::Avx512,
+        SimdLevel::Avx2,
+        SimdLevel::Neon,
+        SimdLevel::Simd128,
+    ]
+    .into_iter()
+    .find_map(table_for_level)
+    .copied()
+    .unwrap_or_else(DispatchTable::scalar)
+}
 
-    #[cfg(target_arch = "aarch64")]
-    {
-        // NEON is always available on AArch64
-        return DispatchTable::new(
-            |data| unsafe { aarch64::neon::sum(data) },
-            |a, b| unsafe {
-                match aarch64::neon::dot_product(a, b) {
-                    Ok(result) => result,
-                    Err(e) => panic!("dot_product error: {}", e),
-                }
-            },
-        );
+#[cfg(feature = "std")]
+std::thread_local! {
+    /// Per-thread override installed by [`LevelGuard`].
+    static FORCED_LEVEL: Cell<Option<SimdLevel>> = const { Cell::new(None) };
+}
+
+/// The SIMD level forced on the current thread by a [`LevelGuard`], if any.
+#[cfg(feature = "std")]
+pub fn forced_level() -> Option<SimdLevel> {
+    FORCED_LEVEL.with(Cell::get)
+}
+
+/// RAII guard forcing a SIMD level for the current thread.
+///
+/// While the guard is alive, [`sum`] and [`dot_product`] on this thread use the
+/// kernels for the forced level instead of the detected best one. Dropping the
+/// guard restores whatever was in effect before, so guards nest. Other threads are
+/// unaffected, which makes scoped benchmarking and parity tests safe to run in
+/// parallel.
+///
+/// Forcing a level that this target or CPU does not support falls back to the
+/// scalar kernels rather than executing unsupported instructions.
+///
+/// # Examples
+///
+/// ```rust
+/// use ta_core::simd::{dispatch::{self, LevelGuard}, SimdLevel};
+///
+/// {
+///     let _guard = LevelGuard::new(SimdLevel::Scalar);
+///     assert_eq!(dispatch::forced_level(), Some(SimdLevel::Scalar));
+///     assert_eq!(dispatch::sum(&[1.0, 2.0]), 3.0);
+/// }
+/// assert_eq!(dispatch::forced_level(), None);
+/// ```
+#[cfg(feature = "std")]
+#[must_use = "the level is only forced while the guard is alive"]
+#[derive(Debug)]
+pub struct LevelGuard {
+    previous: Option<SimdLevel>,
+}
+
+#[cfg(feature = "std")]
+impl LevelGuard {
+    /// Force `level` on the current thread until the guard is dropped.
+    pub fn new(level: SimdLevel) -> Self {
+        let previous = FORCED_LEVEL.with(|forced| forced.replace(Some(level)));
+        Self { previous }
     }
+}
 
-    #[cfg(target_arch = "wasm32")]
-    {
-        // SIMD128 is enabled at compile-time
-        return DispatchTable::new(
-            |data| unsafe { wasm32::simd128::sum(data) },
-            |a, b| unsafe {
-                match wasm32::simd128::dot_product(a, b) {
-                    Ok(result) => result,
-                    Err(e) => panic!("dot_product error: {}", e),
-                }
-            },
-        );
+#[cfg(feature = "std")]
+impl Drop for LevelGuard {
+    fn drop(&mut self) {
+        FORCED_LEVEL.with(|forced| forced.set(self.previous));
     }
+}
 
-    // Fall back to scalar implementation
-    DispatchTable::scalar()
+/// The dispatch table in effect for the current thread.
+#[inline]
+fn active_dispatch() -> &'static DispatchTable {
+    #[cfg(feature = "std")]
+    if let Some(level) = forced_level() {
+        return table_for_level(level).unwrap_or(&SCALAR_DISPATCH);
+    }
+    get_dispatch()
 }
 
 /// Get the global dispatch table, initializing it if necessary.
@@ -173,10 +481,28 @@ pub fn get_dispatch() -> &'static DispatchTable {
     DISPATCH.get_or_init(init_dispatch)
 }
 
+/// The SIMD level whose kernels the dispatched functions use on this thread.
+///
+/// This is the detected best level, initializing the dispatch table if needed,
+/// unless a [`LevelGuard`] forces another one. A forced level that this target or
+/// CPU does not support reports `Scalar`, since that is what actually runs.
+///
+/// # Examples
+///
+/// ```rust
+/// use ta_core::simd::{dispatch, SimdLevel};
+///
+/// println!("Dispatching to {:?}", dispatch::active_level());
+/// ```
+#[inline]
+pub fn active_level() -> SimdLevel {
+    active_dispatch().level
+}
+
 /// Calculate the sum of all elements in a slice.
 ///
-/// This function automatically dispatches to the best available SIMD implementation.
-/// The first call will initialize the dispatch table (~100-500ns), subsequent calls
+/// This function automatically dispatches to the best available SIMD implementation,
+/// or to the level forced on this thread by a [`LevelGuard`]. The first call will initialize the dispatch table (~100-500ns), subsequent calls
 /// have minimal overhead (~5-10ns).
 ///
 /// # Arguments
@@ -192,20 +518,20 @@ pub fn get_dispatch() -> &'static DispatchTable {
 /// ```rust
 /// use ta_core::simd::dispatch;
 ///
-/// let data = vec![1.0_f64, 2.0, 3.0];
+/// let data = vec![1.0, 2.0, 3.0];
 /// let result = dispatch::sum(&data);
 /// assert_eq!(result, 6.0);
 /// ```
 #[inline]
 pub fn sum(data: &[Float]) -> Float {
-    let dispatch = get_dispatch();
+    let dispatch = active_dispatch();
     (dispatch.sum)(data)
 }
 
 /// Calculate the dot product of two vectors.
 ///
-/// This function automatically dispatches to the best available SIMD implementation.
-/// The first call will initialize the dispatch table (~100-500ns), subsequent calls
+/// This function automatically dispatches to the best available SIMD implementation,
+/// or to the level forced on this thread by a [`LevelGuard`]. The first call will initialize the dispatch table (~100-500ns), subsequent calls
 /// have minimal overhead (~5-10ns).
 ///
 /// # Arguments
@@ -226,18 +552,291 @@ pub fn sum(data: &[Float]) -> Float {
 /// ```rust
 /// use ta_core::simd::dispatch;
 ///
-/// let a = vec![1.0_f64, 2.0, 3.0];
-/// let b = vec![4.0_f64, 5.0, 6.0];
+/// let a = vec![1.0, 2.0, 3.0];
+/// let b = vec![4.0, 5.0, 6.0];
 /// let result = dispatch::dot_product(&a, &b);
 /// // (1*4) + (2*5) + (3*6) = 32
 /// assert_eq!(result, 32.0);
 /// ```
 #[inline]
 pub fn dot_product(a: &[Float], b: &[Float]) -> Float {
-    let dispatch = get_dispatch();
+    let dispatch = active_dispatch();
     (dispatch.dot_product)(a, b)
 }
 
+/// Calculate the sum of all elements using the kernels of a specific SIMD level.
+///
+/// Ignores both the detected level and any [`LevelGuard`], which makes it useful for
+/// comparing levels side by side. A level that this target or CPU does not support
+/// falls back to the scalar kernel rather than executing unsupported instructions.
+///
+/// # Examples
+///
+/// ```rust
+/// use ta_core::simd::{dispatch, SimdLevel};
+///
+/// let data = [1.0, 2.0, 3.0];
+/// assert_eq!(dispatch::sum_with(&data, SimdLevel::Scalar), 6.0);
+/// ```
+#[inline]
+pub fn sum_with(data: &[Float], level: SimdLevel) -> Float {
+    let table = table_for_level(level).unwrap_or(&SCALAR_DISPATCH);
+    (table.sum)(data)
+}
+
+/// Calculate the dot product using the kernels of a specific SIMD level.
+///
+/// Unsupported levels fall back to scalar, as with [`sum_with`].
+///
+/// # Panics
+///
+/// Panics if the input vectors have different lengths.
+#[inline]
+pub fn dot_product_with(a: &[Float], b: &[Float], level: SimdLevel) -> Float {
+    let table = table_for_level(level).unwrap_or(&SCALAR_DISPATCH);
+    (table.dot_product)(a, b)
+}
+
+/// Calculate the minimum of a slice.
+///
+/// Dispatches like [`sum`]. NaN values are skipped, and an empty (or all-NaN) slice
+/// returns `Float::INFINITY` rather than an error, so results of sub-slices can be
+/// combined with `Float::min` directly.
+///
+/// # Examples
+///
+/// ```rust
+/// use ta_core::{simd::dispatch, Float};
+///
+/// assert_eq!(dispatch::min(&[4.0, -2.5, 7.0]), -2.5);
+/// assert_eq!(dispatch::min(&[]), Float::INFINITY);
+/// ```
+#[inline]
+pub fn min(data: &[Float]) -> Float {
+    let dispatch = active_dispatch();
+    (dispatch.min)(data)
+}
+
+/// Calculate the maximum of a slice.
+///
+/// Dispatches like [`sum`]. NaN values are skipped, and an empty (or all-NaN) slice
+/// returns `Float::NEG_INFINITY`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ta_core::{simd::dispatch, Float};
+///
+/// assert_eq!(dispatch::max(&[4.0, -2.5, 7.0]), 7.0);
+/// assert_eq!(dispatch::max(&[]), Float::NEG_INFINITY);
+/// ```
+#[inline]
+pub fn max(data: &[Float]) -> Float {
+    let dispatch = active_dispatch();
+    (dispatch.max)(data)
+}
+
+/// Calculate the minimum using the kernel of a specific SIMD level.
+///
+/// Unsupported levels fall back to scalar, as with [`sum_with`].
+#[inline]
+pub fn min_with(data: &[Float], level: SimdLevel) -> Float {
+    let table = table_for_level(level).unwrap_or(&SCALAR_DISPATCH);
+    (table.min)(data)
+}
+
+/// Calculate the maximum using the kernel of a specific SIMD level.
+///
+/// Unsupported levels fall back to scalar, as with [`sum_with`].
+#[inline]
+pub fn max_with(data: &[Float], level: SimdLevel) -> Float {
+    let table = table_for_level(level).unwrap_or(&SCALAR_DISPATCH);
+    (table.max)(data)
+}
+
+/// Calculate the sum of a slice, treating NaN and infinite values as zero.
+///
+/// Dispatches like [`sum`], but masks every vector before accumulating, so it is
+/// slower than [`sum`]; prefer the plain variant when the data is known to be clean.
+///
+/// # Examples
+///
+/// ```rust
+/// use ta_core::{simd::dispatch, Float};
+///
+/// assert_eq!(dispatch::nansum(&[1.0, Float::NAN, 2.0]), 3.0);
+/// ```
+#[inline]
+pub fn nansum(data: &[Float]) -> Float {
+    let dispatch = active_dispatch();
+    (dispatch.nansum)(data)
+}
+
+/// Calculate the dot product of two vectors, treating NaN and infinite values as zero.
+///
+/// A pair contributes nothing when either of its values is non-finite. Like
+/// [`nansum`] this is slower than the plain [`dot_product`].
+///
+/// # Panics
+///
+/// Panics if the input vectors have different lengths.
+///
+/// # Examples
+///
+/// ```rust
+/// use ta_core::{simd::dispatch, Float};
+///
+/// assert_eq!(dispatch::nandot(&[1.0, Float::NAN, 3.0], &[4.0, 5.0, 6.0]), 22.0);
+/// ```
+#[inline]
+pub fn nandot(a: &[Float], b: &[Float]) -> Float {
+    let dispatch = active_dispatch();
+    (dispatch.nandot)(a, b)
+}
+
+/// Calculate the arithmetic mean of a slice.
+///
+/// Dispatches the underlying sum like [`sum`]. The mean of an empty slice is NaN.
+///
+/// # Examples
+///
+/// ```rust
+/// use ta_core::simd::dispatch;
+///
+/// assert_eq!(dispatch::mean(&[1.0, 2.0, 6.0]), 3.0);
+/// ```
+#[inline]
+pub fn mean(data: &[Float]) -> Float {
+    sum(data) / data.len() as Float
+}
+
+/// Calculate the variance of a slice with `ddof` delta degrees of freedom.
+///
+/// Uses two passes, one for the mean and one accumulating squared deviations, which
+/// avoids the cancellation of the single-pass `E[x^2] - E[x]^2` form. As in NumPy,
+/// `ddof = 0` gives the population variance and `ddof = 1` the sample variance.
+///
+/// # Errors
+///
+/// Returns `TalibError::InvalidParameter` if `ddof >= data.len()`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ta_core::simd::dispatch;
+///
+/// let data = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+/// assert_eq!(dispatch::variance(&data, 0).unwrap(), 4.0);
+/// ```
+pub fn variance(data: &[Float], ddof: usize) -> Result<Float> {
+    check_ddof(ddof, data.len())?;
+    let table = active_dispatch();
+    let mean = (table.sum)(data) / data.len() as Float;
+    Ok((table.sum_sq_dev)(data, mean) / (data.len() - ddof) as Float)
+}
+
+/// Calculate the variance of every rolling window with `ddof` delta degrees of freedom.
+///
+/// Each window is reduced with the same two-pass kernels as [`variance`].
+///
+/// # Returns
+///
+/// A vector of variances with length `data.len() - window + 1`.
+///
+/// # Errors
+///
+/// Returns `TalibError::InvalidParameter` if `ddof >= window`.
+///
+/// # Panics
+///
+/// Panics if `window` is 0 or greater than the input data length.
+pub fn rolling_variance(data: &[Float], window: usize, ddof: usize) -> Result<Vec<Float>> {
+    assert!(window >= 1, "Window size must be at least 1");
+    assert!(
+        data.len() >= window,
+        "Data length must be at least window size"
+    );
+    check_ddof(ddof, window)?;
+
+    let table = active_dispatch();
+    let inv_window = 1.0 / window as Float;
+    let inv_dof = 1.0 / (window - ddof) as Float;
+    Ok(data
+        .windows(window)
+        .map(|values| {
+            let mean = (table.sum)(values) * inv_window;
+            (table.sum_sq_dev)(values, mean) * inv_dof
+        })
+        .collect())
+}
+
+fn check_ddof(ddof: usize, len: usize) -> Result<()> {
+    if ddof >= len {
+        return Err(TalibError::invalid_parameter(
+            "ddof".to_string(),
+            ddof.to_string(),
+            format!("less than the number of values ({})", len),
+        ));
+    }
+    Ok(())
+}
+
+/// Summary statistics of one rolling window, as returned by [`rolling_stats`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindowStats {
+    /// Arithmetic mean of the window
+    pub mean: Float,
+    /// Population standard deviation of the window
+    pub std: Float,
+    /// Smallest value in the window
+    pub min: Float,
+    /// Largest value in the window
+    pub max: Float,
+}
+
+/// Calculate mean, standard deviation, min and max of every rolling window.
+///
+/// Each window is reduced in a single pass by the dispatched fused kernel, which
+/// gathers all four statistics in one traversal instead of four. The deviations are
+/// taken from the window's first value, so the standard deviation stays accurate for
+/// windows with a large mean relative to their spread. NaN values are skipped by
+/// `min` and `max` but propagate to `mean` and `std`.
+///
+/// # Arguments
+///
+/// * `data` - Input slice of floating-point values
+/// * `window` - Size of the rolling window (must be >= 1)
+///
+/// # Returns
+///
+/// A vector of window statistics with length `data.len() - window + 1`.
+///
+/// # Panics
+///
+/// Panics if `window` is 0 or greater than the input data length.
+///
+/// # Examples
+///
+/// ```rust
+/// use ta_core::simd::dispatch;
+///
+/// let stats = dispatch::rolling_stats(&[1.0, 3.0, 2.0, 6.0], 2);
+/// assert_eq!(stats.len(), 3);
+/// assert_eq!(stats[0].mean, 2.0);
+/// assert_eq!(stats[0].std, 1.0);
+/// assert_eq!((stats[2].min, stats[2].max), (2.0, 6.0));
+/// ```
+pub fn rolling_stats(data: &[Float], window: usize) -> Vec<WindowStats> {
+    assert!(window >= 1, "Window size must be at least 1");
+    assert!(
+        data.len() >= window,
+        "Data length must be at least window size"
+    );
+
+    let window_stats = active_dispatch().window_stats;
+    data.windows(window).map(window_stats).collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -336,6 +935,311 @@ mod tests {
         dot_product(&a, &b);
     }
 
+    #[cfg(all(target_arch = "x86_64", feature = "std"))]
+    #[test]
+    fn test_x86_dot_product_matches_scalar() {
+        let a: Vec<Float> = (0..1003).map(|i| (i as Float * 0.1).sin()).collect();
+        let b: Vec<Float> = (0..1003).map(|i| (i as Float * 0.3).cos()).collect();
+        let expected = scalar::dot_product(&a, &b);
+        let magnitude: Float = a.iter().zip(b.iter()).map(|(x, y)| (x * y).abs()).sum();
+        let tolerance = 2.0 * a.len() as Float * Float::EPSILON * magnitude;
+
+        if std::is_x86_feature_detected!("avx2") {
+            let result = unsafe { x86_64::avx2::dot_product(&a, &b) }.unwrap();
+            assert!((result - expected).abs() <= tolerance);
+        }
+        if std::is_x86_feature_detected!("avx512f") {
+            let result = unsafe { x86_64::avx512::dot_product(&a, &b) }.unwrap();
+            assert!((result - expected).abs() <= tolerance);
+        }
+    }
+
+    #[cfg(all(target_arch = "x86_64", feature = "std"))]
+    #[test]
+    fn test_avx2_dot_product_long_vectors() {
+        if !std::is_x86_feature_detected!("avx2") {
+            return;
+        }
+        let a: Vec<Float> = (0..10_000).map(|i| (i as Float * 0.01).sin()).collect();
+        let b: Vec<Float> = (0..10_000).map(|i| (i as Float * 0.03).cos()).collect();
+        let expected = scalar::dot_product(&a, &b);
+        let magnitude: Float = a.iter().zip(b.iter()).map(|(x, y)| (x * y).abs()).sum();
+        let tolerance = 2.0 * a.len() as Float * Float::EPSILON * magnitude;
+        let result = unsafe { x86_64::avx2::dot_product(&a, &b) }.unwrap();
+        assert!((result - expected).abs() <= tolerance);
+    }
+
+    /// A series with scattered NaN and infinite values, and the same series with
+    /// those values replaced by zero.
+    fn dirty_and_clean(n: usize, offset: usize) -> (Vec<Float>, Vec<Float>) {
+        let dirty: Vec<Float> = (0..n)
+            .map(|i| match (i + offset) % 13 {
+                0 => Float::NAN,
+                7 => Float::INFINITY,
+                _ => (i as Float * 0.37).sin(),
+            })
+            .collect();
+        let clean = dirty
+            .iter()
+            .map(|&x| if x.is_finite() { x } else { 0.0 })
+            .collect();
+        (dirty, clean)
+    }
+
+    #[test]
+    fn test_nansum_matches_cleaned_sum() {
+        for n in [0, 5, 37, 1003] {
+            let (dirty, clean) = dirty_and_clean(n, 0);
+            let magnitude: Float = clean.iter().map(|x| x.abs()).sum();
+            let tolerance = 2.0 * n as Float * Float::EPSILON * magnitude + Float::MIN_POSITIVE;
+            assert!((nansum(&dirty) - sum(&clean)).abs() < tolerance);
+            assert!((scalar::nansum(&dirty) - scalar::sum(&clean)).abs() < tolerance);
+        }
+        assert_eq!(nansum(&[Float::NAN; 20]), 0.0);
+    }
+
+    #[test]
+    fn test_nandot_matches_cleaned_dot_product() {
+        for n in [0, 5, 37, 1003] {
+            let (dirty_a, clean_a) = dirty_and_clean(n, 0);
+            let (dirty_b, clean_b) = dirty_and_clean(n, 5);
+            let expected = dot_product(&clean_a, &clean_b);
+            let magnitude: Float = clean_a
+                .iter()
+                .zip(&clean_b)
+                .map(|(x, y)| (x * y).abs())
+                .sum();
+            let tolerance = 2.0 * n as Float * Float::EPSILON * magnitude + Float::MIN_POSITIVE;
+            assert!((nandot(&dirty_a, &dirty_b) - expected).abs() < tolerance);
+            assert!((scalar::nandot(&dirty_a, &dirty_b) - expected).abs() < tolerance);
+        }
+    }
+
+    #[cfg(all(target_arch = "x86_64", feature = "std"))]
+    #[test]
+    fn test_x86_nan_kernels_match_scalar() {
+        let (a, clean_a) = dirty_and_clean(1003, 0);
+        let (b, clean_b) = dirty_and_clean(1003, 5);
+        let sum_magnitude: Float = clean_a.iter().map(|x| x.abs()).sum();
+        let dot_magnitude: Float = clean_a
+            .iter()
+            .zip(&clean_b)
+            .map(|(x, y)| (x * y).abs())
+            .sum();
+        let scale = 2.0 * a.len() as Float * Float::EPSILON;
+        for level in [SimdLevel::Avx2, SimdLevel::Avx512] {
+            let Some(table) = table_for_level(level) else {
+                continue;
+            };
+            assert!(((table.nansum)(&a) - scalar::nansum(&a)).abs() <= scale * sum_magnitude);
+            assert!(
+                ((table.nandot)(&a, &b) - scalar::nandot(&a, &b)).abs() <= scale * dot_magnitude
+            );
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_level_guard_scopes_override() {
+        assert_eq!(forced_level(), None);
+        {
+            let _outer = LevelGuard::new(SimdLevel::Scalar);
+            assert_eq!(forced_level(), Some(SimdLevel::Scalar));
+            {
+                let _inner = LevelGuard::new(SimdLevel::Avx2);
+                assert_eq!(forced_level(), Some(SimdLevel::Avx2));
+                assert_eq!(sum(&[1.0, 2.0, 3.0]), 6.0);
+            }
+            assert_eq!(forced_level(), Some(SimdLevel::Scalar));
+            let data: Vec<Float> = (0..100).map(|i| (i as Float).sqrt()).collect();
+            assert_eq!(sum(&data), scalar::sum(&data));
+        }
+        assert_eq!(forced_level(), None);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_level_guard_is_thread_local() {
+        let _guard = LevelGuard::new(SimdLevel::Scalar);
+        let other = std::thread::spawn(forced_level).join().unwrap();
+        assert_eq!(other, None);
+        assert_eq!(forced_level(), Some(SimdLevel::Scalar));
+    }
+
+    #[test]
+    fn test_sum_with_levels_agree() {
+        // Integer-valued data sums exactly in any accumulation order
+        let a: Vec<Float> = (0..1001).map(|i| (i % 17) as Float - 8.0).collect();
+        let b: Vec<Float> = (0..1001).map(|i| (i % 5) as Float).collect();
+        let detected = SimdLevel::detect();
+        assert_eq!(sum_with(&a, SimdLevel::Scalar), sum_with(&a, detected));
+        assert_eq!(
+            dot_product_with(&a, &b, SimdLevel::Scalar),
+            dot_product_with(&a, &b, detected)
+        );
+        for level in [
+            SimdLevel::Avx512,
+            SimdLevel::Avx2,
+            SimdLevel::Neon,
+            SimdLevel::Simd128,
+        ] {
+            assert_eq!(sum_with(&a, level), scalar::sum(&a));
+            assert_eq!(dot_product_with(&a, &b, level), scalar::dot_product(&a, &b));
+        }
+    }
+
+    #[test]
+    fn test_min_max_with_levels_agree() {
+        let mut data: Vec<Float> = (0..1003)
+            .map(|i| ((i * 37) % 101) as Float - 50.0)
+            .collect();
+        data[500] = Float::NAN;
+        for len in [0, 1, 3, 8, 17, 1003] {
+            let slice = &data[..len];
+            let (min_ref, max_ref) = (scalar::min(slice), scalar::max(slice));
+            assert_eq!(min(slice), min_ref);
+            assert_eq!(max(slice), max_ref);
+            for level in [
+                SimdLevel::Scalar,
+                SimdLevel::Avx512,
+                SimdLevel::Avx2,
+                SimdLevel::Neon,
+                SimdLevel::Simd128,
+            ] {
+                assert_eq!(min_with(slice, level), min_ref, "{:?} len {}", level, len);
+                assert_eq!(max_with(slice, level), max_ref, "{:?} len {}", level, len);
+            }
+        }
+        assert_eq!(max(&data), 50.0);
+        assert_eq!(min(&data), -50.0);
+        assert_eq!(min(&[]), Float::INFINITY);
+        assert_eq!(max(&[Float::NAN]), Float::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_variance_matches_numpy() {
+        let data = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        assert_eq!(mean(&data), 5.0);
+        // np.var(data) and np.var(data, ddof=1)
+        assert!((variance(&data, 0).unwrap() - 4.0).abs() < 16.0 * Float::EPSILON);
+        assert!((variance(&data, 1).unwrap() - 32.0 / 7.0).abs() < 16.0 * Float::EPSILON);
+
+        // Large offset with a small spread: np.var(base + [4, 7, 13, 16]), with the
+        // base as large as the precision keeps the values exact
+        let base: Float = if cfg!(feature = "f32") { 1e6 } else { 1e9 };
+        let offset = [base + 4.0, base + 7.0, base + 13.0, base + 16.0];
+        let tolerance = 16.0 * Float::EPSILON * base;
+        assert!((variance(&offset, 0).unwrap() - 22.5).abs() < tolerance);
+    }
+
+    #[test]
+    fn test_variance_kernels_agree() {
+        let data: Vec<Float> = (0..1003)
+            .map(|i| 50.0 + (i as Float * 0.11).sin() * 7.0)
+            .collect();
+        let m = scalar::mean(&data);
+        let reference = scalar::sum_squared_deviations(&data, m);
+        let tolerance = data.len() as Float * Float::EPSILON * reference;
+        assert!((wide_sum_sq_dev(&data, m) - reference).abs() < tolerance);
+    }
+
+    #[test]
+    fn test_rolling_variance_matches_rolling_stats() {
+        let data: Vec<Float> = (0..120)
+            .map(|i| 200.0 + (i as Float * 0.3).cos() * 4.0)
+            .collect();
+        let rolling = rolling_variance(&data, 15, 0).unwrap();
+        let stats = rolling_stats(&data, 15);
+        assert_eq!(rolling.len(), stats.len());
+        // Rounding error in the deviations scales with the values, not their spread
+        let tolerance = 1e2 * Float::EPSILON * 200.0;
+        for (v, s) in rolling.iter().zip(&stats) {
+            assert!((v.sqrt() - s.std).abs() < tolerance);
+        }
+        let sample = rolling_variance(&data, 15, 1).unwrap();
+        assert!((sample[0] - rolling[0] * 15.0 / 14.0).abs() < 16.0 * Float::EPSILON * sample[0]);
+    }
+
+    #[test]
+    fn test_ddof_too_large() {
+        assert!(matches!(
+            variance(&[1.0, 2.0], 2),
+            Err(TalibError::InvalidParameter { .. })
+        ));
+        assert!(matches!(
+            rolling_variance(&[1.0, 2.0, 3.0, 4.0], 3, 3),
+            Err(TalibError::InvalidParameter { .. })
+        ));
+    }
+
+    #[test]
+    fn test_unsupported_level_has_no_table() {
+        assert!(table_for_level(SimdLevel::Scalar).is_some());
+        #[cfg(not(target_arch = "wasm32"))]
+        assert!(table_for_level(SimdLevel::Simd128).is_none());
+    }
+
+    #[test]
+    fn test_rolling_stats_matches_individual_functions() {
+        let data: Vec<Float> = (0..200)
+            .map(|i| 1000.0 + (i as Float * 0.7).sin() * 5.0)
+            .collect();
+        let window = 12;
+        let stats = rolling_stats(&data, window);
+        let sums = scalar::rolling_sum(&data, window);
+        assert_eq!(stats.len(), sums.len());
+
+        for (i, (s, &sum)) in stats.iter().zip(sums.iter()).enumerate() {
+            let values = &data[i..i + window];
+            let mean = sum / window as Float;
+            let variance =
+                values.iter().map(|x| (x - mean).powi(2)).sum::<Float>() / window as Float;
+            let min = values.iter().copied().fold(Float::INFINITY, Float::min);
+            let max = values.iter().copied().fold(Float::NEG_INFINITY, Float::max);
+            // Rounding scales with the values, which stay near 1000
+            assert!((s.mean - mean).abs() < 1e2 * Float::EPSILON * 1000.0);
+            assert!((s.std - variance.sqrt()).abs() < 1e2 * Float::EPSILON * 1000.0);
+            assert_eq!(s.min, min);
+            assert_eq!(s.max, max);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "at least window size")]
+    fn test_rolling_stats_window_too_large() {
+        rolling_stats(&[1.0, 2.0], 3);
+    }
+
+    #[test]
+    fn test_rolling_stats_accurate_at_large_mean() {
+        let base: Float = if cfg!(feature = "f32") { 1e4 } else { 1e9 };
+        let data: Vec<Float> = (0..100).map(|i| base + (i as Float * 0.9).sin()).collect();
+        let variances = rolling_variance(&data, 20, 0).unwrap();
+        for (v, s) in variances.iter().zip(rolling_stats(&data, 20)) {
+            // Both are accurate to rounding of the values, not of their square
+            assert!((v.sqrt() - s.std).abs() < 1e2 * Float::EPSILON * base);
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_rolling_stats_follows_forced_level() {
+        let data: Vec<Float> = (0..150)
+            .map(|i| 50.0 + (i as Float * 0.45).cos() * 3.0)
+            .collect();
+        let active = rolling_stats(&data, 40);
+        let scalar = {
+            let _guard = LevelGuard::new(SimdLevel::Scalar);
+            rolling_stats(&data, 40)
+        };
+        let tolerance = 1e2 * Float::EPSILON * 50.0;
+        for (a, s) in active.iter().zip(&scalar) {
+            assert!((a.mean - s.mean).abs() < tolerance);
+            assert!((a.std - s.std).abs() < tolerance);
+            assert_eq!((a.min, a.max), (s.min, s.max));
+        }
+    }
+
     #[test]
     fn test_dispatch_table_scalar() {
         let table = DispatchTable::scalar();
@@ -346,11 +1250,49 @@ mod tests {
         assert!((dot_result - 11.0 as Float).abs() < Float::from(1e-10));
     }
 
+    #[test]
+    fn test_active_level_reports_installed_kernels() {
+        #[cfg(all(target_arch = "x86_64", feature = "std"))]
+        {
+            let expected = if std::is_x86_feature_detected!("avx512f") {
+                SimdLevel::Avx512
+            } else if std::is_x86_feature_detected!("avx2") {
+                SimdLevel::Avx2
+            } else {
+                SimdLevel::Scalar
+            };
+            assert_eq!(active_level(), expected);
+        }
+        #[cfg(all(target_arch = "x86_64", not(feature = "std")))]
+        assert_eq!(active_level(), SimdLevel::Scalar);
+        assert_eq!(active_level(), get_dispatch().level);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_active_level_follows_guard() {
+        {
+            let _guard = LevelGuard::new(SimdLevel::Scalar);
+            assert_eq!(active_level(), SimdLevel::Scalar);
+        }
+        // NEON never runs on x86_64, so forcing it reports the scalar fallback
+        #[cfg(target_arch = "x86_64")]
+        {
+            let _guard = LevelGuard::new(SimdLevel::Neon);
+            assert_eq!(active_level(), SimdLevel::Scalar);
+        }
+    }
+
     #[test]
     fn test_dispatch_table_new() {
         let table = DispatchTable::new(
+            SimdLevel::Scalar,
             |data: &[Float]| data.iter().copied().sum(),
             |a: &[Float], b: &[Float]| a.iter().zip(b.iter()).map(|(x, y)| x * y).sum(),
+            scalar::min,
+            scalar::max,
+            scalar::nansum,
+            scalar::nandot,
         );
         let sum_result = (table.sum)(&[1.0 as Float, 2.0 as Float, 3.0 as Float]);
         assert!((sum_result - 6.0 as Float).abs() < Float::from(1e-10));
@@ -12,7 +12,7 @@
 //! ```rust
 //! use ta_core::simd;
 //!
-//! let data = vec![1.0_f64, 2.0, 3.0, 4.0];
+//! let data = vec![1.0, 2.0, 3.0, 4.0];
 //! let result = simd::sum(&data);  // Auto-dispatched to AVX2/AVX-512/NEON/SIMD128/Scalar
 //! assert_eq!(result, 10.0);
 //! ```
@@ -26,6 +26,7 @@
 use crate::Float;
 use wide;
 pub mod scalar;
+pub use scalar::{rolling_max, rolling_min};
 // Include arch module for all platforms with std support
 #[cfg(feature = "std")]
 mod arch;
@@ -35,6 +36,12 @@ use core::mem;
 use std::mem;
 
 pub mod dispatch;
+pub use dispatch::{
+    dot_product, max, mean, min, nandot, nansum, rolling_stats, rolling_variance, sum, variance,
+    WindowStats,
+};
+pub mod types;
+pub use types::SimdLevel;
 
 #[cfg(feature = "f32")]
 /// wide f32 Float
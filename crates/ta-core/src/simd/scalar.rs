@@ -3,7 +3,13 @@
 //!
 //! These serve as a portable fallback when no SIMD acceleration is available.
 //!
+#[cfg(feature = "fixed")]
+use crate::types::Fixed;
 use crate::types::Float;
+#[cfg(not(feature = "std"))]
+use alloc::collections::VecDeque;
+#[cfg(feature = "std")]
+use std::collections::VecDeque;
 
 /// Calculate sum of all elements in a slice using scalar operations.
 ///
@@ -23,7 +29,7 @@ use crate::types::Float;
 /// ```rust
 /// use ta_core::simd::scalar::sum;
 ///
-/// let data = vec![1.0_f32, 2.0, 3.0, 4.0, 5.0];
+/// let data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
 /// assert_eq!(sum(&data), 15.0);
 /// ```
 #[inline]
@@ -54,8 +60,8 @@ pub fn sum(data: &[Float]) -> Float {
 /// ```rust
 /// use ta_core::simd::scalar::dot_product;
 ///
-/// let a = vec![1.0_f32, 2.0, 3.0];
-/// let b = vec![4.0_f32, 5.0, 6.0];
+/// let a = vec![1.0, 2.0, 3.0];
+/// let b = vec![4.0, 5.0, 6.0];
 /// // (1*4) + (2*5) + (3*6) = 32
 /// assert_eq!(dot_product(&a, &b), 32.0);
 /// ```
@@ -70,6 +76,87 @@ pub fn dot_product(a: &[Float], b: &[Float]) -> Float {
     a.iter().zip(b.iter()).map(|(x, y)| x * y).sum()
 }
 
+/// Calculate the sum of a slice, treating NaN and infinite values as zero.
+///
+/// # Examples
+///
+/// ```rust
+/// use ta_core::{simd::scalar::nansum, Float};
+///
+/// assert_eq!(nansum(&[1.0, Float::NAN, 2.0, Float::INFINITY]), 3.0);
+/// ```
+#[inline]
+pub fn nansum(data: &[Float]) -> Float {
+    data.iter().filter(|x| x.is_finite()).sum()
+}
+
+/// Calculate the dot product of two vectors, treating NaN and infinite values as zero.
+///
+/// A pair contributes nothing when either of its values is non-finite.
+///
+/// # Panics
+///
+/// Panics if input vectors have different lengths.
+///
+/// # Examples
+///
+/// ```rust
+/// use ta_core::{simd::scalar::nandot, Float};
+///
+/// assert_eq!(nandot(&[1.0, Float::NAN, 3.0], &[4.0, 5.0, 6.0]), 22.0);
+/// ```
+#[inline]
+pub fn nandot(a: &[Float], b: &[Float]) -> Float {
+    assert_eq!(
+        a.len(),
+        b.len(),
+        "Dot product requires vectors of equal length"
+    );
+
+    a.iter()
+        .zip(b.iter())
+        .filter(|(x, y)| x.is_finite() && y.is_finite())
+        .map(|(x, y)| x * y)
+        .sum()
+}
+
+/// Calculate the minimum of all elements in a slice using scalar operations.
+///
+/// NaN values are skipped. The minimum of an empty (or all-NaN) slice is
+/// `Float::INFINITY`, the identity of `min`, so partial results can be combined
+/// without special cases.
+///
+/// # Examples
+///
+/// ```rust
+/// use ta_core::{simd::scalar::min, Float};
+///
+/// assert_eq!(min(&[3.0, -1.0, Float::NAN, 2.0]), -1.0);
+/// assert_eq!(min(&[]), Float::INFINITY);
+/// ```
+#[inline]
+pub fn min(data: &[Float]) -> Float {
+    data.iter().fold(Float::INFINITY, |a, &b| a.min(b))
+}
+
+/// Calculate the maximum of all elements in a slice using scalar operations.
+///
+/// NaN values are skipped. The maximum of an empty (or all-NaN) slice is
+/// `Float::NEG_INFINITY`, the identity of `max`.
+///
+/// # Examples
+///
+/// ```rust
+/// use ta_core::{simd::scalar::max, Float};
+///
+/// assert_eq!(max(&[3.0, -1.0, Float::NAN, 2.0]), 3.0);
+/// assert_eq!(max(&[]), Float::NEG_INFINITY);
+/// ```
+#[inline]
+pub fn max(data: &[Float]) -> Float {
+    data.iter().fold(Float::NEG_INFINITY, |a, &b| a.max(b))
+}
+
 /// Calculate rolling sums with a specified window size using scalar operations.
 ///
 /// This is fallback implementation when no SIMD acceleration is available.
@@ -97,7 +184,7 @@ pub fn dot_product(a: &[Float], b: &[Float]) -> Float {
 /// ```rust
 /// use ta_core::simd::scalar::rolling_sum;
 ///
-/// let data = vec![1.0_f32, 2.0, 3.0, 4.0, 5.0];
+/// let data = vec![1.0, 2.0, 3.0, 4.0, 5.0];
 /// let result = rolling_sum(&data, 3);
 /// // Windows: [1,2,3]=6, [2,3,4]=9, [3,4,5]=12
 /// assert_eq!(result, vec![6.0, 9.0, 12.0]);
@@ -128,9 +215,229 @@ pub fn rolling_sum(data: &[Float], window_size: usize) -> Vec<Float> {
     result
 }
 
+/// Calculate the arithmetic mean of a slice using scalar operations.
+///
+/// The mean of an empty slice is NaN.
+///
+/// # Examples
+///
+/// ```rust
+/// use ta_core::simd::scalar::mean;
+///
+/// assert_eq!(mean(&[1.0, 2.0, 6.0]), 3.0);
+/// ```
+#[inline]
+pub fn mean(data: &[Float]) -> Float {
+    sum(data) / data.len() as Float
+}
+
+/// Calculate the sum of squared deviations of a slice from `mean`.
+///
+/// This is the second pass of a two-pass variance: dividing the result by
+/// `data.len() - ddof` gives the variance.
+///
+/// # Examples
+///
+/// ```rust
+/// use ta_core::simd::scalar::sum_squared_deviations;
+///
+/// assert_eq!(sum_squared_deviations(&[1.0, 3.0], 2.0), 2.0);
+/// ```
+#[inline]
+pub fn sum_squared_deviations(data: &[Float], mean: Float) -> Float {
+    data.iter().map(|&x| (x - mean) * (x - mean)).sum()
+}
+
+/// Push `value` onto a monotonic deque of `(index, value)` pairs and return the
+/// extreme of the last `window_size` values with its index.
+///
+/// `dominated(back, new)` says whether the value at the back of the deque can never
+/// be the extreme again once `new` has arrived; entries it dominates and entries
+/// that slid out of the window are dropped. Ties should count as dominated, so the
+/// extreme is always the most recent one. Each index is pushed and popped at most
+/// once, so streaming a series costs amortized O(1) per value.
+#[inline]
+pub(crate) fn push_extreme(
+    deque: &mut VecDeque<(usize, Float)>,
+    index: usize,
+    value: Float,
+    window_size: usize,
+    dominated: impl Fn(Float, Float) -> bool,
+) -> (usize, Float) {
+    while deque.back().is_some_and(|&(_, v)| dominated(v, value)) {
+        deque.pop_back();
+    }
+    deque.push_back((index, value));
+    // Drop the front once it has slid out of the window
+    if deque[0].0 + window_size <= index {
+        deque.pop_front();
+    }
+    deque[0]
+}
+
+/// Rolling extreme of each window, streaming the data through [`push_extreme`].
+///
+/// The whole pass is O(n) regardless of the window size.
+#[inline]
+fn rolling_extreme(
+    data: &[Float],
+    window_size: usize,
+    dominated: impl Fn(Float, Float) -> bool,
+) -> Vec<Float> {
+    assert!(window_size >= 1, "Window size must be at least 1");
+    assert!(
+        data.len() >= window_size,
+        "Data length must be at least window size"
+    );
+
+    let mut deque = VecDeque::with_capacity(window_size);
+    data.iter()
+        .enumerate()
+        .filter_map(|(i, &value)| {
+            let (_, extreme) = push_extreme(&mut deque, i, value, window_size, &dominated);
+            (i + 1 >= window_size).then_some(extreme)
+        })
+        .collect()
+}
+
+/// Calculate rolling minimums with a specified window size.
+///
+/// Uses an ascending monotonic deque, so the cost is O(n) rather than O(n * w).
+///
+/// # Returns
+///
+/// A vector containing the rolling minimums with length `data.len() - window_size + 1`.
+///
+/// # Panics
+///
+/// Panics if `window_size` is 0 or greater than the input data length.
+///
+/// # Examples
+///
+/// ```rust
+/// use ta_core::simd::scalar::rolling_min;
+///
+/// let data = vec![3.0, 1.0, 4.0, 1.0, 5.0];
+/// assert_eq!(rolling_min(&data, 3), vec![1.0, 1.0, 1.0]);
+/// ```
+#[inline]
+pub fn rolling_min(data: &[Float], window_size: usize) -> Vec<Float> {
+    rolling_extreme(data, window_size, |back, new| back >= new)
+}
+
+/// Calculate rolling maximums with a specified window size.
+///
+/// Uses a descending monotonic deque, so the cost is O(n) rather than O(n * w).
+///
+/// # Returns
+///
+/// A vector containing the rolling maximums with length `data.len() - window_size + 1`.
+///
+/// # Panics
+///
+/// Panics if `window_size` is 0 or greater than the input data length.
+///
+/// # Examples
+///
+/// ```rust
+/// use ta_core::simd::scalar::rolling_max;
+///
+/// let data = vec![3.0, 1.0, 4.0, 1.0, 5.0];
+/// assert_eq!(rolling_max(&data, 3), vec![4.0, 4.0, 5.0]);
+/// ```
+#[inline]
+pub fn rolling_max(data: &[Float], window_size: usize) -> Vec<Float> {
+    rolling_extreme(data, window_size, |back, new| back <= new)
+}
+
+/// Calculate product of all elements in a slice using scalar operations.
+///
+/// The product of an empty slice is 1. No overflow protection is applied, so long
+/// slices of large values can reach infinity; prefer summing logarithms when the
+/// inputs are known to be positive.
+///
+/// # Examples
+///
+/// ```rust
+/// use ta_core::simd::scalar::product;
+///
+/// let data = vec![1.0, 2.0, 3.0, 4.0];
+/// assert_eq!(product(&data), 24.0);
+/// ```
+#[inline]
+pub fn product(data: &[Float]) -> Float {
+    data.iter().product()
+}
+
+/// Calculate sum of fixed-point values.
+///
+/// Unlike the floating-point [`sum`], this is bit-identical on every target, since it
+/// only uses integer addition. Only available with the experimental `fixed` feature.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use ta_core::simd::scalar::sum_fixed;
+/// use ta_core::types::Fixed;
+///
+/// let data = [Fixed::from_int(1), Fixed::from_int(2)];
+/// assert_eq!(sum_fixed(&data), Fixed::from_int(3));
+/// ```
+#[cfg(feature = "fixed")]
+#[inline]
+pub fn sum_fixed(data: &[Fixed]) -> Fixed {
+    data.iter().copied().sum()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[test]
+    fn test_rolling_min_max() {
+        let data = [3.0, 1.0, 4.0, 1.0, 5.0, 9.0, 2.0];
+        assert_eq!(rolling_min(&data, 3), vec![1.0, 1.0, 1.0, 1.0, 2.0]);
+        assert_eq!(rolling_max(&data, 3), vec![4.0, 4.0, 5.0, 9.0, 9.0]);
+        assert_eq!(rolling_min(&data, 1), data.to_vec());
+        assert_eq!(rolling_max(&data, 7), vec![9.0]);
+    }
+
+    #[test]
+    fn test_rolling_min_max_match_naive() {
+        let data: Vec<Float> = (0..300)
+            .map(|i| ((i * 37) % 101) as Float - (i % 7) as Float)
+            .collect();
+        for window in [2, 5, 16, 300] {
+            let naive_min: Vec<Float> = data
+                .windows(window)
+                .map(|w| w.iter().copied().fold(Float::INFINITY, Float::min))
+                .collect();
+            let naive_max: Vec<Float> = data
+                .windows(window)
+                .map(|w| w.iter().copied().fold(Float::NEG_INFINITY, Float::max))
+                .collect();
+            assert_eq!(rolling_min(&data, window), naive_min);
+            assert_eq!(rolling_max(&data, window), naive_max);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Window size must be at least 1")]
+    fn test_rolling_min_zero_window() {
+        let _ = rolling_min(&[1.0, 2.0], 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Data length must be at least window size")]
+    fn test_rolling_max_window_too_large() {
+        let _ = rolling_max(&[1.0, 2.0], 3);
+    }
+
+    #[test]
+    fn test_product() {
+        assert_eq!(product(&[]), 1.0);
+        assert_eq!(product(&[2.0, -3.0, 0.5]), -3.0);
+    }
+
     #[test]
     fn test_sum_empty() {
         let data: Vec<Float> = vec![];
@@ -291,7 +598,7 @@ mod tests {
 
     #[test]
     fn test_rolling_sum_large_window() {
-        let data: Vec<Float> = (1..=100).map(|i| Float::from(i as f64)).collect();
+        let data: Vec<Float> = (1..=100).map(|i| i as Float).collect();
         let result = rolling_sum(&data, 10);
         assert_eq!(result.len(), 91);
         // First window: 1+2+...+10 = 55, Last window: 90+91+92+...+100 = 955
@@ -313,4 +620,15 @@ mod tests {
         assert_eq!(result.len(), 1);
         assert_eq!(result[0], sum(&data));
     }
+
+    #[cfg(feature = "fixed")]
+    #[test]
+    fn test_sum_fixed_is_exact() {
+        let data: Vec<Fixed> = (0..1000).map(|_| Fixed::from_float(0.1)).collect();
+        let expected = Fixed::from_bits(Fixed::from_float(0.1).to_bits() * 1000);
+        assert_eq!(sum_fixed(&data), expected);
+        // Order independent, which float summation is not
+        let reversed: Vec<Fixed> = data.iter().rev().copied().collect();
+        assert_eq!(sum_fixed(&reversed), sum_fixed(&data));
+    }
 }
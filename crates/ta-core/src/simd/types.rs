@@ -38,7 +38,11 @@ impl SimdLevel {
     /// Detect the best available SIMD level at runtime.
     ///
     /// This function checks the CPU features and returns the highest supported
-    /// SIMD level for the current platform.
+    /// SIMD level for the current platform. On x86_64 with the `std` feature the
+    /// CPU is queried at runtime, so a default build still reports AVX2/AVX-512 on
+    /// machines that have them. Without `std`, `is_x86_feature_detected!` is not
+    /// available and only features enabled at compile time (for example via
+    /// `-C target-feature=+avx2`) are reported.
     ///
     /// # Examples
     ///
@@ -50,20 +54,25 @@ impl SimdLevel {
     /// ```
     #[inline]
     pub fn detect() -> Self {
-        // Detect AVX-512
-        #[cfg(target_arch = "x86_64")]
+        // Detect AVX-512 / AVX2 at runtime
+        #[cfg(all(target_arch = "x86_64", feature = "std"))]
         {
-            #[cfg(target_feature = "avx512f")]
-            {
-                if is_x86_feature_detected!("avx512f") {
-                    return SimdLevel::Avx512;
-                }
+            if std::is_x86_feature_detected!("avx512f") {
+                return SimdLevel::Avx512;
             }
-            #[cfg(target_feature = "avx2")]
-            {
-                if is_x86_feature_detected!("avx2") {
-                    return SimdLevel::Avx2;
-                }
+            if std::is_x86_feature_detected!("avx2") {
+                return SimdLevel::Avx2;
+            }
+        }
+
+        // Without std, fall back to what the build targets
+        #[cfg(all(target_arch = "x86_64", not(feature = "std")))]
+        {
+            if cfg!(target_feature = "avx512f") {
+                return SimdLevel::Avx512;
+            }
+            if cfg!(target_feature = "avx2") {
+                return SimdLevel::Avx2;
             }
         }
 
@@ -104,10 +113,11 @@ impl SimdLevel {
     /// # Examples
     ///
     /// ```rust
-    /// use ta_core::simd::SimdLevel;
+    /// use ta_core::{simd::SimdLevel, Float};
     ///
-    /// // f64 (default)
-    /// assert_eq!(SimdLevel::Avx2.lanes(), 4);
+    /// // 4 lanes with f64 (default), 8 with the "f32" feature
+    /// let float_bytes = core::mem::size_of::<Float>();
+    /// assert_eq!(SimdLevel::Avx2.lanes(), 32 / float_bytes);
     /// assert_eq!(SimdLevel::Scalar.lanes(), 1);
     /// ```
     #[inline]
@@ -133,6 +143,29 @@ impl SimdLevel {
         }
     }
 
+    /// Get the chunk granularity of this SIMD level: the number of values in one vector.
+    ///
+    /// Custom kernels can walk their input in `chunks_exact(chunk_size())` blocks and
+    /// finish the remainder with scalar code. This is the smallest block a vector
+    /// kernel works on, not a description of how each library kernel splits its
+    /// input: the x86 dot products, for example, process several vectors per step to
+    /// keep independent accumulators. This currently equals [`SimdLevel::lanes`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ta_core::simd::SimdLevel;
+    ///
+    /// let level = SimdLevel::detect();
+    /// let data = vec![1.0; 10];
+    /// let chunks = data.chunks_exact(level.chunk_size());
+    /// assert_eq!(chunks.len(), 10 / level.lanes());
+    /// ```
+    #[inline]
+    pub fn chunk_size(&self) -> usize {
+        self.lanes()
+    }
+
     /// Get the vector width in bits for this SIMD level.
     ///
     /// # f64 (default):
@@ -152,11 +185,11 @@ impl SimdLevel {
     /// # Examples
     ///
     /// ```rust
-    /// use ta_core::simd::SimdLevel;
+    /// use ta_core::{simd::SimdLevel, Float};
     ///
-    /// // f64 (default)
+    /// // Scalar is one `Float` wide: 64 bits with f64 (default), 32 with "f32"
     /// assert_eq!(SimdLevel::Avx2.width_bits(), 256);
-    /// assert_eq!(SimdLevel::Scalar.width_bits(), 64);
+    /// assert_eq!(SimdLevel::Scalar.width_bits(), 8 * core::mem::size_of::<Float>());
     /// ```
     #[inline]
     pub fn width_bits(&self) -> usize {
@@ -375,12 +408,20 @@ pub type SimdVecSimd128 = wide::f32x4;
 #[allow(dead_code)]
 pub const SIMD_LANES: usize = Lanes::AVX2;
 
+/// Helpers shared by the `wide` vector types used in the arch kernels.
 #[allow(dead_code)]
 pub trait SimdVecExt {
+    /// Vector with every lane set to zero.
     const ZERO: Self;
 
+    /// Load the first lane-count values of `data` into a vector.
+    ///
+    /// # Safety
+    ///
+    /// `data` must contain at least as many elements as the vector has lanes.
     unsafe fn from_slice_unaligned(data: &[crate::types::Float]) -> Self;
 
+    /// Sum all lanes of the vector.
     fn horizontal_sum(self) -> crate::types::Float;
 }
 
@@ -447,7 +488,8 @@ impl SimdVecExt for wide::f32x16 {
 
     #[inline]
     fn horizontal_sum(self) -> crate::types::Float {
-        self.reduce_add()
+        // `wide` has no `reduce_add` for the 16-lane type
+        self.to_array().iter().sum()
     }
 }
 
@@ -484,7 +526,6 @@ impl SimdVecExt for wide::f32x4 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloc::format;
 
     #[test]
     fn test_lanes_constants() {
@@ -533,6 +574,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_simd_level_chunk_size_matches_lanes() {
+        for level in [
+            SimdLevel::Scalar,
+            SimdLevel::Avx2,
+            SimdLevel::Avx512,
+            SimdLevel::Neon,
+            SimdLevel::Simd128,
+        ] {
+            assert_eq!(level.chunk_size(), level.lanes());
+        }
+        assert_eq!(
+            SimdLevel::detect().chunk_size(),
+            SimdLevel::detect().lanes()
+        );
+    }
+
+    #[cfg(all(target_arch = "x86_64", feature = "std"))]
+    #[test]
+    fn test_detect_uses_runtime_cpu_features() {
+        let level = SimdLevel::detect();
+        if std::is_x86_feature_detected!("avx512f") {
+            assert_eq!(level, SimdLevel::Avx512);
+        } else if std::is_x86_feature_detected!("avx2") {
+            assert_eq!(level, SimdLevel::Avx2);
+        } else {
+            assert_eq!(level, SimdLevel::Scalar);
+        }
+    }
+
     #[test]
     fn test_simd_level_width_bits() {
         #[cfg(all(feature = "f64", not(feature = "f32")))]
@@ -0,0 +1,109 @@
+//! Shared smoothing stages used to compose indicators.
+
+use crate::{
+    overlap::{MaKind, SMA},
+    Float, Indicator,
+};
+
+/// Exponential smoothing stage seeded with the SMA of its first `period` values.
+#[derive(Debug, Clone)]
+pub(crate) struct EmaStage {
+    period: usize,
+    alpha: Float,
+    count: usize,
+    value: Float,
+}
+
+impl EmaStage {
+    pub(crate) fn new(period: usize) -> Self {
+        Self::with_alpha(period, 2.0 / (period as Float + 1.0))
+    }
+
+    /// Wilder's smoothing: the same recurrence with `alpha = 1 / period`.
+    pub(crate) fn wilder(period: usize) -> Self {
+        Self::with_alpha(period, 1.0 / period as Float)
+    }
+
+    pub(crate) fn with_alpha(period: usize, alpha: Float) -> Self {
+        Self {
+            period,
+            alpha,
+            count: 0,
+            value: 0.0,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn next(&mut self, input: Float) -> Float {
+        if self.count < self.period {
+            self.count += 1;
+            self.value += input;
+            if self.count < self.period {
+                return Float::NAN;
+            }
+            self.value /= self.period as Float;
+        } else {
+            self.value += self.alpha * (input - self.value);
+        }
+        self.value
+    }
+}
+
+/// `D` EMAs of the same period, each smoothing the output of the one before.
+///
+/// A stage only receives values once the stage feeding it is warm, so level `d`
+/// (zero-based) first produces a value after `(d + 1) * (period - 1)` inputs.
+#[derive(Debug, Clone)]
+pub(crate) struct EmaChain<const D: usize> {
+    stages: [EmaStage; D],
+}
+
+impl<const D: usize> EmaChain<D> {
+    /// `period` must be non-zero; callers validate it first.
+    pub(crate) fn new(period: usize) -> Self {
+        Self {
+            stages: core::array::from_fn(|_| EmaStage::new(period)),
+        }
+    }
+
+    /// Returns every level of the chain, `Float::NAN` for levels still warming up.
+    #[inline]
+    pub(crate) fn next(&mut self, input: Float) -> [Float; D] {
+        let mut levels = [Float::NAN; D];
+        let mut value = input;
+        for (stage, level) in self.stages.iter_mut().zip(levels.iter_mut()) {
+            value = stage.next(value);
+            if value.is_nan() {
+                break;
+            }
+            *level = value;
+        }
+        levels
+    }
+}
+
+/// Streaming moving average of a configurable [`MaKind`].
+#[derive(Debug, Clone)]
+pub(crate) enum MaStage {
+    Sma(SMA),
+    Exponential(EmaStage),
+}
+
+impl MaStage {
+    /// `period` must be non-zero; callers validate it first.
+    pub(crate) fn new(kind: MaKind, period: usize) -> Self {
+        match kind {
+            MaKind::Sma => MaStage::Sma(SMA::new(period)),
+            MaKind::Ema => MaStage::Exponential(EmaStage::new(period)),
+            MaKind::Wilder => MaStage::Exponential(EmaStage::wilder(period)),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn next(&mut self, input: Float) -> Float {
+        match self {
+            MaStage::Sma(sma) => sma.next(input),
+            MaStage::Exponential(ema) => ema.next(input),
+        }
+    }
+}
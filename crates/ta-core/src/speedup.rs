@@ -0,0 +1,86 @@
+//! Runtime measurement of the SIMD speedup on the current machine
+
+extern crate std;
+
+use std::time::{Duration, Instant};
+
+use crate::{
+    overlap::WMA,
+    simd::{dispatch::LevelGuard, SimdLevel},
+    Float, Indicator,
+};
+
+/// Timed runs per path; the fastest one is kept to damp scheduler noise.
+const RUNS: usize = 7;
+
+/// Measure how much faster the detected SIMD level is than the scalar kernels.
+///
+/// Times `WMA::compute_to_vec`, whose every window goes through the dispatched dot
+/// product, over the same synthetic `size`-point series twice: once at the level
+/// active on this thread and once with [`SimdLevel::Scalar`] forced through a
+/// [`LevelGuard`]. Returns `scalar_time / simd_time`, so values above 1 mean SIMD is
+/// helping on this CPU; on a host without a SIMD level both runs use the same
+/// kernels and the ratio is about 1. The measurement takes a few milliseconds for
+/// large sizes, so call it once (for example at startup or in a diagnostic command)
+/// rather than per series.
+///
+/// Only available with the `std` feature.
+///
+/// # Panics
+///
+/// Panics if `period` is zero or greater than `size`.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// let ratio = ta_core::benchmark_speedup(64, 100_000);
+/// println!("SIMD kernels run {ratio:.2}x as fast as scalar");
+/// ```
+pub fn benchmark_speedup(period: usize, size: usize) -> f64 {
+    assert!(period > 0, "Period must be greater than 0");
+    assert!(size >= period, "Size must be at least the period");
+
+    let data: Vec<Float> = (0..size)
+        .map(|i| 100.0 + (i as Float * 0.01).sin() * 5.0)
+        .collect();
+    let wma = WMA::new(period).expect("period is non-zero");
+    let run = || {
+        let _ = core::hint::black_box(wma.compute_to_vec(core::hint::black_box(&data)));
+    };
+
+    let simd = best_time(run);
+    let scalar = {
+        let _guard = LevelGuard::new(SimdLevel::Scalar);
+        best_time(run)
+    };
+    scalar.as_secs_f64() / simd.as_secs_f64().max(f64::MIN_POSITIVE)
+}
+
+fn best_time(mut run: impl FnMut()) -> Duration {
+    (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            run();
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(debug_assertions))]
+    #[test]
+    fn test_speedup_is_positive_and_finite() {
+        let ratio = benchmark_speedup(64, 100_000);
+        assert!(ratio.is_finite() && ratio > 0.0, "ratio {ratio}");
+    }
+
+    #[test]
+    #[should_panic(expected = "at least the period")]
+    fn test_size_below_period_panics() {
+        benchmark_speedup(20, 10);
+    }
+}
@@ -0,0 +1,128 @@
+//! Implementation of rolling Jensen-style alpha.
+
+use super::moments::PairWindow;
+use crate::{Float, Indicator, Resettable, Result, TalibError};
+
+/// Rolling alpha of an asset against a market
+///
+/// Takes `(asset, market)` return pairs and, over each window of `period` pairs,
+/// computes
+///
+/// ```text
+/// beta  = cov(asset, market) / var(market)
+/// alpha = mean(asset) - beta * mean(market)
+/// ```
+///
+/// Alpha is the return left over once the market-explained part is removed. When
+/// the market returns are flat over the window, beta is undefined and the output
+/// is `Float::NAN`.
+#[derive(Debug, Clone)]
+pub struct Alpha {
+    period: usize,
+    window: PairWindow,
+}
+
+impl Alpha {
+    /// Create a rolling alpha over `period` pairs.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if `period < 2`.
+    pub fn new(period: usize) -> Result<Self> {
+        if period < 2 {
+            return Err(TalibError::invalid_period(
+                period,
+                "period must be at least 2 to estimate beta",
+            ));
+        }
+        Ok(Self {
+            period,
+            window: PairWindow::new(period),
+        })
+    }
+}
+
+impl Indicator for Alpha {
+    type Input = (Float, Float);
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        format!("Alpha({})", self.period)
+    }
+
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut alpha = Self::new(self.period)?;
+        Ok(inputs.iter().map(|&pair| alpha.next(pair)).collect())
+    }
+
+    fn next(&mut self, (asset, market): (Float, Float)) -> Float {
+        let Some(moments) = self.window.push(asset, market) else {
+            return Float::NAN;
+        };
+        match moments.slope() {
+            Some(beta) => moments.mean_a - beta * moments.mean_b,
+            None => Float::NAN,
+        }
+    }
+}
+
+impl Resettable for Alpha {
+    fn reset(&mut self) {
+        self.window = PairWindow::new(self.period);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn market(n: usize) -> Vec<Float> {
+        (0..n)
+            .map(|i| (i as Float * 0.7).sin() * 0.02 + 0.001)
+            .collect()
+    }
+
+    #[test]
+    fn test_pure_beta_has_zero_alpha() {
+        let pairs: Vec<(Float, Float)> = market(80).into_iter().map(|m| (1.5 * m, m)).collect();
+        let out = Alpha::new(20).unwrap().compute_to_vec(&pairs).unwrap();
+        assert!(out[..19].iter().all(|v| v.is_nan()));
+        for &v in &out[19..] {
+            assert!(v.abs() < 1e2 * Float::EPSILON, "alpha {v}");
+        }
+    }
+
+    #[test]
+    fn test_constant_excess_return_is_alpha() {
+        let pairs: Vec<(Float, Float)> = market(60)
+            .into_iter()
+            .map(|m| (0.8 * m + 0.003, m))
+            .collect();
+        let out = Alpha::new(10).unwrap().compute_to_vec(&pairs).unwrap();
+        for &v in &out[9..] {
+            assert!((v - 0.003).abs() < 1e2 * Float::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_flat_market_is_nan() {
+        let mut alpha = Alpha::new(3).unwrap();
+        for asset in [0.01, 0.02, 0.03] {
+            assert!(alpha.next((asset, 0.001)).is_nan());
+        }
+    }
+
+    #[test]
+    fn test_reset_and_invalid_period() {
+        assert!(Alpha::new(1).is_err());
+        let mut alpha = Alpha::new(2).unwrap();
+        alpha.next((0.01, 0.02));
+        alpha.reset();
+        assert!(alpha.next((0.03, 0.01)).is_nan());
+    }
+}
@@ -0,0 +1,156 @@
+//! Implementation of the rolling maximum drawdown.
+
+use crate::{Float, Indicator, Resettable, Result, TalibError};
+
+/// Rolling maximum drawdown
+///
+/// For each window of `period` values (typically an equity curve or prices), the
+/// largest peak-to-trough decline, as a fraction of the peak:
+///
+/// ```text
+/// drawdown = min over window of (value - running_max) / running_max
+/// ```
+///
+/// where `running_max` is the highest value seen so far within the same window.
+/// Outputs are zero or negative; `-0.25` means a 25% decline. A window that only
+/// rises gives 0. Values are expected to be positive, as with prices and equity.
+#[derive(Debug, Clone)]
+pub struct MaxDrawdown {
+    period: usize,
+    buffer: Vec<Float>,
+    ordered: Vec<Float>,
+    index: usize,
+    is_full: bool,
+}
+
+impl MaxDrawdown {
+    /// Create a rolling maximum drawdown over `period` values.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if `period < 2`.
+    pub fn new(period: usize) -> Result<Self> {
+        if period < 2 {
+            return Err(TalibError::invalid_period(
+                period,
+                "a drawdown needs a period of at least 2",
+            ));
+        }
+        Ok(Self {
+            period,
+            buffer: vec![0.0; period],
+            ordered: vec![0.0; period],
+            index: 0,
+            is_full: false,
+        })
+    }
+}
+
+/// Maximum drawdown of a window given in chronological order.
+fn window_drawdown(window: &[Float]) -> Float {
+    let mut peak = Float::NEG_INFINITY;
+    let mut worst: Float = 0.0;
+    for &value in window {
+        peak = peak.max(value);
+        worst = worst.min((value - peak) / peak);
+    }
+    worst
+}
+
+impl Indicator for MaxDrawdown {
+    type Input = Float;
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        format!("MaxDrawdown({})", self.period)
+    }
+
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut result = vec![Float::NAN; inputs.len()];
+        for (i, window) in inputs.windows(self.period).enumerate() {
+            result[i + self.period - 1] = window_drawdown(window);
+        }
+        Ok(result)
+    }
+
+    fn next(&mut self, input: Float) -> Float {
+        self.buffer[self.index] = input;
+        if !self.is_full && self.index == self.period - 1 {
+            self.is_full = true;
+        }
+        self.index = (self.index + 1) % self.period;
+        if !self.is_full {
+            return Float::NAN;
+        }
+        // The running max depends on order, so unroll the ring oldest-first
+        let (newest, oldest) = self.buffer.split_at(self.index);
+        self.ordered[..oldest.len()].copy_from_slice(oldest);
+        self.ordered[oldest.len()..].copy_from_slice(newest);
+        window_drawdown(&self.ordered)
+    }
+}
+
+impl Resettable for MaxDrawdown {
+    fn reset(&mut self) {
+        self.index = 0;
+        self.is_full = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clear_drawdown() {
+        // Peak at 120, trough at 90: a 25% decline
+        let inputs = [100.0, 120.0, 110.0, 90.0, 105.0, 130.0];
+        let out = MaxDrawdown::new(5)
+            .unwrap()
+            .compute_to_vec(&inputs)
+            .unwrap();
+        assert!(out[..4].iter().all(|v| v.is_nan()));
+        assert!((out[4] + 0.25).abs() < 1e-12);
+        // Window [120, 110, 90, 105, 130] still holds the same decline
+        assert!((out[5] + 0.25).abs() < 1e-12);
+
+        let later = MaxDrawdown::new(3)
+            .unwrap()
+            .compute_to_vec(&inputs)
+            .unwrap();
+        // Window [90, 105, 130] never declines
+        assert_eq!(later[5], 0.0);
+    }
+
+    #[test]
+    fn test_rising_series_has_zero_drawdown() {
+        let inputs: Vec<Float> = (1..50).map(|i| i as Float).collect();
+        let out = MaxDrawdown::new(10)
+            .unwrap()
+            .compute_to_vec(&inputs)
+            .unwrap();
+        assert!(out[9..].iter().all(|&v| v == 0.0));
+    }
+
+    #[test]
+    fn test_streaming_matches_batch() {
+        let inputs: Vec<Float> = (0..80)
+            .map(|i| 100.0 + (i as Float * 0.3).sin() * 15.0 + i as Float * 0.2)
+            .collect();
+        let batch = MaxDrawdown::new(12)
+            .unwrap()
+            .compute_to_vec(&inputs)
+            .unwrap();
+        let mut stream = MaxDrawdown::new(12).unwrap();
+        for (x, expected) in inputs.iter().zip(&batch) {
+            let v = stream.next(*x);
+            assert!((v.is_nan() && expected.is_nan()) || v == *expected);
+        }
+        assert!(MaxDrawdown::new(1).is_err());
+    }
+}
@@ -0,0 +1,137 @@
+//! Implementation of the compounded equity curve.
+
+use crate::{transform::ReturnKind, Float, Indicator, Resettable, Result, TalibError};
+
+/// Equity curve compounded from a series of returns
+///
+/// Starting from `start` capital, each simple return `r` multiplies the equity by
+/// `1 + r`; with [`ReturnKind::Log`], log returns are summed and the equity is
+/// `start * exp(Σ r)`. Every input produces an output, so `lookback() == 0`.
+///
+/// A non-finite return yields `Float::NAN` and is skipped, leaving the equity where
+/// it was.
+#[derive(Debug, Clone)]
+pub struct EquityCurve {
+    start: Float,
+    kind: ReturnKind,
+    /// Running growth factor for simple returns, running log sum for log returns
+    accumulator: Float,
+}
+
+impl EquityCurve {
+    /// Create an equity curve starting at `start` capital.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidParameter` unless `start` is finite and positive.
+    pub fn new(start: Float, kind: ReturnKind) -> Result<Self> {
+        if !(start.is_finite() && start > 0.0) {
+            return Err(TalibError::invalid_parameter(
+                "start".to_string(),
+                start.to_string(),
+                "a finite value greater than zero".to_string(),
+            ));
+        }
+        Ok(Self {
+            start,
+            kind,
+            accumulator: Self::initial(kind),
+        })
+    }
+
+    fn initial(kind: ReturnKind) -> Float {
+        match kind {
+            ReturnKind::Simple => 1.0,
+            ReturnKind::Log => 0.0,
+        }
+    }
+}
+
+impl Indicator for EquityCurve {
+    type Input = Float;
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        format!("EquityCurve({},{:?})", self.start, self.kind)
+    }
+
+    fn lookback(&self) -> usize {
+        0
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut curve = Self::new(self.start, self.kind)?;
+        Ok(inputs.iter().map(|&r| curve.next(r)).collect())
+    }
+
+    fn next(&mut self, input: Float) -> Float {
+        if !input.is_finite() {
+            return Float::NAN;
+        }
+        match self.kind {
+            ReturnKind::Simple => {
+                self.accumulator *= 1.0 + input;
+                self.start * self.accumulator
+            }
+            ReturnKind::Log => {
+                self.accumulator += input;
+                self.start * self.accumulator.exp()
+            }
+        }
+    }
+}
+
+impl Resettable for EquityCurve {
+    fn reset(&mut self) {
+        self.accumulator = Self::initial(self.kind);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constant_return_matches_closed_form() {
+        let returns = vec![0.01; 250];
+        let curve = EquityCurve::new(1000.0, ReturnKind::Simple)
+            .unwrap()
+            .compute_to_vec(&returns)
+            .unwrap();
+        for (n, &equity) in curve.iter().enumerate() {
+            let expected = 1000.0 * (1.01 as Float).powi(n as i32 + 1);
+            // Each of the 250 compounding steps rounds once
+            assert!((equity - expected).abs() / expected < 1e3 * Float::EPSILON);
+        }
+    }
+
+    #[test]
+    fn test_log_returns_match_simple_compounding() {
+        let simple: Vec<Float> = (0..50).map(|i| (i as Float * 0.3).sin() * 0.02).collect();
+        let log: Vec<Float> = simple.iter().map(|r| (1.0 + r).ln()).collect();
+        let a = EquityCurve::new(100.0, ReturnKind::Simple)
+            .unwrap()
+            .compute_to_vec(&simple)
+            .unwrap();
+        let b = EquityCurve::new(100.0, ReturnKind::Log)
+            .unwrap()
+            .compute_to_vec(&log)
+            .unwrap();
+        for (x, y) in a.iter().zip(&b) {
+            assert!((x - y).abs() < 1e3 * Float::EPSILON * y);
+        }
+    }
+
+    #[test]
+    fn test_skips_non_finite_and_resets() {
+        let mut curve = EquityCurve::new(100.0, ReturnKind::Simple).unwrap();
+        let tolerance = 4.0 * Float::EPSILON * 121.0;
+        assert!((curve.next(0.1) - 110.0).abs() < tolerance);
+        assert!(curve.next(Float::NAN).is_nan());
+        assert!((curve.next(0.1) - 121.0).abs() < tolerance);
+        curve.reset();
+        assert!((curve.next(-0.5) - 50.0).abs() < tolerance);
+        assert!(EquityCurve::new(0.0, ReturnKind::Log).is_err());
+    }
+}
@@ -0,0 +1,203 @@
+//! Implementation of the Hampel outlier filter.
+
+use crate::{Float, Indicator, Result, TalibError};
+
+/// Scale factor that makes the MAD a consistent estimator of the standard deviation
+/// for normally distributed data.
+const MAD_SCALE: Float = 1.4826;
+
+/// Hampel filter: rolling-median outlier replacement
+///
+/// For each window of `period` values the center value is compared against the
+/// window median. If it deviates by more than `k` scaled MADs
+/// (`1.4826 * median(|x - median|)`) it is replaced with the median, otherwise it
+/// passes through unchanged.
+///
+/// Because the filtered value is the window center, the output at index `i`
+/// corresponds to the input at `i - period / 2`.
+#[derive(Debug, Clone)]
+pub struct HampelFilter {
+    period: usize,
+    k: Float,
+    buffer: Vec<Float>,
+    scratch: Vec<Float>,
+    index: usize,
+    is_full: bool,
+}
+
+impl HampelFilter {
+    /// Create a new Hampel filter.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` unless `period` is odd and at least 3, and
+    /// `TalibError::InvalidParameter` unless `k` is finite and positive.
+    pub fn new(period: usize, k: Float) -> Result<Self> {
+        if period < 3 || period % 2 == 0 {
+            return Err(TalibError::invalid_period(
+                period,
+                "period must be odd and at least 3",
+            ));
+        }
+        if !(k.is_finite() && k > 0.0) {
+            return Err(TalibError::invalid_parameter(
+                "k".to_string(),
+                k.to_string(),
+                "finite value greater than 0".to_string(),
+            ));
+        }
+        Ok(Self {
+            period,
+            k,
+            buffer: vec![0.0; period],
+            scratch: vec![0.0; period],
+            index: 0,
+            is_full: false,
+        })
+    }
+}
+
+/// Median of `values`, reordering the slice in place.
+fn median(values: &mut [Float]) -> Float {
+    values.sort_unstable_by(|a, b| a.total_cmp(b));
+    let mid = values.len() / 2;
+    if values.len() % 2 == 0 {
+        (values[mid - 1] + values[mid]) * 0.5
+    } else {
+        values[mid]
+    }
+}
+
+/// Filter the `center` value of a window whose values have been copied into `scratch`.
+fn hampel(center: Float, scratch: &mut [Float], k: Float) -> Float {
+    if scratch.iter().any(|x| !x.is_finite()) {
+        return Float::NAN;
+    }
+    let med = median(scratch);
+    for x in scratch.iter_mut() {
+        *x = (*x - med).abs();
+    }
+    let mad = MAD_SCALE * median(scratch);
+    if (center - med).abs() > k * mad {
+        med
+    } else {
+        center
+    }
+}
+
+impl Indicator for HampelFilter {
+    type Input = Float;
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        format!("HampelFilter({},{})", self.period, self.k)
+    }
+
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut result = vec![Float::NAN; inputs.len()];
+        let mut scratch = vec![0.0; self.period];
+        let half = self.period / 2;
+        for (i, window) in inputs.windows(self.period).enumerate() {
+            scratch.copy_from_slice(window);
+            result[i + self.period - 1] = hampel(window[half], &mut scratch, self.k);
+        }
+        Ok(result)
+    }
+
+    fn next(&mut self, input: Float) -> Float {
+        self.buffer[self.index] = input;
+        if !self.is_full && self.index == self.period - 1 {
+            self.is_full = true;
+        }
+        self.index = (self.index + 1) % self.period;
+
+        if !self.is_full {
+            return Float::NAN;
+        }
+        // `index` now points at the oldest value, so the center is half a window ahead
+        let center = self.buffer[(self.index + self.period / 2) % self.period];
+        self.scratch.copy_from_slice(&self.buffer);
+        hampel(center, &mut self.scratch, self.k)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn noisy_series() -> Vec<Float> {
+        (0..40)
+            .map(|i| 10.0 + (i as Float * 0.7).sin() * 0.5)
+            .collect()
+    }
+
+    #[test]
+    fn test_spike_is_replaced_by_median() {
+        let mut inputs = noisy_series();
+        inputs[20] = 100.0;
+        let filter = HampelFilter::new(7, 3.0).unwrap();
+        let outputs = filter.compute_to_vec(&inputs).unwrap();
+
+        // Output at i filters the input at i - 3
+        let filtered = outputs[23];
+        assert!(filtered < 11.0, "spike not replaced: {}", filtered);
+        let mut window = inputs[17..24].to_vec();
+        assert_eq!(filtered, median(&mut window));
+    }
+
+    #[test]
+    fn test_normal_values_pass_through() {
+        let inputs = noisy_series();
+        let filter = HampelFilter::new(7, 3.0).unwrap();
+        let outputs = filter.compute_to_vec(&inputs).unwrap();
+        assert!(outputs[..6].iter().all(|v| v.is_nan()));
+        for i in 6..inputs.len() {
+            assert_eq!(outputs[i], inputs[i - 3]);
+        }
+    }
+
+    #[test]
+    fn test_streaming_matches_batch() {
+        let mut inputs = noisy_series();
+        inputs[12] = -50.0;
+        inputs[30] = 75.0;
+        let batch = HampelFilter::new(5, 2.0)
+            .unwrap()
+            .compute_to_vec(&inputs)
+            .unwrap();
+        let mut stream = HampelFilter::new(5, 2.0).unwrap();
+        for (i, &x) in inputs.iter().enumerate() {
+            let value = stream.next(x);
+            if i < 4 {
+                assert!(value.is_nan());
+            } else {
+                assert_eq!(value, batch[i]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(matches!(
+            HampelFilter::new(4, 3.0),
+            Err(TalibError::InvalidPeriod { .. })
+        ));
+        assert!(matches!(
+            HampelFilter::new(1, 3.0),
+            Err(TalibError::InvalidPeriod { .. })
+        ));
+        assert!(matches!(
+            HampelFilter::new(5, 0.0),
+            Err(TalibError::InvalidParameter { .. })
+        ));
+        assert!(matches!(
+            HampelFilter::new(5, Float::NAN),
+            Err(TalibError::InvalidParameter { .. })
+        ));
+    }
+}
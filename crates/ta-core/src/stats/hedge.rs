@@ -0,0 +1,102 @@
+//! Implementation of the rolling hedge ratio.
+
+use super::moments::PairWindow;
+use crate::{Float, Indicator, Resettable, Result, TalibError};
+
+/// Rolling hedge ratio between two series
+///
+/// Takes `(a, b)` price pairs and, over each window of `period` pairs, computes the
+/// OLS slope of `a` on `b`:
+///
+/// ```text
+/// ratio = cov(a, b) / var(b)
+/// ```
+///
+/// This is the dynamic hedge ratio used to build a [`Spread`](super::Spread). When
+/// `b` is flat over the window the slope is undefined and the output is
+/// `Float::NAN`.
+#[derive(Debug, Clone)]
+pub struct HedgeRatio {
+    period: usize,
+    window: PairWindow,
+}
+
+impl HedgeRatio {
+    /// Create a rolling hedge ratio over `period` pairs.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if `period < 2`.
+    pub fn new(period: usize) -> Result<Self> {
+        if period < 2 {
+            return Err(TalibError::invalid_period(
+                period,
+                "period must be at least 2 to estimate a slope",
+            ));
+        }
+        Ok(Self {
+            period,
+            window: PairWindow::new(period),
+        })
+    }
+}
+
+impl Indicator for HedgeRatio {
+    type Input = (Float, Float);
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        format!("HedgeRatio({})", self.period)
+    }
+
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut ratio = Self::new(self.period)?;
+        Ok(inputs.iter().map(|&pair| ratio.next(pair)).collect())
+    }
+
+    fn next(&mut self, (a, b): (Float, Float)) -> Float {
+        self.window
+            .push(a, b)
+            .and_then(|moments| moments.slope())
+            .unwrap_or(Float::NAN)
+    }
+}
+
+impl Resettable for HedgeRatio {
+    fn reset(&mut self) {
+        self.window = PairWindow::new(self.period);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PairIndicator;
+
+    #[test]
+    fn test_double_series_has_ratio_two() {
+        let b: Vec<Float> = (0..50)
+            .map(|i| 30.0 + (i as Float * 0.4).sin() * 3.0)
+            .collect();
+        let a: Vec<Float> = b.iter().map(|x| 2.0 * x).collect();
+        let ratio = HedgeRatio::new(10).unwrap().compute_pair(&a, &b).unwrap();
+        assert!(ratio[..9].iter().all(|v| v.is_nan()));
+        for &r in &ratio[9..] {
+            assert!((r - 2.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_flat_series_is_nan() {
+        let mut ratio = HedgeRatio::new(2).unwrap();
+        ratio.next((1.0, 5.0));
+        assert!(ratio.next((2.0, 5.0)).is_nan());
+        assert!((ratio.next((4.0, 6.0)) - 2.0).abs() < 1e-12);
+        assert!(HedgeRatio::new(1).is_err());
+    }
+}
@@ -0,0 +1,446 @@
+//! Least-squares linear regression over rolling windows.
+
+use crate::{simd::dispatch, Float, Indicator, Resettable, Result, TalibError};
+
+/// Precomputed x-axis terms for an OLS fit of `y = a + b * x` with `x = 0..period`.
+///
+/// Since x never changes between windows, only `sum(y)` and `sum(x * y)` have to be
+/// computed per window.
+#[derive(Debug, Clone)]
+struct OlsWindow {
+    x: Vec<Float>,
+    sum_x: Float,
+    denominator: Float,
+}
+
+impl OlsWindow {
+    fn new(period: usize) -> Self {
+        let n = period as Float;
+        let sum_x = n * (n - 1.0) / 2.0;
+        let sum_xx = (n - 1.0) * n * (2.0 * n - 1.0) / 6.0;
+        Self {
+            x: (0..period).map(|k| k as Float).collect(),
+            sum_x,
+            denominator: n * sum_xx - sum_x * sum_x,
+        }
+    }
+
+    /// Returns `(intercept, slope)` for a window of exactly `period` values.
+    #[inline]
+    fn fit(&self, window: &[Float]) -> (Float, Float) {
+        let n = self.x.len() as Float;
+        let sum_y = dispatch::sum(window);
+        let sum_xy = dispatch::dot_product(&self.x, window);
+        let slope = (n * sum_xy - self.sum_x * sum_y) / self.denominator;
+        let intercept = (sum_y - slope * self.sum_x) / n;
+        (intercept, slope)
+    }
+}
+
+fn validate_period(period: usize) -> Result<()> {
+    if period < 2 {
+        return Err(TalibError::invalid_period(
+            period,
+            "linear regression needs a period of at least 2",
+        ));
+    }
+    Ok(())
+}
+
+/// Ring buffer that can hand out its window in chronological order.
+#[derive(Debug, Clone)]
+struct Window {
+    buffer: Vec<Float>,
+    ordered: Vec<Float>,
+    index: usize,
+    is_full: bool,
+}
+
+impl Window {
+    fn new(period: usize) -> Self {
+        Self {
+            buffer: vec![0.0; period],
+            ordered: vec![0.0; period],
+            index: 0,
+            is_full: false,
+        }
+    }
+
+    /// Push a value; returns the ordered window once `period` values have been seen.
+    fn push(&mut self, input: Float) -> Option<&[Float]> {
+        let period = self.buffer.len();
+        self.buffer[self.index] = input;
+        if !self.is_full && self.index == period - 1 {
+            self.is_full = true;
+        }
+        self.index = (self.index + 1) % period;
+        if !self.is_full {
+            return None;
+        }
+        let (newest, oldest) = self.buffer.split_at(self.index);
+        self.ordered[..oldest.len()].copy_from_slice(oldest);
+        self.ordered[oldest.len()..].copy_from_slice(newest);
+        Some(&self.ordered)
+    }
+
+    fn clear(&mut self) {
+        self.index = 0;
+        self.is_full = false;
+    }
+}
+
+/// Linear regression forecast
+///
+/// Fits an OLS line over each window of `period` values (x = `0..period`) and
+/// projects it `horizon` bars past the window's last value:
+///
+/// ```text
+/// forecast = intercept + slope * (period - 1 + horizon)
+/// ```
+///
+/// A `horizon` of 0 gives the fitted value at the end of the window.
+#[derive(Debug, Clone)]
+pub struct LinRegForecast {
+    period: usize,
+    horizon: usize,
+    ols: OlsWindow,
+    window: Window,
+}
+
+impl LinRegForecast {
+    /// Create a new linear regression forecast.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if `period < 2`.
+    pub fn new(period: usize, horizon: usize) -> Result<Self> {
+        validate_period(period)?;
+        Ok(Self {
+            period,
+            horizon,
+            ols: OlsWindow::new(period),
+            window: Window::new(period),
+        })
+    }
+
+    #[inline]
+    fn project(&self, (intercept, slope): (Float, Float)) -> Float {
+        intercept + slope * (self.period - 1 + self.horizon) as Float
+    }
+
+    /// Fit each window and evaluate its line at a caller-supplied x position.
+    ///
+    /// Positions use the window's own axis, where the oldest value sits at `x = 0`
+    /// and the newest at `x = period - 1`, so fractional and out-of-window offsets
+    /// are allowed; `horizon` is ignored. `x_positions` holds either one position
+    /// per window (`inputs.len() - period + 1` of them) or a single position that is
+    /// broadcast to every window. As with `compute_to_vec`, the result is aligned
+    /// with `inputs` and starts with a `Float::NAN` warm-up prefix.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidParameter` if `x_positions` has any other length.
+    pub fn evaluate_at(&self, inputs: &[Float], x_positions: &[Float]) -> Result<Vec<Float>> {
+        let windows = (inputs.len() + 1).saturating_sub(self.period);
+        if x_positions.len() != 1 && x_positions.len() != windows {
+            return Err(TalibError::invalid_parameter(
+                "x_positions".to_string(),
+                format!("{} positions", x_positions.len()),
+                format!("1 or {} positions (one per window)", windows),
+            ));
+        }
+        let mut result = vec![Float::NAN; inputs.len()];
+        for (i, window) in inputs.windows(self.period).enumerate() {
+            let x = x_positions[if x_positions.len() == 1 { 0 } else { i }];
+            let (intercept, slope) = self.ols.fit(window);
+            result[i + self.period - 1] = intercept + slope * x;
+        }
+        Ok(result)
+    }
+}
+
+impl Indicator for LinRegForecast {
+    type Input = Float;
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        format!("LinRegForecast({},{})", self.period, self.horizon)
+    }
+
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut result = vec![Float::NAN; inputs.len()];
+        for (i, window) in inputs.windows(self.period).enumerate() {
+            result[i + self.period - 1] = self.project(self.ols.fit(window));
+        }
+        Ok(result)
+    }
+
+    fn next(&mut self, input: Float) -> Float {
+        match self.window.push(input) {
+            Some(window) => {
+                let fit = self.ols.fit(window);
+                self.project(fit)
+            }
+            None => Float::NAN,
+        }
+    }
+}
+
+impl Resettable for LinRegForecast {
+    fn reset(&mut self) {
+        self.window.clear();
+    }
+}
+
+/// Defines a rolling OLS indicator whose output is a function of the window's
+/// `period`, `intercept` and `slope`.
+macro_rules! regression_indicator {
+    ($(#[$meta:meta])* $name:ident, |$period:ident, $intercept:ident, $slope:ident| $output:expr) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone)]
+        pub struct $name {
+            period: usize,
+            ols: OlsWindow,
+            window: Window,
+        }
+
+        impl $name {
+            #[doc = concat!("Create a new `", stringify!($name), "`.")]
+            ///
+            /// # Errors
+            ///
+            /// Returns `TalibError::InvalidPeriod` if `period < 2`.
+            pub fn new(period: usize) -> Result<Self> {
+                validate_period(period)?;
+                Ok(Self {
+                    period,
+                    ols: OlsWindow::new(period),
+                    window: Window::new(period),
+                })
+            }
+
+            #[inline]
+            fn output(&self, ($intercept, $slope): (Float, Float)) -> Float {
+                let $period = self.period as Float;
+                $output
+            }
+        }
+
+        impl Indicator for $name {
+            type Input = Float;
+
+            type Output = Float;
+
+            fn name(&self) -> String {
+                format!(concat!(stringify!($name), "({})"), self.period)
+            }
+
+            fn lookback(&self) -> usize {
+                self.period - 1
+            }
+
+            fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+                let mut result = vec![Float::NAN; inputs.len()];
+                for (i, window) in inputs.windows(self.period).enumerate() {
+                    result[i + self.period - 1] = self.output(self.ols.fit(window));
+                }
+                Ok(result)
+            }
+
+            fn next(&mut self, input: Float) -> Float {
+                match self.window.push(input) {
+                    Some(window) => {
+                        let fit = self.ols.fit(window);
+                        self.output(fit)
+                    }
+                    None => Float::NAN,
+                }
+            }
+        }
+
+        impl Resettable for $name {
+            fn reset(&mut self) {
+                self.window.clear();
+            }
+        }
+    };
+}
+
+regression_indicator!(
+    /// Linear regression (TA-Lib `LINEARREG`)
+    ///
+    /// The value of each window's OLS line at its last bar, `intercept + slope * (period - 1)`.
+    LinearReg,
+    |period, intercept, slope| intercept + slope * (period - 1.0)
+);
+
+regression_indicator!(
+    /// Linear regression slope (TA-Lib `LINEARREG_SLOPE`)
+    ///
+    /// The slope `b` of each window's OLS line `y = a + b * x`.
+    LinearRegSlope,
+    |_period, _intercept, slope| slope
+);
+
+regression_indicator!(
+    /// Linear regression intercept (TA-Lib `LINEARREG_INTERCEPT`)
+    ///
+    /// The intercept `a` of each window's OLS line `y = a + b * x`, i.e. its value at
+    /// the window's oldest bar.
+    LinearRegIntercept,
+    |_period, intercept, _slope| intercept
+);
+
+regression_indicator!(
+    /// Linear regression angle (TA-Lib `LINEARREG_ANGLE`)
+    ///
+    /// The angle of each window's OLS line in degrees, `atan(slope)`.
+    LinearRegAngle,
+    |_period, _intercept, slope| slope.atan().to_degrees()
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_reset_streaming_matches_batch;
+
+    fn line(n: usize) -> Vec<Float> {
+        (0..n).map(|i| 3.0 + 0.5 * i as Float).collect()
+    }
+
+    #[test]
+    fn test_forecast_extrapolates_linear_input() {
+        let inputs = line(30);
+        for horizon in [0, 1, 5] {
+            let outputs = LinRegForecast::new(10, horizon)
+                .unwrap()
+                .compute_to_vec(&inputs)
+                .unwrap();
+            assert!(outputs[..9].iter().all(|v| v.is_nan()));
+            for (i, &v) in outputs.iter().enumerate().skip(9) {
+                let expected = 3.0 + 0.5 * (i + horizon) as Float;
+                assert!((v - expected).abs() < 1e-9, "{} != {}", v, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_streaming_matches_batch() {
+        let inputs: Vec<Float> = (0..60)
+            .map(|i| (i as Float * 0.4).sin() * 3.0 + i as Float)
+            .collect();
+        let batch = LinRegForecast::new(7, 2)
+            .unwrap()
+            .compute_to_vec(&inputs)
+            .unwrap();
+        let mut stream = LinRegForecast::new(7, 2).unwrap();
+        for (i, &x) in inputs.iter().enumerate() {
+            let value = stream.next(x);
+            assert!((value.is_nan() && batch[i].is_nan()) || (value - batch[i]).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_evaluate_at_fractional_positions() {
+        let inputs = line(20);
+        let forecast = LinRegForecast::new(5, 1).unwrap();
+        let positions: Vec<Float> = (0..16).map(|k| k as Float * 0.25 - 0.5).collect();
+        let outputs = forecast.evaluate_at(&inputs, &positions).unwrap();
+        assert!(outputs[..4].iter().all(|v| v.is_nan()));
+        for (k, &x) in positions.iter().enumerate() {
+            // Window k starts at input index k
+            let expected = 3.0 + 0.5 * (k as Float + x);
+            assert!((outputs[k + 4] - expected).abs() < 1e-9);
+        }
+
+        let broadcast = forecast.evaluate_at(&inputs, &[2.5]).unwrap();
+        for (k, &v) in broadcast.iter().enumerate().skip(4) {
+            assert!((v - (3.0 + 0.5 * (k as Float - 4.0 + 2.5))).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_evaluate_at_rejects_mismatched_positions() {
+        let forecast = LinRegForecast::new(5, 0).unwrap();
+        assert!(matches!(
+            forecast.evaluate_at(&line(10), &[0.0, 1.0]),
+            Err(TalibError::InvalidParameter { .. })
+        ));
+    }
+
+    #[test]
+    fn test_regression_family_on_linear_input() {
+        let inputs = line(30);
+        let fitted = LinearReg::new(10).unwrap().compute_to_vec(&inputs).unwrap();
+        let slope = LinearRegSlope::new(10)
+            .unwrap()
+            .compute_to_vec(&inputs)
+            .unwrap();
+        let intercept = LinearRegIntercept::new(10)
+            .unwrap()
+            .compute_to_vec(&inputs)
+            .unwrap();
+        let angle = LinearRegAngle::new(10)
+            .unwrap()
+            .compute_to_vec(&inputs)
+            .unwrap();
+        for outputs in [&fitted, &slope, &intercept, &angle] {
+            assert_eq!(outputs.len(), inputs.len());
+            assert!(outputs[..9].iter().all(|v| v.is_nan()));
+        }
+        let expected_angle = (0.5 as Float).atan().to_degrees();
+        for i in 9..inputs.len() {
+            assert!((fitted[i] - inputs[i]).abs() < 1e-9);
+            assert!((slope[i] - 0.5).abs() < 1e-12);
+            assert!((intercept[i] - inputs[i - 9]).abs() < 1e-9);
+            assert!((angle[i] - expected_angle).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_linear_reg_matches_zero_horizon_forecast() {
+        let inputs: Vec<Float> = (0..50)
+            .map(|i| (i as Float * 0.3).cos() * 4.0 + 0.2 * i as Float)
+            .collect();
+        let fitted = LinearReg::new(8).unwrap().compute_to_vec(&inputs).unwrap();
+        let forecast = LinRegForecast::new(8, 0)
+            .unwrap()
+            .compute_to_vec(&inputs)
+            .unwrap();
+        for (a, b) in fitted.iter().zip(&forecast) {
+            assert!((a.is_nan() && b.is_nan()) || (a - b).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_regression_streaming_matches_batch() {
+        let inputs: Vec<Float> = (0..60)
+            .map(|i| (i as Float * 0.4).sin() * 3.0 + i as Float)
+            .collect();
+        let angle = LinearRegAngle::new(6).unwrap();
+        assert_reset_streaming_matches_batch(angle, &[100.0; 10], &inputs, 1e-9);
+    }
+
+    #[test]
+    fn test_regression_names_and_invalid_period() {
+        assert_eq!(LinearReg::new(14).unwrap().name(), "LinearReg(14)");
+        assert_eq!(LinearRegSlope::new(5).unwrap().name(), "LinearRegSlope(5)");
+        assert!(matches!(
+            LinearRegIntercept::new(1),
+            Err(TalibError::InvalidPeriod { .. })
+        ));
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        assert!(matches!(
+            LinRegForecast::new(1, 1),
+            Err(TalibError::InvalidPeriod { .. })
+        ));
+    }
+}
@@ -0,0 +1,34 @@
+//! Statistic functions computed over rolling windows.
+//! This module groups together distribution-style indicators like rolling entropy
+//! robust filters like the Hampel filter, linear regression lines, slopes and
+//! forecasts (including TSF), rolling products, drawdowns, return compounding,
+//! variance and standard deviation, z-scores, and two-series measures like rolling
+//! alpha, hedge ratios and spreads.
+
+mod alpha;
+mod drawdown;
+mod entropy;
+mod equity;
+mod hampel;
+mod hedge;
+mod linreg;
+mod moments;
+mod product;
+mod spread;
+mod stddev;
+mod tsf;
+mod welford;
+mod zscore;
+
+pub use alpha::Alpha;
+pub use drawdown::MaxDrawdown;
+pub use entropy::RollingEntropy;
+pub use equity::EquityCurve;
+pub use hampel::HampelFilter;
+pub use hedge::HedgeRatio;
+pub use linreg::{LinRegForecast, LinearReg, LinearRegAngle, LinearRegIntercept, LinearRegSlope};
+pub use product::{ProductMethod, RollingProduct};
+pub use spread::Spread;
+pub use stddev::{STDDEV, VAR};
+pub use tsf::TSF;
+pub use zscore::{detect_anomalies, ZScore};
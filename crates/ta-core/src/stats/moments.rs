@@ -0,0 +1,80 @@
+//! Rolling moments over paired series, shared by the two-series statistics.
+
+use crate::Float;
+
+/// Population moments of two equally long series
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PairMoments {
+    pub(crate) mean_a: Float,
+    pub(crate) mean_b: Float,
+    pub(crate) cov_ab: Float,
+    pub(crate) var_b: Float,
+}
+
+impl PairMoments {
+    /// Two-pass moments of `a` and `b`, which must have the same non-zero length.
+    pub(crate) fn from_slices(a: &[Float], b: &[Float]) -> Self {
+        debug_assert_eq!(a.len(), b.len());
+        let n = a.len() as Float;
+        let mean_a = a.iter().sum::<Float>() / n;
+        let mean_b = b.iter().sum::<Float>() / n;
+        let (mut cov_ab, mut var_b) = (0.0, 0.0);
+        for (&x, &y) in a.iter().zip(b) {
+            let dy = y - mean_b;
+            cov_ab += (x - mean_a) * dy;
+            var_b += dy * dy;
+        }
+        Self {
+            mean_a,
+            mean_b,
+            cov_ab: cov_ab / n,
+            var_b: var_b / n,
+        }
+    }
+
+    /// OLS slope of `a` on `b` (`cov(a, b) / var(b)`), or `None` when `b` is flat.
+    ///
+    /// A variance within rounding noise of zero relative to the mean of `b` counts as
+    /// flat, so a constant series is not mistaken for a tiny but real variance.
+    pub(crate) fn slope(&self) -> Option<Float> {
+        if self.var_b <= Float::EPSILON * self.mean_b * self.mean_b {
+            return None;
+        }
+        let slope = self.cov_ab / self.var_b;
+        slope.is_finite().then_some(slope)
+    }
+}
+
+/// Ring buffer over `(a, b)` pairs that yields the window moments once full
+#[derive(Debug, Clone)]
+pub(crate) struct PairWindow {
+    a: Vec<Float>,
+    b: Vec<Float>,
+    index: usize,
+    is_full: bool,
+}
+
+impl PairWindow {
+    pub(crate) fn new(period: usize) -> Self {
+        Self {
+            a: vec![0.0; period],
+            b: vec![0.0; period],
+            index: 0,
+            is_full: false,
+        }
+    }
+
+    /// Push a pair; returns the window moments once `period` pairs have been seen.
+    pub(crate) fn push(&mut self, a: Float, b: Float) -> Option<PairMoments> {
+        let period = self.a.len();
+        self.a[self.index] = a;
+        self.b[self.index] = b;
+        if !self.is_full && self.index == period - 1 {
+            self.is_full = true;
+        }
+        self.index = (self.index + 1) % period;
+        // Moments do not depend on order, so the ring is used as-is
+        self.is_full
+            .then(|| PairMoments::from_slices(&self.a, &self.b))
+    }
+}
@@ -0,0 +1,178 @@
+//! Implementation of the rolling window product.
+
+use crate::{simd::scalar, Float, Indicator, Resettable, Result, TalibError};
+
+/// How [`RollingProduct`] multiplies a window
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProductMethod {
+    /// `exp(Σ ln x)`, stable for long windows of growth factors; inputs must be positive
+    LogSum,
+    /// Plain multiplication; any finite input is allowed, overflow yields `Float::NAN`
+    Direct,
+}
+
+/// Rolling window product
+///
+/// Multiplies the last `period` inputs, for example to compound a window of growth
+/// factors (`1 + r`). Each window is recomputed from the ring buffer rather than
+/// updated by dividing out the oldest value, so streaming does not accumulate drift
+/// and a zero leaving the window is handled correctly.
+///
+/// With [`ProductMethod::LogSum`], a non-positive input makes every window that
+/// contains it `Float::NAN` when streaming; batch computation rejects it up front
+/// with `TalibError::InvalidInput`. With [`ProductMethod::Direct`], a window whose
+/// product overflows is `Float::NAN`.
+#[derive(Debug, Clone)]
+pub struct RollingProduct {
+    period: usize,
+    method: ProductMethod,
+    buffer: Vec<Float>,
+    index: usize,
+    is_full: bool,
+}
+
+impl RollingProduct {
+    /// Create a rolling product over `period` values.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if `period` is zero.
+    pub fn new(period: usize, method: ProductMethod) -> Result<Self> {
+        if period == 0 {
+            return Err(TalibError::invalid_period(
+                period,
+                "period must be greater than zero",
+            ));
+        }
+        Ok(Self {
+            period,
+            method,
+            buffer: vec![1.0; period],
+            index: 0,
+            is_full: false,
+        })
+    }
+
+    /// The method used to multiply each window.
+    pub fn method(&self) -> ProductMethod {
+        self.method
+    }
+
+    fn window_product(&self) -> Float {
+        let product = match self.method {
+            ProductMethod::LogSum => {
+                if self.buffer.iter().any(|&x| x <= 0.0) {
+                    return Float::NAN;
+                }
+                self.buffer.iter().map(|x| x.ln()).sum::<Float>().exp()
+            }
+            ProductMethod::Direct => scalar::product(&self.buffer),
+        };
+        if product.is_finite() {
+            product
+        } else {
+            Float::NAN
+        }
+    }
+}
+
+impl Indicator for RollingProduct {
+    type Input = Float;
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        format!("RollingProduct({},{:?})", self.period, self.method)
+    }
+
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        if self.method == ProductMethod::LogSum {
+            if let Some(i) = inputs.iter().position(|&x| x <= 0.0) {
+                return Err(TalibError::invalid_input(format!(
+                    "log-sum product requires positive inputs, got {} at index {}",
+                    inputs[i], i
+                )));
+            }
+        }
+        let mut product = Self::new(self.period, self.method)?;
+        Ok(inputs.iter().map(|&x| product.next(x)).collect())
+    }
+
+    fn next(&mut self, input: Float) -> Float {
+        self.buffer[self.index] = input;
+        if !self.is_full && self.index == self.period - 1 {
+            self.is_full = true;
+        }
+        self.index = (self.index + 1) % self.period;
+        if self.is_full {
+            self.window_product()
+        } else {
+            Float::NAN
+        }
+    }
+}
+
+impl Resettable for RollingProduct {
+    fn reset(&mut self) {
+        self.buffer.fill(1.0);
+        self.index = 0;
+        self.is_full = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_sum_matches_direct_on_positive_windows() {
+        let growth: Vec<Float> = (0..60)
+            .map(|i| 1.0 + (i as Float * 0.37).sin() * 0.03)
+            .collect();
+        let log = RollingProduct::new(12, ProductMethod::LogSum)
+            .unwrap()
+            .compute_to_vec(&growth)
+            .unwrap();
+        let direct = RollingProduct::new(12, ProductMethod::Direct)
+            .unwrap()
+            .compute_to_vec(&growth)
+            .unwrap();
+        assert!(log[..11].iter().all(|v| v.is_nan()));
+        for i in 11..growth.len() {
+            let expected = scalar::product(&growth[i - 11..=i]);
+            // Twelve factors near 1, each rounded once, plus the exp/ln round trip
+            assert!((log[i] - expected).abs() < 64.0 * Float::EPSILON * expected);
+            assert!((direct[i] - expected).abs() < 64.0 * Float::EPSILON * expected);
+        }
+    }
+
+    #[test]
+    fn test_log_sum_rejects_non_positive() {
+        let product = RollingProduct::new(2, ProductMethod::LogSum).unwrap();
+        assert!(matches!(
+            product.compute_to_vec(&[1.0, 0.0, 2.0]),
+            Err(TalibError::InvalidInput { .. })
+        ));
+
+        let mut stream = RollingProduct::new(2, ProductMethod::LogSum).unwrap();
+        stream.next(-1.0);
+        assert!(stream.next(2.0).is_nan());
+        assert!((stream.next(3.0) - 6.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_direct_handles_zero_and_overflow() {
+        let mut stream = RollingProduct::new(2, ProductMethod::Direct).unwrap();
+        stream.next(0.0);
+        assert_eq!(stream.next(-4.0), 0.0);
+        assert_eq!(stream.next(2.0), -8.0);
+        stream.next(Float::MAX);
+        assert!(stream.next(Float::MAX).is_nan());
+        stream.reset();
+        assert!(stream.next(5.0).is_nan());
+    }
+}
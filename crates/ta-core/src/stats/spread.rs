@@ -0,0 +1,166 @@
+//! Implementation of the hedge-ratio spread between two series.
+
+use crate::{
+    overlap::MaKind, smoothing::MaStage, Float, Indicator, Resettable, Result, TalibError,
+};
+
+/// Spread between two series for pairs trading
+///
+/// Takes `(a, b)` price pairs and produces `a - ratio * b`, where `ratio` is the
+/// hedge ratio. When the ratio matches the relationship between two cointegrated
+/// series, the spread oscillates around a stable level instead of trending.
+///
+/// The spread can optionally be smoothed by a moving average, see
+/// [`Spread::smoothed`]. Use [`PairIndicator::compute_pair`](crate::PairIndicator)
+/// to pass the two series as separate slices.
+#[derive(Debug, Clone)]
+pub struct Spread {
+    ratio: Float,
+    smoothing: Option<(MaKind, usize)>,
+    ma: Option<MaStage>,
+}
+
+impl Spread {
+    /// Create a raw spread with the given hedge ratio.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidParameter` if `ratio` is not finite.
+    pub fn new(ratio: Float) -> Result<Self> {
+        if !ratio.is_finite() {
+            return Err(TalibError::invalid_parameter(
+                "ratio".to_string(),
+                ratio.to_string(),
+                "a finite hedge ratio".to_string(),
+            ));
+        }
+        Ok(Self {
+            ratio,
+            smoothing: None,
+            ma: None,
+        })
+    }
+
+    /// Create a spread smoothed by a `kind` moving average of `period` values.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidParameter` if `ratio` is not finite and
+    /// `TalibError::InvalidPeriod` if `period` is zero.
+    pub fn smoothed(ratio: Float, period: usize, kind: MaKind) -> Result<Self> {
+        if period == 0 {
+            return Err(TalibError::invalid_period(
+                period,
+                "period must be greater than zero",
+            ));
+        }
+        let mut spread = Self::new(ratio)?;
+        spread.smoothing = Some((kind, period));
+        spread.ma = Some(MaStage::new(kind, period));
+        Ok(spread)
+    }
+
+    /// The hedge ratio applied to `b`.
+    pub fn ratio(&self) -> Float {
+        self.ratio
+    }
+
+    fn fresh(&self) -> Self {
+        Self {
+            ratio: self.ratio,
+            smoothing: self.smoothing,
+            ma: self
+                .smoothing
+                .map(|(kind, period)| MaStage::new(kind, period)),
+        }
+    }
+}
+
+impl Indicator for Spread {
+    type Input = (Float, Float);
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        match self.smoothing {
+            Some((kind, period)) => format!("Spread({},{:?},{})", self.ratio, kind, period),
+            None => format!("Spread({})", self.ratio),
+        }
+    }
+
+    fn lookback(&self) -> usize {
+        self.smoothing.map_or(0, |(_, period)| period - 1)
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut spread = self.fresh();
+        Ok(inputs.iter().map(|&pair| spread.next(pair)).collect())
+    }
+
+    fn next(&mut self, (a, b): (Float, Float)) -> Float {
+        let raw = a - self.ratio * b;
+        match self.ma.as_mut() {
+            Some(ma) => ma.next(raw),
+            None => raw,
+        }
+    }
+}
+
+impl Resettable for Spread {
+    fn reset(&mut self) {
+        *self = self.fresh();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{overlap::SMA, PairIndicator};
+
+    /// A trending series and a second one tied to it by a factor of 2 plus noise.
+    fn pair(n: usize) -> (Vec<Float>, Vec<Float>) {
+        let b: Vec<Float> = (0..n)
+            .map(|i| 50.0 + i as Float * 0.3 + (i as Float * 0.2).sin() * 4.0)
+            .collect();
+        let a = b
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| 2.0 * x + 5.0 + (i as Float * 1.7).sin() * 0.5)
+            .collect();
+        (a, b)
+    }
+
+    #[test]
+    fn test_matching_ratio_gives_bounded_spread() {
+        let (a, b) = pair(300);
+        let hedged = Spread::new(2.0).unwrap().compute_pair(&a, &b).unwrap();
+        assert!(hedged.iter().all(|s| (s - 5.0).abs() <= 0.5 + 1e-9));
+
+        let unhedged = Spread::new(1.0).unwrap().compute_pair(&a, &b).unwrap();
+        assert!(unhedged[299] - unhedged[0] > 50.0);
+    }
+
+    #[test]
+    fn test_smoothed_spread_is_ma_of_raw() {
+        let (a, b) = pair(60);
+        let raw = Spread::new(2.0).unwrap().compute_pair(&a, &b).unwrap();
+        let expected = SMA::new(10).compute_to_vec(&raw).unwrap();
+        let spread = Spread::smoothed(2.0, 10, MaKind::Sma).unwrap();
+        assert_eq!(spread.lookback(), 9);
+        let smoothed = spread.compute_pair(&a, &b).unwrap();
+        for (x, y) in smoothed.iter().zip(&expected) {
+            assert!((x.is_nan() && y.is_nan()) || (x - y).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_length_mismatch() {
+        let spread = Spread::new(1.0).unwrap();
+        assert_eq!(
+            spread.compute_pair(&[1.0, 2.0, 3.0], &[1.0, 2.0]),
+            Err(TalibError::dimension_mismatch(3, 2))
+        );
+        assert!(Spread::new(Float::NAN).is_err());
+        assert!(Spread::smoothed(1.0, 0, MaKind::Ema).is_err());
+    }
+}
@@ -0,0 +1,233 @@
+//! Implementation of the rolling variance (VAR) and standard deviation (STDDEV).
+
+use super::welford::WelfordWindow;
+use crate::{simd::dispatch, Float, Indicator, Resettable, Result, TalibError};
+
+fn validate_period(period: usize) -> Result<()> {
+    if period < 2 {
+        return Err(TalibError::invalid_period(
+            period,
+            "period must be at least 2",
+        ));
+    }
+    Ok(())
+}
+
+/// Batch population variances, full length with a `Float::NAN` warm-up prefix.
+fn rolling_variance(inputs: &[Float], period: usize) -> Result<Vec<Float>> {
+    let mut result = vec![Float::NAN; inputs.len()];
+    if inputs.len() >= period {
+        let variances = dispatch::rolling_variance(inputs, period, 0)?;
+        result[period - 1..].copy_from_slice(&variances);
+    }
+    Ok(result)
+}
+
+/// Rolling variance
+///
+/// The population variance of the last `period` values, as TA-Lib's `VAR`. Batch
+/// computation uses the SIMD two-pass kernel; streaming uses a sliding Welford
+/// update, so each `next` is O(1) and stays accurate for large prices.
+#[derive(Debug, Clone)]
+pub struct VAR {
+    period: usize,
+    window: WelfordWindow,
+}
+
+impl VAR {
+    /// Create a rolling variance over `period` values.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if `period < 2`.
+    pub fn new(period: usize) -> Result<Self> {
+        validate_period(period)?;
+        Ok(Self {
+            period,
+            window: WelfordWindow::new(period),
+        })
+    }
+}
+
+impl Indicator for VAR {
+    type Input = Float;
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        format!("VAR({})", self.period)
+    }
+
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        rolling_variance(inputs, self.period)
+    }
+
+    fn next(&mut self, input: Float) -> Float {
+        self.window.push(input).unwrap_or(Float::NAN)
+    }
+}
+
+impl Resettable for VAR {
+    fn reset(&mut self) {
+        self.window.reset();
+    }
+}
+
+/// Rolling standard deviation
+///
+/// `nbdev` times the population standard deviation of the last `period` values,
+/// as TA-Lib's `STDDEV`. Computed like [`VAR`].
+#[derive(Debug, Clone)]
+pub struct STDDEV {
+    period: usize,
+    nbdev: Float,
+    window: WelfordWindow,
+}
+
+impl STDDEV {
+    /// Create a rolling standard deviation over `period` values scaled by `nbdev`,
+    /// commonly `STDDEV::new(5, 1.0)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if `period < 2` and
+    /// `TalibError::InvalidParameter` if `nbdev` is not finite.
+    pub fn new(period: usize, nbdev: Float) -> Result<Self> {
+        validate_period(period)?;
+        if !nbdev.is_finite() {
+            return Err(TalibError::invalid_parameter(
+                "nbdev".to_string(),
+                nbdev.to_string(),
+                "a finite value".to_string(),
+            ));
+        }
+        Ok(Self {
+            period,
+            nbdev,
+            window: WelfordWindow::new(period),
+        })
+    }
+}
+
+impl Indicator for STDDEV {
+    type Input = Float;
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        format!("STDDEV({},{})", self.period, self.nbdev)
+    }
+
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut result = rolling_variance(inputs, self.period)?;
+        for v in &mut result {
+            *v = self.nbdev * v.sqrt();
+        }
+        Ok(result)
+    }
+
+    fn next(&mut self, input: Float) -> Float {
+        match self.window.push(input) {
+            Some(variance) => self.nbdev * variance.sqrt(),
+            None => Float::NAN,
+        }
+    }
+}
+
+impl Resettable for STDDEV {
+    fn reset(&mut self) {
+        self.window.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_reset_streaming_matches_batch;
+
+    const SERIES: [Float; 12] = [
+        101.3, 99.8, 102.7, 103.1, 100.4, 98.9, 104.6, 105.2, 103.8, 101.1, 106.3, 107.9,
+    ];
+
+    #[test]
+    fn test_reference_values() {
+        // Population variance and 2 * stddev over 5 values, as numpy.var(w) and
+        // 2 * numpy.std(w), rounded to 6 decimals; generated with Python's
+        // statistics.pvariance/pstdev
+        let variance = [
+            1.6264, 2.7016, 4.1224, 5.8824, 6.1456, 5.6136, 3.068, 5.3384,
+        ];
+        let stddev = [
+            2.550608, 3.287309, 4.060739, 4.850732, 4.958064, 4.738607, 3.503141, 4.620996,
+        ];
+        let var_out = VAR::new(5).unwrap().compute_to_vec(&SERIES).unwrap();
+        let std_out = STDDEV::new(5, 2.0)
+            .unwrap()
+            .compute_to_vec(&SERIES)
+            .unwrap();
+        // Rounding of the fixtures, plus rounding of deviations from prices near 100
+        let tolerance = 1e-6 + 1e2 * Float::EPSILON * 100.0;
+        assert!(var_out[..4].iter().all(|v| v.is_nan()));
+        assert!(var_out[4..]
+            .iter()
+            .zip(&variance)
+            .all(|(&a, &b)| (a - b).abs() < tolerance));
+        assert!(std_out[4..]
+            .iter()
+            .zip(&stddev)
+            .all(|(&a, &b)| (a - b).abs() < tolerance));
+    }
+
+    #[test]
+    fn test_streaming_matches_batch() {
+        // A large offset would break a naive sum-of-squares update
+        let offset: Float = if cfg!(feature = "f32") { 1e3 } else { 1e7 };
+        let inputs: Vec<Float> = (0..200)
+            .map(|i| offset + (i as Float * 0.37).sin() * 3.0 + (i as Float * 2.1).cos())
+            .collect();
+        // Deviations carry rounding error proportional to the offset
+        let tolerance = 1e2 * Float::EPSILON * offset;
+        assert_reset_streaming_matches_batch(VAR::new(20).unwrap(), &[0.0], &inputs, tolerance);
+        let std = STDDEV::new(20, 1.5).unwrap();
+        assert_reset_streaming_matches_batch(std, &[0.0], &inputs, tolerance);
+    }
+
+    #[test]
+    fn test_constant_window() {
+        let big: Float = 1e9;
+        let mut std = STDDEV::new(4, 1.0).unwrap();
+        let out: Vec<Float> = [5.0, big, big, big, big, big, big, big]
+            .iter()
+            .map(|&x| std.next(x))
+            .collect();
+        // Sliding the outlier out cancels its squared deviation only to within
+        // rounding, which leaves at most about sqrt(EPSILON) * big of spread...
+        assert!(out[4] < Float::EPSILON.sqrt() * big);
+        // ...and the once-per-period rebuild drops that residual entirely
+        assert!(out[7] <= Float::EPSILON * big);
+        assert_eq!(
+            STDDEV::new(4, 1.0)
+                .unwrap()
+                .compute_to_vec(&[1e9; 6])
+                .unwrap()[3..],
+            [0.0; 3]
+        );
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(matches!(VAR::new(1), Err(TalibError::InvalidPeriod { .. })));
+        assert!(matches!(
+            STDDEV::new(5, Float::NAN),
+            Err(TalibError::InvalidParameter { .. })
+        ));
+    }
+}
@@ -0,0 +1,90 @@
+//! Time Series Forecast.
+
+use super::LinRegForecast;
+use crate::{Float, Indicator, Resettable, Result};
+
+/// Time Series Forecast (TA-Lib `TSF`)
+///
+/// Projects each window's OLS line one bar past its end, `intercept + slope * period`.
+/// This is [`LinRegForecast`] with a horizon of 1.
+#[derive(Debug, Clone)]
+pub struct TSF {
+    period: usize,
+    forecast: LinRegForecast,
+}
+
+impl TSF {
+    /// Create a new Time Series Forecast.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if `period < 2`.
+    pub fn new(period: usize) -> Result<Self> {
+        Ok(Self {
+            period,
+            forecast: LinRegForecast::new(period, 1)?,
+        })
+    }
+}
+
+impl Indicator for TSF {
+    type Input = Float;
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        format!("TSF({})", self.period)
+    }
+
+    fn lookback(&self) -> usize {
+        self.forecast.lookback()
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        self.forecast.compute_to_vec(inputs)
+    }
+
+    fn next(&mut self, input: Float) -> Float {
+        self.forecast.next(input)
+    }
+}
+
+impl Resettable for TSF {
+    fn reset(&mut self) {
+        self.forecast.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_reset_streaming_matches_batch;
+    use crate::TalibError;
+
+    #[test]
+    fn test_tsf_extrapolates_linear_ramp() {
+        let inputs: Vec<Float> = (0..25).map(|i| 10.0 - 0.75 * i as Float).collect();
+        let tsf = TSF::new(6).unwrap();
+        assert_eq!(tsf.lookback(), 5);
+        let outputs = tsf.compute_to_vec(&inputs).unwrap();
+        assert!(outputs[..5].iter().all(|v| v.is_nan()));
+        for (i, &v) in outputs.iter().enumerate().skip(5) {
+            let next = 10.0 - 0.75 * (i + 1) as Float;
+            assert!((v - next).abs() < 1e-9, "{} != {}", v, next);
+        }
+    }
+
+    #[test]
+    fn test_streaming_matches_batch() {
+        let inputs: Vec<Float> = (0..40)
+            .map(|i| (i as Float * 0.5).sin() * 2.0 + 0.1 * i as Float)
+            .collect();
+        assert_reset_streaming_matches_batch(TSF::new(9).unwrap(), &[-3.0; 12], &inputs, 1e-10);
+    }
+
+    #[test]
+    fn test_name_and_invalid_period() {
+        assert_eq!(TSF::new(14).unwrap().name(), "TSF(14)");
+        assert!(matches!(TSF::new(1), Err(TalibError::InvalidPeriod { .. })));
+    }
+}
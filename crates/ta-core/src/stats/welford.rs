@@ -0,0 +1,68 @@
+//! Sliding-window Welford accumulator shared by the rolling variance indicators.
+
+use crate::Float;
+
+/// Sliding-window Welford accumulator for the population variance.
+///
+/// Updating the mean and the sum of squared deviations directly avoids the
+/// catastrophic cancellation of `E[x^2] - mean^2` when values are large relative
+/// to their spread. Both are recomputed from the buffer with two passes once per
+/// `period` updates, so rounding error from the sliding update cannot accumulate.
+#[derive(Debug, Clone)]
+pub(super) struct WelfordWindow {
+    buffer: Vec<Float>,
+    index: usize,
+    count: usize,
+    mean: Float,
+    m2: Float,
+}
+
+impl WelfordWindow {
+    pub(super) fn new(period: usize) -> Self {
+        Self {
+            buffer: vec![0.0; period],
+            index: 0,
+            count: 0,
+            mean: 0.0,
+            m2: 0.0,
+        }
+    }
+
+    /// Push a value; returns the window variance once `period` values have been seen.
+    pub(super) fn push(&mut self, x: Float) -> Option<Float> {
+        let period = self.buffer.len();
+        if self.count < period {
+            self.count += 1;
+            let delta = x - self.mean;
+            self.mean += delta / self.count as Float;
+            self.m2 += delta * (x - self.mean);
+        } else {
+            let old = self.buffer[self.index];
+            let prev_mean = self.mean;
+            self.mean += (x - old) / period as Float;
+            self.m2 += (x - old) * (x - self.mean + old - prev_mean);
+        }
+        self.buffer[self.index] = x;
+        self.index = (self.index + 1) % period;
+        if self.count < period {
+            return None;
+        }
+        if self.index == 0 {
+            self.mean = self.buffer.iter().sum::<Float>() / period as Float;
+            self.m2 = self.buffer.iter().map(|v| (v - self.mean).powi(2)).sum();
+        }
+        Some(self.m2.max(0.0) / period as Float)
+    }
+
+    /// Mean of the values currently in the window.
+    pub(super) fn mean(&self) -> Float {
+        self.mean
+    }
+
+    pub(super) fn reset(&mut self) {
+        self.index = 0;
+        self.count = 0;
+        self.mean = 0.0;
+        self.m2 = 0.0;
+    }
+}
@@ -0,0 +1,248 @@
+//! Implementation of the rolling z-score and a batch anomaly scanner.
+
+use super::welford::WelfordWindow;
+use crate::{simd::dispatch, Float, Indicator, Resettable, Result, TalibError};
+
+/// Rolling z-score
+///
+/// How many standard deviations each value sits from the mean of the trailing
+/// window of `period` values that ends with it: `z = (x - mean) / std`, using the
+/// population standard deviation. A window with no spread gives a z-score of 0.
+///
+/// Because the value is part of its own window, `|z|` can never exceed
+/// `(period - 1) / sqrt(period)`; choose thresholds with that bound in mind.
+///
+/// Streaming updates are O(1) and use the same sliding Welford update as
+/// [`VAR`](super::VAR), so they stay accurate when values are large relative to
+/// their spread.
+#[derive(Debug, Clone)]
+pub struct ZScore {
+    period: usize,
+    window: WelfordWindow,
+}
+
+impl ZScore {
+    /// Create a rolling z-score over `period` values.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if `period < 2`.
+    pub fn new(period: usize) -> Result<Self> {
+        if period < 2 {
+            return Err(TalibError::invalid_period(
+                period,
+                "z-score needs a period of at least 2",
+            ));
+        }
+        Ok(Self {
+            period,
+            window: WelfordWindow::new(period),
+        })
+    }
+
+    #[inline]
+    fn score(&self, x: Float, mean: Float, variance: Float) -> Float {
+        // Spread below the rounding noise of the window's mean is no spread
+        let noise = self.period as Float * Float::EPSILON * mean;
+        if variance <= noise * noise {
+            0.0
+        } else {
+            (x - mean) / variance.sqrt()
+        }
+    }
+}
+
+impl Indicator for ZScore {
+    type Input = Float;
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        format!("ZScore({})", self.period)
+    }
+
+    fn lookback(&self) -> usize {
+        self.period - 1
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut result = vec![Float::NAN; inputs.len()];
+        if inputs.len() < self.period {
+            return Ok(result);
+        }
+        let inv_period = 1.0 / self.period as Float;
+        let variances = dispatch::rolling_variance(inputs, self.period, 0)?;
+        for ((window, variance), out) in inputs
+            .windows(self.period)
+            .zip(variances)
+            .zip(&mut result[self.period - 1..])
+        {
+            let mean = dispatch::sum(window) * inv_period;
+            *out = self.score(window[self.period - 1], mean, variance);
+        }
+        Ok(result)
+    }
+
+    fn next(&mut self, input: Float) -> Float {
+        match self.window.push(input) {
+            Some(variance) => self.score(input, self.window.mean(), variance),
+            None => Float::NAN,
+        }
+    }
+}
+
+impl Resettable for ZScore {
+    fn reset(&mut self) {
+        self.window.reset();
+    }
+}
+
+/// Indices of the inputs whose rolling z-score exceeds `threshold` in magnitude.
+///
+/// A one-call data-quality scan built on [`ZScore`]: each value is scored against
+/// the window of `period` values ending with it, so the first `period - 1` inputs
+/// are never flagged. Indices are returned in ascending order.
+///
+/// # Errors
+///
+/// Returns `TalibError::InvalidPeriod` if `period < 2` and
+/// `TalibError::InvalidParameter` unless `threshold` is finite and positive.
+///
+/// # Example
+///
+/// ```rust
+/// use ta_core::stats::detect_anomalies;
+///
+/// let mut data = vec![10.0; 30];
+/// data[20] = 50.0;
+/// assert_eq!(detect_anomalies(&data, 10, 2.5).unwrap(), vec![20]);
+/// ```
+pub fn detect_anomalies(inputs: &[Float], period: usize, threshold: Float) -> Result<Vec<usize>> {
+    if !(threshold.is_finite() && threshold > 0.0) {
+        return Err(TalibError::invalid_parameter(
+            "threshold".to_string(),
+            threshold.to_string(),
+            "a finite value greater than zero".to_string(),
+        ));
+    }
+    let scores = ZScore::new(period)?.compute_to_vec(inputs)?;
+    Ok(scores
+        .iter()
+        .enumerate()
+        .filter(|(_, z)| z.abs() > threshold)
+        .map(|(i, _)| i)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_reset_streaming_matches_batch;
+
+    fn noise(n: usize) -> Vec<Float> {
+        (0..n)
+            .map(|i| 100.0 + (i as Float * 1.3).sin() + (i as Float * 0.7).cos() * 0.5)
+            .collect()
+    }
+
+    #[test]
+    fn test_streaming_matches_batch() {
+        let inputs = noise(80);
+        let batch = ZScore::new(15).unwrap().compute_to_vec(&inputs).unwrap();
+        let mut stream = ZScore::new(15).unwrap();
+        for (x, expected) in inputs.iter().zip(&batch) {
+            let z = stream.next(*x);
+            assert!(
+                (z.is_nan() && expected.is_nan()) || (z - expected).abs() < 1e4 * Float::EPSILON
+            );
+        }
+    }
+
+    #[test]
+    fn test_streaming_matches_batch_at_large_offset() {
+        // A running sum of squares loses the spread entirely at this offset
+        let offset: Float = if cfg!(feature = "f32") { 1e3 } else { 1e8 };
+        let inputs: Vec<Float> = noise(200).iter().map(|x| x - 100.0 + offset).collect();
+        let batch = ZScore::new(15).unwrap().compute_to_vec(&inputs).unwrap();
+        let mut stream = ZScore::new(15).unwrap();
+        for (i, (&x, &expected)) in inputs.iter().zip(&batch).enumerate() {
+            let z = stream.next(x);
+            assert!(
+                (z.is_nan() && expected.is_nan()) || (z - expected).abs() < 1e-3,
+                "{z} != {expected} at {i}"
+            );
+        }
+    }
+
+    /// Deterministic standard-normal-like draws via Box-Muller over an LCG.
+    fn gaussian(n: usize) -> Vec<Float> {
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut uniform = || {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            ((state >> 11) as f64 + 0.5) / (1u64 << 53) as f64
+        };
+        (0..n)
+            .map(|_| {
+                let (u1, u2) = (uniform(), uniform());
+                ((-2.0 * u1.ln()).sqrt() * (2.0 * core::f64::consts::PI * u2).cos()) as Float
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_matches_two_pass_reference() {
+        let inputs = gaussian(300);
+        let period = 30;
+        let zscore = ZScore::new(period).unwrap();
+        let batch = zscore.compute_to_vec(&inputs).unwrap();
+        assert_reset_streaming_matches_batch(zscore, &[1e6], &inputs, 2e-6);
+        for (i, (&x, &z)) in inputs.iter().zip(&batch).enumerate() {
+            if i + 1 < period {
+                assert!(z.is_nan());
+                continue;
+            }
+            let window = &inputs[i + 1 - period..=i];
+            let mean = window.iter().sum::<Float>() / period as Float;
+            let var = window.iter().map(|v| (v - mean).powi(2)).sum::<Float>() / period as Float;
+            let expected = (x - mean) / var.sqrt();
+            assert!((z - expected).abs() < 1e-6, "batch at {i}");
+        }
+    }
+
+    #[test]
+    fn test_constant_window_scores_zero() {
+        // A large offset makes the running sum of squares cancel imperfectly
+        let mut inputs = gaussian(20);
+        inputs.extend([12_345.678; 25]);
+        let batch = ZScore::new(10).unwrap().compute_to_vec(&inputs).unwrap();
+        let mut stream = ZScore::new(10).unwrap();
+        let streamed: Vec<Float> = inputs.iter().map(|&x| stream.next(x)).collect();
+        assert!(batch[35..].iter().all(|&z| z == 0.0));
+        assert!(streamed[35..].iter().all(|&z| z == 0.0));
+    }
+
+    #[test]
+    fn test_detect_anomalies_flags_injected_outliers() {
+        let mut inputs = noise(200);
+        inputs[60] += 25.0;
+        inputs[140] -= 30.0;
+        assert_eq!(detect_anomalies(&inputs, 20, 3.0).unwrap(), vec![60, 140]);
+    }
+
+    #[test]
+    fn test_clean_series_has_no_anomalies() {
+        assert!(detect_anomalies(&noise(200), 20, 3.0).unwrap().is_empty());
+        assert!(detect_anomalies(&[5.0; 40], 10, 1.0).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(ZScore::new(1).is_err());
+        assert!(matches!(
+            detect_anomalies(&noise(10), 5, 0.0),
+            Err(TalibError::InvalidParameter { .. })
+        ));
+    }
+}
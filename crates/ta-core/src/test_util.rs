@@ -0,0 +1,60 @@
+//! Assertions shared by the indicator unit tests.
+
+use crate::{Float, Indicator, Resettable};
+
+/// Outputs whose components can be compared one by one.
+pub(crate) trait Components {
+    fn components(&self) -> &[Float];
+}
+
+impl Components for Float {
+    fn components(&self) -> &[Float] {
+        core::slice::from_ref(self)
+    }
+}
+
+impl<const N: usize> Components for [Float; N] {
+    fn components(&self) -> &[Float] {
+        self
+    }
+}
+
+/// Feed `junk` to `indicator`, reset it, then stream `inputs` and assert that every
+/// output matches `compute_to_vec` to within `tolerance`.
+///
+/// Warm-up `NaN`s must line up exactly; a `tolerance` of `0.0` demands identical
+/// values. Returns the streamed outputs for any further checks.
+pub(crate) fn assert_reset_streaming_matches_batch<I, const N: usize>(
+    mut indicator: I,
+    junk: &[I::Input],
+    inputs: &[I::Input],
+    tolerance: Float,
+) -> Vec<I::Output>
+where
+    I: Indicator<N> + Resettable,
+    I::Input: Copy,
+    I::Output: Components + core::fmt::Debug,
+{
+    let batch = indicator.compute_to_vec(inputs).unwrap();
+    for &x in junk {
+        indicator.next(x);
+    }
+    indicator.reset();
+    let streamed: Vec<I::Output> = inputs.iter().map(|&x| indicator.next(x)).collect();
+    for (i, (got, want)) in streamed.iter().zip(&batch).enumerate() {
+        let matches = got
+            .components()
+            .iter()
+            .zip(want.components())
+            .all(|(a, b)| (a.is_nan() && b.is_nan()) || a == b || (a - b).abs() <= tolerance);
+        assert!(
+            matches,
+            "{}: streamed {:?} vs batch {:?} at {}",
+            indicator.name(),
+            got,
+            want,
+            i
+        );
+    }
+    streamed
+}
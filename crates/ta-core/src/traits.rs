@@ -24,7 +24,11 @@
 //! See [error.rs](../error/index.html) for detailed validation patterns.
 //! Note that `stream()` uses `Option<Float>` where `None` indicates warm-up.
 
-use crate::error::Result;
+use core::borrow::Borrow;
+use core::mem::MaybeUninit;
+
+use crate::error::{Result, TalibError};
+use crate::types::{Float, Ohlc};
 /// Unified trait for technical analysis indicators
 ///
 /// This trait provides a unified interface that supports three usage modes:
@@ -45,6 +49,14 @@ use crate::error::Result;
 ///
 /// - `N`: Number of output values per input (default: 1). Multi-output indicators can specify a different value (e.g., Bollinger Bands might use `N=3`).
 ///
+/// # Multi-output NaN Semantics
+///
+/// For `N > 1` an output row is treated as a unit: if any component of a row would be
+/// non-finite, **every** component of that row is reported as `Float::NAN`. Callers can
+/// therefore check a single component to decide whether the whole row is usable, and
+/// composites never emit a partially valid row (e.g. a finite middle band with NaN outer
+/// bands). Both the batch and streaming paths must apply this rule identically.
+///
 /// # Example
 ///
 /// ```rust,ignore
@@ -86,6 +98,17 @@ pub trait Indicator<const N: usize = 1> {
     /// For multi-output indicators, this could be an array or tuple type.
     type Output;
 
+    /// Human-readable name with parameters, such as `SMA(20)` or `MACD(12,26,9)`
+    ///
+    /// Meant for logs, dashboards and registries of boxed indicators. The default
+    /// is the bare type name; the indicators in this crate override it to append
+    /// their parameters.
+    fn name(&self) -> String {
+        let path = core::any::type_name::<Self>();
+        let path = path.split('<').next().unwrap_or(path);
+        path.rsplit("::").next().unwrap_or(path).to_string()
+    }
+
     /// Returns the amount of historical data required to produce the first valid output
     ///
     /// This is also known as "warm-up period" or "initialization period". For example:
@@ -103,12 +126,58 @@ pub trait Indicator<const N: usize = 1> {
     /// let sma = Sma::new(20)?;
     /// assert_eq!(sma.lookback(), 19);
     ///
-    /// // With 25 data points, we get 25 - 19 = 6 outputs
-    /// let outputs = sma.compute_to_vec(&[0.0; 25])?;
-    /// assert_eq!(outputs.len(), 6);
+    /// // With 25 data points, 25 - 19 = 6 outputs are valid
+    /// assert_eq!(sma.valid_output_len(25), 6);
     /// ```
     fn lookback(&self) -> usize;
 
+    /// Returns the number of outputs `compute_to_vec` produces for `inputs_len` inputs
+    ///
+    /// Batch implementations use this to allocate their output vector with exactly
+    /// the right capacity. The default matches the full-length contract of
+    /// `compute_to_vec`, where warm-up slots are filled with `Float::NAN`, so only
+    /// indicators that emit a different number of outputs need to override it.
+    #[inline]
+    fn output_len(&self, inputs_len: usize) -> usize {
+        inputs_len
+    }
+
+    /// Returns the number of valid (post warm-up) outputs for `inputs_len` inputs
+    ///
+    /// This is `inputs_len - lookback()`, or zero when there are no more inputs than
+    /// the lookback. Use it to size buffers that hold only valid values instead of
+    /// computing the difference by hand.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let sma = SMA::new(20);
+    /// assert_eq!(sma.valid_output_len(25), 6);
+    /// assert_eq!(sma.valid_output_len(10), 0);
+    /// ```
+    #[inline]
+    fn valid_output_len(&self, inputs_len: usize) -> usize {
+        inputs_len.saturating_sub(self.lookback())
+    }
+
+    /// Returns the minimum number of inputs needed to produce `k` valid outputs
+    ///
+    /// The inverse question to [`valid_output_len`](Indicator::valid_output_len), useful when
+    /// sizing ring buffers. The default, `lookback() + k`, holds for every indicator
+    /// whose first valid output lands on index `lookback()`; indicators with a
+    /// variable number of valid outputs should override it.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let sma = SMA::new(20);
+    /// assert_eq!(sma.inputs_for_outputs(5), 24);
+    /// ```
+    #[inline]
+    fn inputs_for_outputs(&self, k: usize) -> usize {
+        self.lookback() + k
+    }
+
     /// Convenient batch computation with automatic memory management
     ///
     /// This method allocates an output vector and processes all inputs.
@@ -217,6 +286,221 @@ pub trait Indicator<const N: usize = 1> {
     /// - `next()`: Best performance, `Float::NAN` for warm-up
     /// - `stream()`: Batch processing, `Option<Float>` for clear semantics
     fn next(&mut self, input: Self::Input) -> Self::Output;
+
+    /// Batch computation with every output rounded to the nearest multiple of `tick`
+    ///
+    /// Useful when values must align with an exchange tick size or a display
+    /// precision. Halfway cases round away from zero (see [`Float::round`]).
+    /// Warm-up placeholders stay `Float::NAN`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidParameter` if `tick` is not finite and positive,
+    /// plus any error from `compute_to_vec`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let sma = SMA::new(2);
+    /// let outputs = sma.compute_quantized(&[1.0, 1.5, 1.75], 0.25)?;
+    /// // Raw SMA is [NaN, 1.25, 1.625]; 1.625 is halfway and rounds up
+    /// assert_eq!(outputs[2], 1.75);
+    /// ```
+    fn compute_quantized(&self, inputs: &[Self::Input], tick: Float) -> Result<Vec<Float>>
+    where
+        Self: Indicator<N, Output = Float> + Sized,
+    {
+        if !(tick.is_finite() && tick > 0.0) {
+            return Err(TalibError::invalid_parameter(
+                "tick".to_string(),
+                tick.to_string(),
+                "finite value greater than 0".to_string(),
+            ));
+        }
+        let mut outputs = self.compute_to_vec(inputs)?;
+        for value in outputs.iter_mut() {
+            *value = (*value / tick).round() * tick;
+        }
+        Ok(outputs)
+    }
+
+    /// Batch computation with every output passed through `f`
+    ///
+    /// Fuses the usual post-processing loop (rounding, unit conversion, building a
+    /// display struct) into the computation. `f` is called once per output in input
+    /// order, including the `Float::NAN` warm-up placeholders, so it decides how
+    /// those are represented.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let sma = SMA::new(2);
+    /// let cents = sma.compute_map(&[1.004, 1.016], |v| (v * 100.0).round() as i64)?;
+    /// assert_eq!(cents[1], 101);
+    /// ```
+    fn compute_map<T, F>(&self, inputs: &[Self::Input], f: F) -> Result<Vec<T>>
+    where
+        Self: Indicator<N, Output = Float> + Sized,
+        F: FnMut(Float) -> T,
+    {
+        Ok(self.compute_to_vec(inputs)?.into_iter().map(f).collect())
+    }
+
+    /// Batch computation emitting the change since the previous output
+    ///
+    /// Intended for bandwidth-limited streaming, where only the change needs to be
+    /// sent. The first valid output is emitted as-is and every later one as the
+    /// difference from the last valid output. Warm-up and other `Float::NAN` outputs
+    /// stay `Float::NAN` and are skipped, so a running sum of the finite deltas
+    /// reconstructs the original series.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let sma = SMA::new(2);
+    /// let deltas = sma.compute_deltas(&[1.0, 3.0, 4.0, 8.0])?;
+    /// // Raw SMA is [NaN, 2.0, 3.5, 6.0]
+    /// assert_eq!(&deltas[1..], &[2.0, 1.5, 2.5]);
+    /// ```
+    fn compute_deltas(&self, inputs: &[Self::Input]) -> Result<Vec<Float>>
+    where
+        Self: Indicator<N, Output = Float> + Sized,
+    {
+        let mut outputs = self.compute_to_vec(inputs)?;
+        let mut previous: Option<Float> = None;
+        for value in outputs.iter_mut() {
+            if value.is_nan() {
+                continue;
+            }
+            let current = *value;
+            if let Some(prev) = previous {
+                *value = current - prev;
+            }
+            previous = Some(current);
+        }
+        Ok(outputs)
+    }
+
+    /// Batch computation into a flat, row-interleaved buffer
+    ///
+    /// For multi-output indicators with `Output = [Float; N]`, writes each output row
+    /// contiguously: `out[row * N + k]` holds component `k` of row `row`. This layout
+    /// is convenient for FFI and GPU upload, where nested arrays are awkward.
+    ///
+    /// # Returns
+    ///
+    /// The number of rows written (one per input, including warm-up rows).
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::OutputBufferTooSmall` if `out` holds fewer than
+    /// `N * inputs.len()` values, plus any error from `compute_to_vec`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let bands = BollingerBands::new(20, 2.0)?;
+    /// let mut flat = vec![0.0; prices.len() * 3];
+    /// let rows = bands.compute_flat(&prices, &mut flat)?;
+    /// // flat = [lower0, middle0, upper0, lower1, middle1, upper1, ...]
+    /// ```
+    fn compute_flat(&self, inputs: &[Self::Input], out: &mut [Float]) -> Result<usize>
+    where
+        Self: Indicator<N, Output = [Float; N]> + Sized,
+    {
+        let required = N * inputs.len();
+        if out.len() < required {
+            return Err(TalibError::output_buffer_too_small(required, out.len()));
+        }
+        let rows = self.compute_to_vec(inputs)?;
+        for (dst, row) in out.chunks_exact_mut(N).zip(rows.iter()) {
+            dst.copy_from_slice(row);
+        }
+        Ok(rows.len())
+    }
+
+    /// Batch computation into an uninitialized buffer
+    ///
+    /// Writes the same values as `compute_to_vec` (warm-up placeholders included)
+    /// into the start of `out` without requiring it to be initialized first, and
+    /// returns how many were written. Only that prefix is initialized: callers may
+    /// `assume_init` on `out[..count]` and must not read anything past it.
+    ///
+    /// The default computes into a temporary vector and moves the values over;
+    /// hot indicators override it to write each output in place.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::OutputBufferTooSmall` if `out` cannot hold every value
+    /// `compute_to_vec` produces, plus any error from `compute_to_vec`. Nothing is
+    /// written in either case.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use core::mem::MaybeUninit;
+    /// use ta_core::{overlap::WMA, Float, Indicator, TalibError};
+    ///
+    /// let wma = WMA::new(3)?;
+    /// let inputs = [1.0, 2.0, 3.0, 4.0, 5.0];
+    /// let mut out = [MaybeUninit::<Float>::uninit(); 5];
+    /// let count = wma.compute_uninit(&inputs, &mut out)?;
+    /// // SAFETY: the first `count` elements were written
+    /// let values: Vec<Float> = out[..count].iter().map(|v| unsafe { v.assume_init() }).collect();
+    /// assert_eq!(count, inputs.len());
+    /// assert_eq!(values[2..], wma.compute_to_vec(&inputs)?[2..]);
+    ///
+    /// let mut short = [MaybeUninit::<Float>::uninit(); 4];
+    /// assert!(matches!(
+    ///     wma.compute_uninit(&inputs, &mut short),
+    ///     Err(TalibError::OutputBufferTooSmall { required: 5, actual: 4 })
+    /// ));
+    /// # Ok::<(), TalibError>(())
+    /// ```
+    fn compute_uninit(
+        &self,
+        inputs: &[Self::Input],
+        out: &mut [MaybeUninit<Self::Output>],
+    ) -> Result<usize> {
+        let required = self.output_len(inputs.len());
+        if out.len() < required {
+            return Err(TalibError::output_buffer_too_small(required, out.len()));
+        }
+        let outputs = self.compute_to_vec(inputs)?;
+        // `output_len` can be overridden, so check against what was actually produced
+        let count = outputs.len();
+        if out.len() < count {
+            return Err(TalibError::output_buffer_too_small(count, out.len()));
+        }
+        for (dst, value) in out.iter_mut().zip(outputs) {
+            dst.write(value);
+        }
+        Ok(count)
+    }
+
+    /// Reset the indicator and re-prime it from `history`
+    ///
+    /// Feeds every value of `history` through [`next`](Indicator::next) after a
+    /// [`reset`](Resettable::reset), discarding the outputs, so the indicator is
+    /// ready for live ticks as if it had seen `history` from the start.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let mut sma = SMA::new(3);
+    /// sma.warm_up(&[1.0, 2.0, 3.0]);
+    /// assert_eq!(sma.next(4.0), 3.0);
+    /// ```
+    fn warm_up(&mut self, history: &[Self::Input])
+    where
+        Self: Resettable + Sized,
+        Self::Input: Copy,
+    {
+        self.reset();
+        for &input in history {
+            self.next(input);
+        }
+    }
 }
 
 /// Trait for indicators that can reset their internal state
@@ -253,3 +537,469 @@ pub trait Resettable {
     /// All internal buffers and accumulated values are cleared or reset to defaults.
     fn reset(&mut self);
 }
+
+/// Streaming indicators whose state can be checkpointed and rolled back
+///
+/// `snapshot` captures everything `next` depends on, and `restore` puts it back, so
+/// an indicator can be warmed up once and then replayed along many branches (walk
+/// forward, Monte Carlo) without recomputing the warm-up. The configuration, such as
+/// the period, is not part of the state.
+pub trait StatefulIndicator {
+    /// Captured streaming state
+    type State: Clone;
+
+    /// Capture the current streaming state
+    fn snapshot(&self) -> Self::State;
+
+    /// Return to a previously captured state
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidInput` if `state` does not fit this indicator,
+    /// for example because it was captured with a different period.
+    fn restore(&mut self, state: Self::State) -> Result<()>;
+}
+
+/// Output types that can tell a real value from a warm-up placeholder
+///
+/// Implemented for `Float` and for the `[Float; N]` rows of multi-output
+/// indicators. Following the multi-output NaN contract, a row is valid only when
+/// none of its components is `Float::NAN`.
+pub trait IndicatorOutput {
+    /// Whether this output is a real value rather than a `Float::NAN` placeholder.
+    fn is_valid(&self) -> bool;
+}
+
+impl IndicatorOutput for Float {
+    #[inline]
+    fn is_valid(&self) -> bool {
+        !self.is_nan()
+    }
+}
+
+impl<const N: usize> IndicatorOutput for [Float; N] {
+    #[inline]
+    fn is_valid(&self) -> bool {
+        self.iter().all(|v| !v.is_nan())
+    }
+}
+
+/// Streaming helpers for indicators that consume price bars
+///
+/// Implemented for every single-output indicator whose input is an [`Ohlc`] bar, such
+/// as ATR, so candle feeds get `Option`-based streaming: `None` while the indicator
+/// is warming up (or otherwise emits `Float::NAN`), `Some(value)` afterwards.
+///
+/// # Example
+///
+/// ```rust
+/// use ta_core::{volatility::ATR, BarIndicator, Ohlc};
+///
+/// let bar = |high, low, close| Ohlc { open: close, high, low, close, volume: 0.0 };
+/// let mut atr = ATR::new(1).unwrap();
+/// assert_eq!(atr.next_bar(bar(10.0, 8.0, 9.0)), None);
+/// assert_eq!(atr.next_bar(bar(12.0, 9.0, 11.0)), Some(3.0));
+/// ```
+pub trait BarIndicator: Indicator<Input = Ohlc, Output = Float> {
+    /// Process one bar, returning `None` instead of `Float::NAN`.
+    #[inline]
+    fn next_bar(&mut self, bar: Ohlc) -> Option<Float> {
+        let value = self.next(bar);
+        (!value.is_nan()).then_some(value)
+    }
+
+    /// Process a sequence of bars in order, one [`next_bar`](BarIndicator::next_bar)
+    /// call each.
+    ///
+    /// Accepts anything that yields bars or references to bars, so both `&[Ohlc]`
+    /// and a live candle iterator work. State carries over between calls, unlike
+    /// the stateless `compute_to_vec`.
+    fn stream_bars<I>(&mut self, bars: I) -> Vec<Option<Float>>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Ohlc>,
+    {
+        bars.into_iter()
+            .map(|bar| self.next_bar(*bar.borrow()))
+            .collect()
+    }
+}
+
+impl<T: Indicator<Input = Ohlc, Output = Float>> BarIndicator for T {}
+
+/// Lazy streaming that yields only valid outputs
+///
+/// Implemented for every indicator whose output implements [`IndicatorOutput`].
+/// Unlike collecting `Option`s from a stream, nothing is allocated and the warm-up
+/// placeholders never reach the caller, so the result can be collected, folded or
+/// chained with other iterator adapters directly.
+///
+/// # Example
+///
+/// ```rust
+/// use ta_core::{overlap::SMA, Float, IterIndicator};
+///
+/// let mut sma = SMA::new(3);
+/// let total: Float = sma.iter_valid([1.0, 2.0, 3.0, 4.0, 5.0]).sum();
+/// assert_eq!(total, 2.0 + 3.0 + 4.0);
+/// ```
+pub trait IterIndicator<const N: usize = 1>: Indicator<N> {
+    /// Feed `inputs` through [`next`](Indicator::next) as the iterator is driven,
+    /// skipping outputs that are not [valid](IndicatorOutput::is_valid).
+    ///
+    /// Accepts anything that yields inputs or references to inputs. State carries
+    /// over between calls, and inputs the iterator is not driven to are never fed.
+    fn iter_valid<'a, I>(&'a mut self, inputs: I) -> impl Iterator<Item = Self::Output> + 'a
+    where
+        I: IntoIterator,
+        I::Item: Borrow<Self::Input>,
+        I::IntoIter: 'a,
+        Self::Input: Copy,
+        Self::Output: IndicatorOutput,
+        Self: Sized,
+    {
+        inputs
+            .into_iter()
+            .map(move |input| self.next(*input.borrow()))
+            .filter(IndicatorOutput::is_valid)
+    }
+
+    /// Feed a burst of `inputs` through [`next`](Indicator::next), appending only
+    /// the valid outputs to `out`, and return how many were appended.
+    ///
+    /// `out` is never cleared, so one buffer can be reused across bursts: clear it
+    /// between calls and its capacity is kept. State carries over between calls.
+    fn feed_slice(&mut self, inputs: &[Self::Input], out: &mut Vec<Self::Output>) -> usize
+    where
+        Self::Input: Copy,
+        Self::Output: IndicatorOutput,
+        Self: Sized,
+    {
+        let before = out.len();
+        out.extend(self.iter_valid(inputs));
+        out.len() - before
+    }
+}
+
+impl<T: Indicator<N>, const N: usize> IterIndicator<N> for T {}
+
+/// Batch helpers for indicators over two aligned series
+///
+/// Implemented for every indicator whose input is an `(a, b)` pair of floats, such
+/// as a spread or rolling alpha, so callers can pass the two series as separate
+/// slices instead of zipping them first.
+pub trait PairIndicator: Indicator<Input = (Float, Float)> {
+    /// Batch computation over two series of equal length.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::DimensionMismatch` if `a` and `b` differ in length, plus
+    /// any error from `compute_to_vec`.
+    fn compute_pair(&self, a: &[Float], b: &[Float]) -> Result<Vec<Self::Output>> {
+        if a.len() != b.len() {
+            return Err(TalibError::dimension_mismatch(a.len(), b.len()));
+        }
+        let pairs: Vec<(Float, Float)> = a.iter().copied().zip(b.iter().copied()).collect();
+        self.compute_to_vec(&pairs)
+    }
+}
+
+impl<T: Indicator<Input = (Float, Float)>> PairIndicator for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Minimal three-output indicator: `[x - 1, x, x + 1]`
+    struct Envelope;
+
+    impl Indicator<3> for Envelope {
+        type Input = Float;
+        type Output = [Float; 3];
+
+        fn lookback(&self) -> usize {
+            0
+        }
+
+        fn compute_to_vec(&self, inputs: &[Float]) -> Result<Vec<[Float; 3]>> {
+            Ok(inputs.iter().map(|&x| [x - 1.0, x, x + 1.0]).collect())
+        }
+
+        fn next(&mut self, input: Float) -> [Float; 3] {
+            [input - 1.0, input, input + 1.0]
+        }
+    }
+
+    #[test]
+    fn test_compute_flat_interleaves_rows() {
+        let mut flat = [0.0; 9];
+        let rows = Envelope
+            .compute_flat(&[10.0, 20.0, 30.0], &mut flat)
+            .unwrap();
+        assert_eq!(rows, 3);
+        assert_eq!(flat, [9.0, 10.0, 11.0, 19.0, 20.0, 21.0, 29.0, 30.0, 31.0]);
+    }
+
+    #[test]
+    fn test_compute_flat_allows_larger_buffer() {
+        let mut flat = [-1.0; 8];
+        let rows = Envelope.compute_flat(&[5.0, 6.0], &mut flat).unwrap();
+        assert_eq!(rows, 2);
+        assert_eq!(&flat[..6], &[4.0, 5.0, 6.0, 5.0, 6.0, 7.0]);
+        assert_eq!(&flat[6..], &[-1.0, -1.0]);
+    }
+
+    #[test]
+    fn test_iter_valid_skips_warm_up() {
+        use crate::overlap::SMA;
+
+        let inputs: Vec<Float> = (1..=10).map(|i| i as Float).collect();
+        let expected = SMA::new(4).compute_to_vec(&inputs).unwrap();
+        let mut sma = SMA::new(4);
+        let valid: Vec<Float> = sma.iter_valid(&inputs).collect();
+        assert_eq!(valid, expected[3..]);
+
+        // State carries over and the adapter composes with other combinators
+        let doubled: Vec<Float> = sma
+            .iter_valid([11.0, 12.0, 13.0])
+            .map(|v| v * 2.0)
+            .take(2)
+            .collect();
+        assert_eq!(doubled, [19.0, 21.0]);
+    }
+
+    #[test]
+    fn test_feed_slice_bursts_match_one_call() {
+        use crate::overlap::SMA;
+
+        let inputs: Vec<Float> = (1..=12).map(|i| i as Float).collect();
+        let mut combined = Vec::new();
+        assert_eq!(SMA::new(4).feed_slice(&inputs, &mut combined), 9);
+
+        let mut sma = SMA::new(4);
+        let mut out = Vec::with_capacity(16);
+        assert_eq!(sma.feed_slice(&inputs[..2], &mut out), 0);
+        assert_eq!(sma.feed_slice(&inputs[2..7], &mut out), 4);
+        assert_eq!(sma.feed_slice(&inputs[7..], &mut out), 5);
+        assert_eq!(out, combined);
+
+        // Reusing the buffer across bursts does not reallocate
+        let capacity = out.capacity();
+        out.clear();
+        assert_eq!(sma.feed_slice(&[13.0, 14.0], &mut out), 2);
+        assert_eq!(out, [11.5, 12.5]);
+        assert_eq!(out.capacity(), capacity);
+    }
+
+    #[test]
+    fn test_compute_uninit_default() {
+        let mut out = [MaybeUninit::<[Float; 3]>::uninit(); 4];
+        let count = Envelope.compute_uninit(&[1.0, 5.0], &mut out).unwrap();
+        assert_eq!(count, 2);
+        // SAFETY: the first `count` elements were written
+        let rows: Vec<[Float; 3]> = out[..count]
+            .iter()
+            .map(|v| unsafe { v.assume_init() })
+            .collect();
+        assert_eq!(rows, [[0.0, 1.0, 2.0], [4.0, 5.0, 6.0]]);
+
+        let mut small = [MaybeUninit::<[Float; 3]>::uninit(); 1];
+        assert_eq!(
+            Envelope.compute_uninit(&[1.0, 5.0], &mut small),
+            Err(TalibError::output_buffer_too_small(2, 1))
+        );
+    }
+
+    #[test]
+    fn test_compute_uninit_checks_produced_length() {
+        /// Under-reports `output_len`, so only the post-compute check can catch it
+        struct Halved;
+
+        impl Indicator for Halved {
+            type Input = Float;
+            type Output = Float;
+
+            fn lookback(&self) -> usize {
+                0
+            }
+
+            fn output_len(&self, inputs_len: usize) -> usize {
+                inputs_len / 2
+            }
+
+            fn compute_to_vec(&self, inputs: &[Float]) -> Result<Vec<Float>> {
+                Ok(inputs.to_vec())
+            }
+
+            fn next(&mut self, input: Float) -> Float {
+                input
+            }
+        }
+
+        let inputs = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let mut out = [MaybeUninit::<Float>::uninit(); 3];
+        assert_eq!(
+            Halved.compute_uninit(&inputs, &mut out),
+            Err(TalibError::output_buffer_too_small(6, 3))
+        );
+    }
+
+    #[test]
+    fn test_names() {
+        use crate::{momentum::MACD, overlap::SMA, transform::Chain, volume::OBV};
+
+        assert_eq!(SMA::new(20).name(), "SMA(20)");
+        assert_eq!(MACD::new(12, 26, 9).unwrap().name(), "MACD(12,26,9)");
+        assert_eq!(OBV::new().name(), "OBV");
+        assert_eq!(Envelope.name(), "Envelope");
+
+        let chained = Chain::new(SMA::new(3), SMA::new(5));
+        assert_eq!(chained.name(), "Chain(SMA(3),SMA(5))");
+
+        // Names survive dynamic dispatch, e.g. in a registry of boxed indicators
+        let registry: Vec<Box<dyn Indicator<Input = Float, Output = Float>>> =
+            vec![Box::new(SMA::new(10)), Box::new(chained)];
+        let names: Vec<String> = registry.iter().map(|i| i.name()).collect();
+        assert_eq!(names, ["SMA(10)", "Chain(SMA(3),SMA(5))"]);
+    }
+
+    #[test]
+    fn test_warm_up_replays_history() {
+        use crate::overlap::SMA;
+
+        let mut sma = SMA::new(3);
+        sma.warm_up(&[1.0, 2.0, 3.0]);
+        assert_eq!(sma.next(4.0), 3.0);
+
+        // Earlier state is discarded
+        sma.warm_up(&[10.0, 20.0]);
+        assert_eq!(sma.next(30.0), 20.0);
+    }
+
+    #[test]
+    fn test_iter_valid_multi_output() {
+        let rows: Vec<[Float; 3]> = Envelope.iter_valid([1.0, Float::NAN, 2.0]).collect();
+        assert_eq!(rows, [[0.0, 1.0, 2.0], [1.0, 2.0, 3.0]]);
+    }
+
+    #[test]
+    fn test_compute_flat_rejects_small_buffer() {
+        let mut flat = [0.0; 5];
+        let result = Envelope.compute_flat(&[1.0, 2.0], &mut flat);
+        assert_eq!(result, Err(TalibError::output_buffer_too_small(6, 5)));
+    }
+
+    #[test]
+    fn test_compute_deltas_reconstructs_outputs() {
+        use crate::overlap::SMA;
+
+        let inputs: Vec<Float> = (0..40)
+            .map(|i| 10.0 + (i as Float * 0.3).sin() * 2.0)
+            .collect();
+        let sma = SMA::new(4);
+        let outputs = sma.compute_to_vec(&inputs).unwrap();
+        let deltas = sma.compute_deltas(&inputs).unwrap();
+        assert_eq!(deltas.len(), outputs.len());
+        assert!(deltas[..3].iter().all(|d| d.is_nan()));
+        assert_eq!(deltas[3], outputs[3]);
+
+        let mut running = 0.0;
+        for (delta, output) in deltas.iter().zip(&outputs).skip(3) {
+            running += delta;
+            assert!((running - output).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_inputs_for_outputs_yields_exactly_k_values() {
+        use crate::{
+            overlap::{McGinleyDynamic, SMA},
+            stats::Alpha,
+            transform::{OnReturns, ReturnKind},
+            volume::Klinger,
+            Ohlc,
+        };
+
+        fn valid(values: &[Float]) -> usize {
+            values.iter().filter(|v| !v.is_nan()).count()
+        }
+        let price = |i: usize| 100.0 + (i as Float * 0.5).sin() * 3.0 + i as Float * 0.1;
+
+        for k in [1, 5, 12] {
+            let sma = SMA::new(7);
+            let n = sma.inputs_for_outputs(k);
+            assert_eq!(n, 6 + k);
+            let prices: Vec<Float> = (0..n).map(price).collect();
+            assert_eq!(valid(&sma.compute_to_vec(&prices).unwrap()), k);
+
+            let md = McGinleyDynamic::with_period(10).unwrap();
+            let prices: Vec<Float> = (0..md.inputs_for_outputs(k)).map(price).collect();
+            assert_eq!(valid(&md.compute_to_vec(&prices).unwrap()), k);
+
+            let wrapped = OnReturns::new(SMA::new(4), ReturnKind::Log);
+            let prices: Vec<Float> = (0..wrapped.inputs_for_outputs(k)).map(price).collect();
+            assert_eq!(valid(&wrapped.compute_to_vec(&prices).unwrap()), k);
+
+            let alpha = Alpha::new(6).unwrap();
+            let pairs: Vec<(Float, Float)> = (0..alpha.inputs_for_outputs(k))
+                .map(|i| {
+                    (
+                        (i as Float * 0.9).cos() * 0.01,
+                        (i as Float * 0.7).sin() * 0.02,
+                    )
+                })
+                .collect();
+            assert_eq!(valid(&alpha.compute_to_vec(&pairs).unwrap()), k);
+
+            let klinger = Klinger::new(3, 6, 4).unwrap();
+            let bars: Vec<Ohlc> = (0..klinger.inputs_for_outputs(k))
+                .map(|i| {
+                    let mid = price(i);
+                    Ohlc {
+                        open: mid,
+                        high: mid + 1.0 + (i % 3) as Float,
+                        low: mid - 1.0,
+                        close: mid + 0.5,
+                        volume: 1000.0,
+                    }
+                })
+                .collect();
+            let rows = klinger.compute_to_vec(&bars).unwrap();
+            assert_eq!(rows.iter().filter(|r| !r[0].is_nan()).count(), k);
+        }
+    }
+
+    #[test]
+    fn test_stream_bars_matches_compute() {
+        use crate::volatility::ATR;
+
+        let bars: Vec<Ohlc> = (0..30)
+            .map(|i| {
+                let mid = 10.0 + (i as Float * 0.6).sin();
+                Ohlc {
+                    open: mid,
+                    high: mid + 0.5 + (i % 3) as Float * 0.2,
+                    low: mid - 0.4,
+                    close: mid + 0.1,
+                    volume: 0.0,
+                }
+            })
+            .collect();
+        let batch = ATR::new(5).unwrap().compute_to_vec(&bars).unwrap();
+
+        let mut one_at_a_time = ATR::new(5).unwrap();
+        for (bar, &expected) in bars.iter().zip(&batch) {
+            let value = one_at_a_time.next_bar(*bar);
+            assert_eq!(value, (!expected.is_nan()).then_some(expected));
+        }
+
+        // Split across a slice and an iterator of owned bars; state carries over
+        let mut streamed = ATR::new(5).unwrap();
+        let mut values = streamed.stream_bars(&bars[..12]);
+        values.extend(streamed.stream_bars(bars[12..].iter().copied()));
+        assert_eq!(values.iter().filter(|v| v.is_none()).count(), 5);
+        for (value, &expected) in values.iter().zip(&batch) {
+            assert_eq!(*value, (!expected.is_nan()).then_some(expected));
+        }
+    }
+}
@@ -0,0 +1,451 @@
+//! Input transforms applied before an indicator
+//!
+//! These wrappers change what an indicator sees rather than how it computes, so any
+//! existing indicator can be run on a derived series, or on another indicator's
+//! output, without a dedicated variant.
+
+use core::mem::MaybeUninit;
+
+use crate::{
+    error::{Result, TalibError},
+    traits::{Indicator, IndicatorOutput, Resettable},
+    types::Float,
+};
+
+/// How consecutive prices are turned into returns
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReturnKind {
+    /// `price / prev - 1`
+    Simple,
+    /// `ln(price / prev)`, defined only for strictly positive prices
+    Log,
+}
+
+impl ReturnKind {
+    /// Return from `prev` to `price`, or `None` if it is undefined.
+    fn apply(self, prev: Float, price: Float) -> Option<Float> {
+        let value = match self {
+            ReturnKind::Simple if prev != 0.0 => price / prev - 1.0,
+            ReturnKind::Log if prev > 0.0 && price > 0.0 => (price / prev).ln(),
+            _ => return None,
+        };
+        value.is_finite().then_some(value)
+    }
+}
+
+/// Runs an indicator on returns instead of prices
+///
+/// Each input price is converted to a simple or log return against the previous
+/// price before being passed to the wrapped indicator. The first price only seeds
+/// the return, so `lookback()` is one more than the inner indicator's.
+///
+/// In batch mode, a price for which the return is undefined (a zero previous price
+/// for simple returns, or any non-positive price for log returns) is rejected with
+/// `TalibError::InvalidInput`. In streaming mode such a price yields `Float::NAN` and
+/// is dropped without touching the wrapper or inner state.
+///
+/// # Example
+///
+/// ```rust
+/// use ta_core::{overlap::SMA, transform::{OnReturns, ReturnKind}, Float, Indicator};
+///
+/// let sma = OnReturns::new(SMA::new(2), ReturnKind::Simple);
+/// let out = sma.compute_to_vec(&[100.0, 110.0, 121.0]).unwrap();
+/// assert!(out[1].is_nan());
+/// assert!((out[2] - 0.1).abs() < 1e3 * Float::EPSILON);
+/// ```
+#[derive(Debug, Clone)]
+pub struct OnReturns<I> {
+    inner: I,
+    kind: ReturnKind,
+    prev: Option<Float>,
+}
+
+impl<I> OnReturns<I> {
+    /// Wrap `inner` so that it is fed returns of the given kind.
+    pub fn new(inner: I, kind: ReturnKind) -> Self {
+        Self {
+            inner,
+            kind,
+            prev: None,
+        }
+    }
+
+    /// The kind of returns fed to the inner indicator.
+    pub fn kind(&self) -> ReturnKind {
+        self.kind
+    }
+
+    /// Access the wrapped indicator.
+    pub fn inner(&self) -> &I {
+        &self.inner
+    }
+}
+
+impl<I> Indicator for OnReturns<I>
+where
+    I: Indicator<Input = Float, Output = Float>,
+{
+    type Input = Float;
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        format!("OnReturns({:?},{})", self.kind, self.inner.name())
+    }
+
+    fn lookback(&self) -> usize {
+        self.inner.lookback() + 1
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let returns = inputs
+            .windows(2)
+            .enumerate()
+            .map(|(i, pair)| {
+                self.kind.apply(pair[0], pair[1]).ok_or_else(|| {
+                    TalibError::invalid_input(format!(
+                        "{:?} return undefined at index {}",
+                        self.kind,
+                        i + 1
+                    ))
+                })
+            })
+            .collect::<Result<Vec<Float>>>()?;
+
+        let mut outputs = Vec::with_capacity(self.output_len(inputs.len()));
+        if !inputs.is_empty() {
+            outputs.push(Float::NAN);
+            outputs.extend(self.inner.compute_to_vec(&returns)?);
+        }
+        Ok(outputs)
+    }
+
+    fn next(&mut self, input: Float) -> Float {
+        let Some(prev) = self.prev else {
+            if self.kind == ReturnKind::Log && input <= 0.0 {
+                return Float::NAN;
+            }
+            self.prev = Some(input);
+            return Float::NAN;
+        };
+        match self.kind.apply(prev, input) {
+            Some(value) => {
+                self.prev = Some(input);
+                self.inner.next(value)
+            }
+            None => Float::NAN,
+        }
+    }
+}
+
+impl<I: Resettable> Resettable for OnReturns<I> {
+    fn reset(&mut self) {
+        self.prev = None;
+        self.inner.reset();
+    }
+}
+
+/// Feeds the output of one indicator into another
+///
+/// `Chain<A, B>` runs `first` on the inputs and `second` on `first`'s output series.
+/// The two indicators may take different inputs, so an `Ohlc` indicator such as
+/// ATR can feed a price indicator such as SMA. The warm-up periods add up:
+/// `lookback() == first.lookback() + second.lookback()`.
+///
+/// `second` only starts receiving values once `first` is past its warm-up, so the
+/// two warm-up periods do not overlap.
+///
+/// # Example
+///
+/// ```rust
+/// use ta_core::{overlap::SMA, transform::Chain, Indicator};
+///
+/// let smoothed = Chain::new(SMA::new(2), SMA::new(2));
+/// let out = smoothed.compute_to_vec(&[1.0, 3.0, 5.0, 7.0]).unwrap();
+/// assert!(out[1].is_nan());
+/// assert_eq!(out[2], 3.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Chain<A, B> {
+    first: A,
+    second: B,
+    seen: usize,
+}
+
+impl<A, B> Chain<A, B> {
+    /// Chain `first` into `second`.
+    pub fn new(first: A, second: B) -> Self {
+        Self {
+            first,
+            second,
+            seen: 0,
+        }
+    }
+
+    /// Access the first indicator, which receives the raw inputs.
+    pub fn first(&self) -> &A {
+        &self.first
+    }
+
+    /// Access the second indicator, which receives the first one's outputs.
+    pub fn second(&self) -> &B {
+        &self.second
+    }
+}
+
+impl<A, B> Indicator for Chain<A, B>
+where
+    A: Indicator<Output = Float>,
+    B: Indicator<Input = Float, Output = Float>,
+{
+    type Input = A::Input;
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        format!("Chain({},{})", self.first.name(), self.second.name())
+    }
+
+    fn lookback(&self) -> usize {
+        self.first.lookback() + self.second.lookback()
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let intermediate = self.first.compute_to_vec(inputs)?;
+        let warm_up = self.first.lookback().min(intermediate.len());
+        let mut outputs = Vec::with_capacity(self.output_len(inputs.len()));
+        outputs.resize(warm_up, Float::NAN);
+        outputs.extend(self.second.compute_to_vec(&intermediate[warm_up..])?);
+        Ok(outputs)
+    }
+
+    fn next(&mut self, input: Self::Input) -> Float {
+        let value = self.first.next(input);
+        if self.seen < self.first.lookback() {
+            self.seen += 1;
+            return Float::NAN;
+        }
+        self.second.next(value)
+    }
+}
+
+impl<A: Resettable, B: Resettable> Resettable for Chain<A, B> {
+    fn reset(&mut self) {
+        self.first.reset();
+        self.second.reset();
+        self.seen = 0;
+    }
+}
+
+/// Counts the valid outputs an indicator has streamed
+///
+/// Wraps any indicator and tracks how many non-`Float::NAN` values `next` has
+/// returned since creation or the last `reset()`, so signal logic can wait until an
+/// indicator has been warm for a while before acting on it. Batch computation is
+/// passed straight through and does not affect the count.
+///
+/// # Example
+///
+/// ```rust
+/// use ta_core::{overlap::SMA, transform::Counted, Indicator};
+///
+/// let mut sma = Counted::new(SMA::new(2));
+/// sma.next(1.0);
+/// sma.next(2.0);
+/// sma.next(3.0);
+/// assert_eq!(sma.outputs_emitted(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Counted<I> {
+    inner: I,
+    emitted: usize,
+}
+
+impl<I> Counted<I> {
+    /// Wrap `inner`, starting the count at zero.
+    pub fn new(inner: I) -> Self {
+        Self { inner, emitted: 0 }
+    }
+
+    /// Number of valid outputs `next` has produced since creation or reset.
+    pub fn outputs_emitted(&self) -> usize {
+        self.emitted
+    }
+
+    /// Access the wrapped indicator.
+    pub fn inner(&self) -> &I {
+        &self.inner
+    }
+}
+
+impl<const N: usize, I> Indicator<N> for Counted<I>
+where
+    I: Indicator<N>,
+    I::Output: IndicatorOutput,
+{
+    type Input = I::Input;
+
+    type Output = I::Output;
+
+    fn name(&self) -> String {
+        self.inner.name()
+    }
+
+    fn lookback(&self) -> usize {
+        self.inner.lookback()
+    }
+
+    fn output_len(&self, inputs_len: usize) -> usize {
+        self.inner.output_len(inputs_len)
+    }
+
+    fn valid_output_len(&self, inputs_len: usize) -> usize {
+        self.inner.valid_output_len(inputs_len)
+    }
+
+    fn inputs_for_outputs(&self, k: usize) -> usize {
+        self.inner.inputs_for_outputs(k)
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        self.inner.compute_to_vec(inputs)
+    }
+
+    fn compute_uninit(
+        &self,
+        inputs: &[Self::Input],
+        out: &mut [MaybeUninit<Self::Output>],
+    ) -> Result<usize> {
+        self.inner.compute_uninit(inputs, out)
+    }
+
+    fn next(&mut self, input: Self::Input) -> Self::Output {
+        let output = self.inner.next(input);
+        if output.is_valid() {
+            self.emitted += 1;
+        }
+        output
+    }
+}
+
+impl<I: Resettable> Resettable for Counted<I> {
+    fn reset(&mut self) {
+        self.inner.reset();
+        self.emitted = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::overlap::SMA;
+
+    fn prices() -> Vec<Float> {
+        (0..50)
+            .map(|i| 100.0 + (i as Float * 0.4).sin() * 8.0 + i as Float * 0.2)
+            .collect()
+    }
+
+    fn assert_same(a: &[Float], b: &[Float]) {
+        assert_eq!(a.len(), b.len());
+        for (x, y) in a.iter().zip(b) {
+            assert!((x.is_nan() && y.is_nan()) || (x - y).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_matches_sma_over_precomputed_returns() {
+        let prices = prices();
+        for kind in [ReturnKind::Simple, ReturnKind::Log] {
+            let returns: Vec<Float> = prices
+                .windows(2)
+                .map(|w| match kind {
+                    ReturnKind::Simple => w[1] / w[0] - 1.0,
+                    ReturnKind::Log => (w[1] / w[0]).ln(),
+                })
+                .collect();
+            let mut expected = vec![Float::NAN];
+            expected.extend(SMA::new(5).compute_to_vec(&returns).unwrap());
+
+            let wrapped = OnReturns::new(SMA::new(5), kind);
+            assert_eq!(wrapped.lookback(), 5);
+            assert_same(&wrapped.compute_to_vec(&prices).unwrap(), &expected);
+
+            let mut stream = OnReturns::new(SMA::new(5), kind);
+            let streamed: Vec<Float> = prices.iter().map(|&p| stream.next(p)).collect();
+            assert_same(&streamed, &expected);
+        }
+    }
+
+    #[test]
+    fn test_log_returns_reject_non_positive_prices() {
+        let wrapped = OnReturns::new(SMA::new(2), ReturnKind::Log);
+        assert!(matches!(
+            wrapped.compute_to_vec(&[1.0, 2.0, 0.0, 3.0]),
+            Err(TalibError::InvalidInput { .. })
+        ));
+
+        let mut stream = OnReturns::new(SMA::new(1), ReturnKind::Log);
+        stream.next(100.0);
+        assert!(stream.next(-5.0).is_nan());
+        assert!((stream.next(110.0) - (1.1 as Float).ln()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_chain_atr_into_sma() {
+        use crate::{volatility::ATR, Ohlc};
+
+        let bars: Vec<Ohlc> = (0..60)
+            .map(|i| {
+                let mid = 50.0 + (i as Float * 0.3).sin() * 4.0;
+                let range = 1.0 + (i % 5) as Float * 0.4;
+                Ohlc {
+                    open: mid,
+                    high: mid + range,
+                    low: mid - range,
+                    close: mid + (i as Float).cos() * range * 0.5,
+                    volume: 0.0,
+                }
+            })
+            .collect();
+
+        let atr = ATR::new(14).unwrap().compute_to_vec(&bars).unwrap();
+        let mut expected = vec![Float::NAN; 14];
+        expected.extend(SMA::new(5).compute_to_vec(&atr[14..]).unwrap());
+
+        let chain = Chain::new(ATR::new(14).unwrap(), SMA::new(5));
+        assert_eq!(chain.lookback(), 18);
+        let batch = chain.compute_to_vec(&bars).unwrap();
+        assert_same(&batch, &expected);
+        assert!(batch[..18].iter().all(|v| v.is_nan()));
+        assert!(batch[18..].iter().all(|v| v.is_finite()));
+
+        let mut stream = Chain::new(ATR::new(14).unwrap(), SMA::new(5));
+        let streamed: Vec<Float> = bars.iter().map(|&bar| stream.next(bar)).collect();
+        assert_same(&streamed, &expected);
+    }
+
+    #[test]
+    fn test_counted_matches_valid_next_returns() {
+        use crate::{overlap::MaEnvelope, overlap::MaKind};
+
+        let mut sma = Counted::new(SMA::new(4));
+        let mut valid = 0;
+        for (i, price) in prices().into_iter().enumerate() {
+            if !sma.next(price).is_nan() {
+                valid += 1;
+            }
+            assert_eq!(sma.outputs_emitted(), valid);
+            assert_eq!(sma.outputs_emitted(), (i + 1).saturating_sub(3));
+        }
+
+        let mut envelope = Counted::new(MaEnvelope::new(3, MaKind::Ema, 0.01).unwrap());
+        for price in [1.0, 2.0, 3.0, 4.0] {
+            envelope.next(price);
+        }
+        assert_eq!(envelope.outputs_emitted(), 2);
+        envelope.reset();
+        assert_eq!(envelope.outputs_emitted(), 0);
+    }
+}
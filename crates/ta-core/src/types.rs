@@ -7,6 +7,10 @@
 //!
 //! - `f64` (default): Use double-precision floating-point
 //! - `f32`: Use single-precision floating-point
+//! - `fixed` (experimental): Additionally provide the [`Fixed`] 64.64 fixed-point type
+//!
+//! Indicators that are generic over [`Real`] accept both widths regardless of the
+//! feature selection.
 //!
 //! # Example
 //!
@@ -18,6 +22,8 @@
 //! assert_eq!(x + y, 3.0);
 //! ```
 
+use crate::{Result, TalibError};
+
 /// Floating-point type used throughout the library
 ///
 /// When `f32` feature is enabled, this is `f32` (single-precision).
@@ -32,6 +38,300 @@ pub type Float = f32;
 #[cfg(not(feature = "f32"))]
 pub type Float = f64;
 
+/// Floating-point element type for indicators generic over their width
+///
+/// Implemented for `f32` and `f64`, so generic indicators such as
+/// [`Sma`](crate::overlap::Sma) can process series of both widths in one program,
+/// independent of the crate-wide [`Float`] selected by features.
+pub trait Real:
+    Copy
+    + PartialOrd
+    + Default
+    + core::fmt::Debug
+    + core::ops::Add<Output = Self>
+    + core::ops::Sub<Output = Self>
+    + core::ops::Mul<Output = Self>
+    + core::ops::Div<Output = Self>
+{
+    /// Additive identity
+    const ZERO: Self;
+    /// Multiplicative identity
+    const ONE: Self;
+    /// Not-a-number, used for warm-up outputs
+    const NAN: Self;
+
+    /// Convert a count (such as a period) to this type
+    fn from_usize(n: usize) -> Self;
+
+    /// Whether the value is NaN
+    fn is_nan(self) -> bool;
+
+    /// Whether the value is neither infinite nor NaN
+    fn is_finite(self) -> bool;
+
+    /// SIMD sum of `values`
+    fn sum(values: &[Self]) -> Self;
+}
+
+macro_rules! impl_real {
+    ($t:ty, $simd:ty) => {
+        impl Real for $t {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const NAN: Self = <$t>::NAN;
+
+            #[inline]
+            fn from_usize(n: usize) -> Self {
+                n as $t
+            }
+
+            #[inline]
+            fn is_nan(self) -> bool {
+                <$t>::is_nan(self)
+            }
+
+            #[inline]
+            fn is_finite(self) -> bool {
+                <$t>::is_finite(self)
+            }
+
+            #[inline]
+            fn sum(values: &[Self]) -> Self {
+                const LANES: usize = core::mem::size_of::<$simd>() / core::mem::size_of::<$t>();
+                let mut acc = <$simd>::splat(0.0);
+                let mut chunks = values.chunks_exact(LANES);
+                for chunk in &mut chunks {
+                    acc += <$simd>::from(chunk);
+                }
+                // Horizontal reduction via the lane array, which every `wide` width supports
+                let mut sum: $t = acc.to_array().iter().sum();
+                for &v in chunks.remainder() {
+                    sum += v;
+                }
+                sum
+            }
+        }
+    };
+}
+
+impl_real!(f32, wide::f32x16);
+impl_real!(f64, wide::f64x8);
+
+/// How a streaming `next` call treats a non-finite input
+///
+/// Indicators that support a policy document how it interacts with their state.
+/// The type covers non-finite values in general rather than only NaN, which is why
+/// it is not called `NanPolicy`; [`Hold`](Self::Hold) is the policy other libraries
+/// call *skip*, and it holds the last output rather than emitting nothing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InvalidPolicy {
+    /// Feed the input through unchanged, so it reaches the indicator state (default)
+    #[default]
+    Propagate,
+    /// Leave the state untouched and return the previously emitted output again
+    Hold,
+    /// Discard all accumulated state and return `Float::NAN`, so the indicator warms
+    /// up again from the next input
+    Reset,
+}
+
+/// A single price bar with volume
+///
+/// Input type for indicators that need more than a single price per bar, such as
+/// volume-weighted or range-based indicators.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ohlc {
+    /// Opening price
+    pub open: Float,
+    /// Highest price
+    pub high: Float,
+    /// Lowest price
+    pub low: Float,
+    /// Closing price
+    pub close: Float,
+    /// Traded volume
+    pub volume: Float,
+}
+
+impl Ohlc {
+    /// Create a bar, checking that `high >= low`.
+    ///
+    /// Fields can also be set directly with a struct literal, which skips the check.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidInput` if `high < low` or either is NaN.
+    pub fn new(open: Float, high: Float, low: Float, close: Float, volume: Float) -> Result<Self> {
+        if high < low || high.is_nan() || low.is_nan() {
+            return Err(TalibError::invalid_input(format!(
+                "bar high {} is below its low {}",
+                high, low
+            )));
+        }
+        Ok(Self {
+            open,
+            high,
+            low,
+            close,
+            volume,
+        })
+    }
+
+    /// Typical price `(high + low + close) / 3`
+    #[inline]
+    pub fn typical_price(&self) -> Float {
+        (self.high + self.low + self.close) / 3.0
+    }
+}
+
+/// Alias of [`Ohlc`] for code that talks about candles
+pub type Candle = Ohlc;
+
+/// Read access to the prices of a bar
+///
+/// Lets helpers accept any bar-like type, not just [`Ohlc`].
+pub trait PriceSource {
+    /// Highest price
+    fn high(&self) -> Float;
+
+    /// Lowest price
+    fn low(&self) -> Float;
+
+    /// Closing price
+    fn close(&self) -> Float;
+
+    /// Typical price `(high + low + close) / 3`
+    #[inline]
+    fn typical_price(&self) -> Float {
+        (self.high() + self.low() + self.close()) / 3.0
+    }
+}
+
+impl PriceSource for Ohlc {
+    #[inline]
+    fn high(&self) -> Float {
+        self.high
+    }
+
+    #[inline]
+    fn low(&self) -> Float {
+        self.low
+    }
+
+    #[inline]
+    fn close(&self) -> Float {
+        self.close
+    }
+}
+
+/// Signed 64.64 fixed-point number (I64F64)
+///
+/// Stored as an `i128` with 64 integer and 64 fractional bits. All arithmetic is
+/// integer arithmetic, so results are bit-identical across architectures (no
+/// x86/ARM float rounding differences). Intended for the scalar paths that need
+/// deterministic output, such as [`crate::simd::scalar::sum_fixed`] and
+/// `SMA::compute_fixed`.
+///
+/// Arithmetic overflow follows Rust's integer semantics (panics in debug builds).
+#[cfg(feature = "fixed")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Fixed(i128);
+
+#[cfg(feature = "fixed")]
+impl Fixed {
+    /// Number of fractional bits
+    pub const FRAC_BITS: u32 = 64;
+    /// Zero
+    pub const ZERO: Fixed = Fixed(0);
+    /// One
+    pub const ONE: Fixed = Fixed(1 << Self::FRAC_BITS);
+
+    /// Create from the raw `i128` representation
+    #[inline]
+    pub const fn from_bits(bits: i128) -> Self {
+        Fixed(bits)
+    }
+
+    /// Raw `i128` representation
+    #[inline]
+    pub const fn to_bits(self) -> i128 {
+        self.0
+    }
+
+    /// Create from an integer value
+    #[inline]
+    pub const fn from_int(value: i64) -> Self {
+        Fixed((value as i128) << Self::FRAC_BITS)
+    }
+
+    /// Convert from `Float`, truncating toward zero and saturating out-of-range values
+    ///
+    /// NaN converts to zero.
+    #[inline]
+    #[allow(clippy::unnecessary_cast)] // Float may be f32
+    pub fn from_float(value: Float) -> Self {
+        // Scaling by a power of two is exact, and `as` saturates deterministically
+        Fixed((value as f64 * 18_446_744_073_709_551_616.0) as i128)
+    }
+
+    /// Convert to `Float` (rounds to nearest)
+    #[inline]
+    #[allow(clippy::unnecessary_cast)] // Float may be f32
+    pub fn to_float(self) -> Float {
+        (self.0 as f64 / 18_446_744_073_709_551_616.0) as Float
+    }
+
+    /// Divide by a positive integer, truncating toward zero
+    #[inline]
+    pub fn div_int(self, divisor: usize) -> Self {
+        Fixed(self.0 / divisor as i128)
+    }
+}
+
+#[cfg(feature = "fixed")]
+impl core::ops::Add for Fixed {
+    type Output = Fixed;
+
+    #[inline]
+    fn add(self, rhs: Fixed) -> Fixed {
+        Fixed(self.0 + rhs.0)
+    }
+}
+
+#[cfg(feature = "fixed")]
+impl core::ops::Sub for Fixed {
+    type Output = Fixed;
+
+    #[inline]
+    fn sub(self, rhs: Fixed) -> Fixed {
+        Fixed(self.0 - rhs.0)
+    }
+}
+
+#[cfg(feature = "fixed")]
+impl core::ops::AddAssign for Fixed {
+    #[inline]
+    fn add_assign(&mut self, rhs: Fixed) {
+        self.0 += rhs.0;
+    }
+}
+
+#[cfg(feature = "fixed")]
+impl core::ops::SubAssign for Fixed {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Fixed) {
+        self.0 -= rhs.0;
+    }
+}
+
+#[cfg(feature = "fixed")]
+impl core::iter::Sum for Fixed {
+    fn sum<I: Iterator<Item = Fixed>>(iter: I) -> Fixed {
+        iter.fold(Fixed::ZERO, |acc, x| acc + x)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -48,4 +348,53 @@ mod tests {
         let y: Float = 2.5;
         assert!((x + y - 4.0).abs() < 1e-10);
     }
+
+    #[test]
+    fn test_ohlc_typical_price() {
+        let bar = Ohlc {
+            open: 1.0,
+            high: 4.0,
+            low: 2.0,
+            close: 3.0,
+            volume: 10.0,
+        };
+        assert!((bar.typical_price() - 3.0).abs() < 1e-12);
+        assert!((PriceSource::typical_price(&bar) - 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_ohlc_new_checks_range() {
+        let bar: Candle = Ohlc::new(10.0, 12.0, 9.0, 11.0, 500.0).unwrap();
+        assert_eq!(bar.high(), 12.0);
+        assert_eq!(bar.low(), 9.0);
+        assert!((bar.typical_price() - (12.0 + 9.0 + 11.0) / 3.0).abs() < 1e-12);
+        assert!(Ohlc::new(1.0, 1.0, 1.0, 1.0, 0.0).is_ok());
+        assert!(matches!(
+            Ohlc::new(10.0, 9.0, 12.0, 11.0, 0.0),
+            Err(TalibError::InvalidInput { .. })
+        ));
+        assert!(Ohlc::new(10.0, Float::NAN, 9.0, 11.0, 0.0).is_err());
+    }
+
+    #[cfg(feature = "fixed")]
+    #[test]
+    fn test_fixed_round_trip() {
+        for value in [0.0, 1.0, -2.5, 1234.5678, -0.125] {
+            let fixed = Fixed::from_float(value);
+            assert!((fixed.to_float() - value).abs() < 1e-9);
+        }
+        assert_eq!(Fixed::from_int(3), Fixed::from_float(3.0));
+        assert_eq!(Fixed::from_int(1), Fixed::ONE);
+    }
+
+    #[cfg(feature = "fixed")]
+    #[test]
+    fn test_fixed_arithmetic_is_exact() {
+        let a = Fixed::from_float(0.1);
+        let b = Fixed::from_float(0.2);
+        // Integer addition is associative, unlike float addition
+        assert_eq!((a + b) + a, a + (b + a));
+        assert_eq!((a + b) - b, a);
+        assert_eq!(Fixed::from_int(9).div_int(3), Fixed::from_int(3));
+    }
 }
@@ -0,0 +1,65 @@
+//! Input data validation helpers
+//!
+//! Indicators reject non-finite input as a whole. These helpers let callers find
+//! every offending value up front so they can clean or drop them before computing.
+
+/// Check an input slice for non-finite values, reporting every bad index
+///
+/// Unlike per-indicator validation, which stops at the first problem, this scans the
+/// whole slice and returns the indices of all `NaN` and infinite values in ascending
+/// order.
+///
+/// # Returns
+///
+/// - `Ok(())` if every value is finite
+/// - `Err(indices)` with the index of each non-finite value
+///
+/// # Example
+///
+/// ```rust
+/// use ta_core::{validate, Float};
+///
+/// let data: [Float; 4] = [1.0, Float::NAN, 3.0, Float::INFINITY];
+/// assert_eq!(validate(&data), Err(vec![1, 3]));
+/// assert_eq!(validate(&[1.0, 2.0]), Ok(()));
+/// ```
+pub fn validate(inputs: &[crate::Float]) -> core::result::Result<(), Vec<usize>> {
+    let bad: Vec<usize> = inputs
+        .iter()
+        .enumerate()
+        .filter(|(_, x)| !x.is_finite())
+        .map(|(i, _)| i)
+        .collect();
+    if bad.is_empty() {
+        Ok(())
+    } else {
+        Err(bad)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Float;
+
+    #[test]
+    fn test_validate_clean_input() {
+        assert_eq!(validate(&[]), Ok(()));
+        assert_eq!(validate(&[1.0, -2.0, 0.0, Float::MAX]), Ok(()));
+    }
+
+    #[test]
+    fn test_validate_reports_all_bad_indices() {
+        let mut data: Vec<Float> = (0..20).map(|i| i as Float).collect();
+        data[0] = Float::NAN;
+        data[7] = Float::INFINITY;
+        data[8] = Float::NEG_INFINITY;
+        data[19] = Float::NAN;
+        assert_eq!(validate(&data), Err(vec![0, 7, 8, 19]));
+    }
+
+    #[test]
+    fn test_validate_all_bad() {
+        assert_eq!(validate(&[Float::NAN; 3]), Err(vec![0, 1, 2]));
+    }
+}
@@ -0,0 +1,226 @@
+//! Implementation of the Average True Range (ATR).
+
+use super::TRANGE;
+use crate::{
+    overlap::MaKind, smoothing::MaStage, Float, Indicator, Ohlc, Resettable, Result, TalibError,
+};
+
+/// Average True Range
+///
+/// The true range of a bar is `max(high - low, |high - prev_close|, |low - prev_close|)`,
+/// so it needs the previous close and is undefined for the first bar. ATR seeds with
+/// the simple average of the first `period` true ranges and then applies Wilder's
+/// smoothing:
+///
+/// ```text
+/// atr = (atr_prev * (period - 1) + tr) / period
+/// ```
+///
+/// Following TA-Lib, the first valid output is on index `period`. Batch computation
+/// rejects bars with a non-finite open, high, low or close. Some platforms
+/// smooth the true range with a plain SMA or EMA instead; see [`ATR::with_smoothing`].
+#[derive(Debug, Clone)]
+pub struct ATR {
+    period: usize,
+    kind: MaKind,
+    true_range: TRANGE,
+    smoother: MaStage,
+}
+
+impl ATR {
+    /// Create a new ATR with the given smoothing period.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if `period` is zero.
+    pub fn new(period: usize) -> Result<Self> {
+        Self::with_smoothing(period, MaKind::Wilder)
+    }
+
+    /// Create a new ATR that smooths the true range with a `kind` moving average.
+    ///
+    /// Only the smoothing stage changes; every kind has the same warm-up, so
+    /// `lookback()` stays `period`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if `period` is zero.
+    pub fn with_smoothing(period: usize, kind: MaKind) -> Result<Self> {
+        if period == 0 {
+            return Err(TalibError::invalid_period(
+                period,
+                "period must be greater than zero",
+            ));
+        }
+        Ok(Self {
+            period,
+            kind,
+            true_range: TRANGE::new(),
+            smoother: MaStage::new(kind, period),
+        })
+    }
+
+    /// The moving average used to smooth the true range.
+    pub fn smoothing(&self) -> MaKind {
+        self.kind
+    }
+}
+
+impl Indicator for ATR {
+    type Input = Ohlc;
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        format!("ATR({},{:?})", self.period, self.kind)
+    }
+
+    fn lookback(&self) -> usize {
+        self.period
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let bad = inputs.iter().position(|bar| {
+            ![bar.open, bar.high, bar.low, bar.close]
+                .iter()
+                .all(|v| v.is_finite())
+        });
+        if let Some(index) = bad {
+            return Err(TalibError::invalid_input(format!(
+                "non-finite OHLC price at index {}",
+                index
+            )));
+        }
+        let mut atr = Self::with_smoothing(self.period, self.kind)?;
+        Ok(inputs.iter().map(|&bar| atr.next(bar)).collect())
+    }
+
+    fn next(&mut self, input: Ohlc) -> Float {
+        let tr = self.true_range.next(input);
+        if tr.is_nan() {
+            return Float::NAN;
+        }
+        self.smoother.next(tr)
+    }
+}
+
+impl Resettable for ATR {
+    fn reset(&mut self) {
+        self.true_range.reset();
+        self.smoother = MaStage::new(self.kind, self.period);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bar(high: Float, low: Float, close: Float) -> Ohlc {
+        Ohlc {
+            open: close,
+            high,
+            low,
+            close,
+            volume: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_wilder_smoothing() {
+        let bars = [
+            bar(10.0, 8.0, 9.0),
+            bar(11.0, 9.0, 10.0),  // tr = 2
+            bar(14.0, 10.5, 13.0), // tr = max(3.5, 4, 0.5) = 4
+            bar(13.5, 12.0, 12.5), // tr = 1.5
+            bar(12.0, 9.0, 10.0),  // tr = max(3, 0.5, 3.5) = 3.5
+        ];
+        let out = ATR::new(2).unwrap().compute_to_vec(&bars).unwrap();
+        assert!(out[0].is_nan() && out[1].is_nan());
+        assert!((out[2] - 3.0).abs() < 1e-12);
+        assert!((out[3] - 2.25).abs() < 1e-12);
+        assert!((out[4] - 2.875).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_hand_computed_atr3() {
+        let bars = [
+            bar(48.70, 47.79, 48.16),
+            bar(48.72, 48.14, 48.61), // tr = max(0.58, 0.56, 0.02) = 0.58
+            bar(48.90, 48.39, 48.75), // tr = max(0.51, 0.29, 0.22) = 0.51
+            bar(48.87, 48.37, 48.63), // tr = max(0.50, 0.12, 0.38) = 0.50
+            bar(48.82, 48.24, 48.74), // tr = max(0.58, 0.19, 0.39) = 0.58
+            bar(49.05, 48.64, 49.03), // tr = max(0.41, 0.31, 0.10) = 0.41
+        ];
+        let out = ATR::new(3).unwrap().compute_to_vec(&bars).unwrap();
+        assert!(out[..3].iter().all(|v| v.is_nan()));
+        // Seed: (0.58 + 0.51 + 0.50) / 3 = 0.53
+        let seed: Float = 0.53;
+        let fourth = (seed * 2.0 + 0.58) / 3.0;
+        let fifth = (fourth * 2.0 + 0.41) / 3.0;
+        // True ranges are differences of prices near 49, which carry their rounding
+        let tolerance = 1e2 * Float::EPSILON * 49.0;
+        assert!((out[3] - seed).abs() < tolerance);
+        assert!((out[4] - fourth).abs() < tolerance);
+        assert!((out[5] - fifth).abs() < tolerance);
+    }
+
+    #[test]
+    fn test_non_finite_bar_rejected() {
+        let atr = ATR::new(3).unwrap();
+        for field in 0..4 {
+            let mut bars = vec![bar(11.0, 9.0, 10.0); 5];
+            match field {
+                0 => bars[2].open = Float::NAN,
+                1 => bars[2].high = Float::NAN,
+                2 => bars[2].low = Float::NAN,
+                _ => bars[2].close = Float::INFINITY,
+            }
+            assert!(matches!(
+                atr.compute_to_vec(&bars),
+                Err(TalibError::InvalidInput { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut atr = ATR::new(1).unwrap();
+        atr.next(bar(10.0, 8.0, 9.0));
+        assert_eq!(atr.next(bar(12.0, 9.0, 11.0)), 3.0);
+        atr.reset();
+        assert!(atr.next(bar(12.0, 9.0, 11.0)).is_nan());
+        assert!(ATR::new(0).is_err());
+    }
+
+    #[test]
+    fn test_sma_smoothing_matches_true_range_then_sma() {
+        use crate::overlap::SMA;
+
+        let bars: Vec<Ohlc> = (0..40)
+            .map(|i| {
+                let mid = 30.0 + (i as Float * 0.45).sin() * 3.0;
+                bar(mid + 0.6 + (i % 4) as Float * 0.3, mid - 0.5, mid + 0.2)
+            })
+            .collect();
+        let true_range: Vec<Float> = bars
+            .windows(2)
+            .map(|w| {
+                let (prev, cur) = (w[0].close, w[1]);
+                (cur.high - cur.low)
+                    .max((cur.high - prev).abs())
+                    .max((cur.low - prev).abs())
+            })
+            .collect();
+        let mut expected = vec![Float::NAN];
+        expected.extend(SMA::new(5).compute_to_vec(&true_range).unwrap());
+
+        let atr = ATR::with_smoothing(5, MaKind::Sma).unwrap();
+        assert_eq!(atr.smoothing(), MaKind::Sma);
+        assert_eq!(atr.lookback(), 5);
+        let out = atr.compute_to_vec(&bars).unwrap();
+        for (a, b) in out.iter().zip(&expected) {
+            assert!((a.is_nan() && b.is_nan()) || (a - b).abs() < 1e-12);
+        }
+        assert_eq!(ATR::new(5).unwrap().smoothing(), MaKind::Wilder);
+    }
+}
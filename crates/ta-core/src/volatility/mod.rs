@@ -0,0 +1,9 @@
+//! Volatility indicators.
+//! This module groups together indicators that measure the size of price moves, like ATR and
+//! the raw true range.
+
+mod atr;
+mod trange;
+
+pub use atr::ATR;
+pub use trange::TRANGE;
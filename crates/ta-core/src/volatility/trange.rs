@@ -0,0 +1,99 @@
+//! Implementation of the True Range (TRANGE).
+
+use crate::{Float, Indicator, Ohlc, Resettable, Result};
+
+/// True Range
+///
+/// The range of a bar extended to include any gap from the previous close:
+///
+/// ```text
+/// tr = max(high - low, |high - prev_close|, |low - prev_close|)
+/// ```
+///
+/// It needs the previous close, so following TA-Lib the first output is on
+/// index 1. This is the unsmoothed input of [`ATR`](super::ATR).
+#[derive(Debug, Clone, Default)]
+pub struct TRANGE {
+    prev_close: Option<Float>,
+}
+
+impl TRANGE {
+    /// Create a new true range.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Indicator for TRANGE {
+    type Input = Ohlc;
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        "TRANGE".to_string()
+    }
+
+    fn lookback(&self) -> usize {
+        1
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut trange = Self::new();
+        Ok(inputs.iter().map(|&bar| trange.next(bar)).collect())
+    }
+
+    #[inline]
+    fn next(&mut self, input: Ohlc) -> Float {
+        let Some(prev_close) = self.prev_close.replace(input.close) else {
+            return Float::NAN;
+        };
+        (input.high - input.low)
+            .max((input.high - prev_close).abs())
+            .max((input.low - prev_close).abs())
+    }
+}
+
+impl Resettable for TRANGE {
+    fn reset(&mut self) {
+        self.prev_close = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bar(high: Float, low: Float, close: Float) -> Ohlc {
+        Ohlc {
+            open: close,
+            high,
+            low,
+            close,
+            volume: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_three_bar_fixture() {
+        let bars = [
+            bar(10.0, 8.0, 9.0),
+            bar(9.5, 8.5, 9.0),    // straddles the previous close: high - low = 1
+            bar(14.0, 13.0, 13.5), // gap up: high - prev_close = 5 dominates
+        ];
+        let trange = TRANGE::new();
+        assert_eq!(trange.lookback(), 1);
+        let out = trange.compute_to_vec(&bars).unwrap();
+        assert!(out[0].is_nan());
+        assert_eq!(out[1..], [1.0, 5.0]);
+    }
+
+    #[test]
+    fn test_gap_down_and_reset() {
+        let mut trange = TRANGE::new();
+        trange.next(bar(20.0, 19.0, 19.5));
+        // low - prev_close = -4.5 in magnitude beats the bar's own range of 1
+        assert_eq!(trange.next(bar(16.0, 15.0, 15.5)), 4.5);
+        trange.reset();
+        assert!(trange.next(bar(16.0, 15.0, 15.5)).is_nan());
+    }
+}
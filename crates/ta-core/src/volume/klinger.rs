@@ -0,0 +1,230 @@
+//! Implementation of the Klinger Volume Oscillator (KVO).
+
+use crate::{smoothing::EmaStage, Float, Indicator, Ohlc, Resettable, Result, TalibError};
+
+/// Klinger Volume Oscillator
+///
+/// Produces `[kvo, signal]` per bar. Each bar's volume force is
+///
+/// ```text
+/// trend = +1 if (h + l + c) > previous (h + l + c), else -1
+/// dm    = h - l
+/// cm    = cm_prev + dm      if trend is unchanged
+///       = dm_prev + dm      if trend flipped
+/// VF    = volume * |2 * (dm / cm - 1)| * trend * 100
+/// ```
+///
+/// `KVO = EMA(VF, fast) - EMA(VF, slow)` and `signal = EMA(KVO, signal_period)`.
+/// Bars whose cumulative measurement `cm` is zero contribute no volume force. The
+/// first bar only seeds the trend, so no volume force is produced for it.
+#[derive(Debug, Clone)]
+pub struct Klinger {
+    fast_period: usize,
+    slow_period: usize,
+    signal_period: usize,
+    prev_hlc: Float,
+    prev_dm: Float,
+    prev_trend: Float,
+    cm: Float,
+    has_prev: bool,
+    fast: EmaStage,
+    slow: EmaStage,
+    signal: EmaStage,
+}
+
+impl Klinger {
+    /// Create a new Klinger oscillator, commonly `Klinger::new(34, 55, 13)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidPeriod` if any period is zero and
+    /// `TalibError::InvalidParameter` unless `fast_period < slow_period`.
+    pub fn new(fast_period: usize, slow_period: usize, signal_period: usize) -> Result<Self> {
+        for period in [fast_period, slow_period, signal_period] {
+            if period == 0 {
+                return Err(TalibError::invalid_period(
+                    period,
+                    "periods must be greater than zero",
+                ));
+            }
+        }
+        if fast_period >= slow_period {
+            return Err(TalibError::invalid_parameter(
+                "fast_period".to_string(),
+                fast_period.to_string(),
+                format!("less than slow_period ({})", slow_period),
+            ));
+        }
+        Ok(Self {
+            fast_period,
+            slow_period,
+            signal_period,
+            prev_hlc: 0.0,
+            prev_dm: 0.0,
+            prev_trend: 0.0,
+            cm: 0.0,
+            has_prev: false,
+            fast: EmaStage::new(fast_period),
+            slow: EmaStage::new(slow_period),
+            signal: EmaStage::new(signal_period),
+        })
+    }
+
+    /// Volume force for `bar`, or `None` for the seeding first bar.
+    fn volume_force(&mut self, bar: Ohlc) -> Option<Float> {
+        let hlc = bar.high + bar.low + bar.close;
+        let dm = bar.high - bar.low;
+        if !self.has_prev {
+            self.has_prev = true;
+            self.prev_hlc = hlc;
+            self.prev_dm = dm;
+            return None;
+        }
+        let trend = if hlc > self.prev_hlc { 1.0 } else { -1.0 };
+        self.cm = if trend == self.prev_trend {
+            self.cm + dm
+        } else {
+            self.prev_dm + dm
+        };
+        self.prev_hlc = hlc;
+        self.prev_dm = dm;
+        self.prev_trend = trend;
+
+        if self.cm == 0.0 {
+            return Some(0.0);
+        }
+        Some(bar.volume * (2.0 * (dm / self.cm - 1.0)).abs() * trend * 100.0)
+    }
+}
+
+impl Indicator<2> for Klinger {
+    type Input = Ohlc;
+
+    type Output = [Float; 2];
+
+    fn name(&self) -> String {
+        format!(
+            "Klinger({},{},{})",
+            self.fast_period, self.slow_period, self.signal_period
+        )
+    }
+
+    fn lookback(&self) -> usize {
+        // First bar seeds the trend, then the slow EMA and signal EMA warm up
+        1 + (self.slow_period - 1) + (self.signal_period - 1)
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut klinger = Self::new(self.fast_period, self.slow_period, self.signal_period)?;
+        Ok(inputs.iter().map(|&bar| klinger.next(bar)).collect())
+    }
+
+    fn next(&mut self, input: Ohlc) -> [Float; 2] {
+        let Some(vf) = self.volume_force(input) else {
+            return [Float::NAN; 2];
+        };
+        let fast = self.fast.next(vf);
+        let slow = self.slow.next(vf);
+        if slow.is_nan() {
+            return [Float::NAN; 2];
+        }
+        let kvo = fast - slow;
+        let signal = self.signal.next(kvo);
+        if kvo.is_finite() && signal.is_finite() {
+            [kvo, signal]
+        } else {
+            [Float::NAN; 2]
+        }
+    }
+}
+
+impl Resettable for Klinger {
+    fn reset(&mut self) {
+        *self = Self {
+            fast: EmaStage::new(self.fast_period),
+            slow: EmaStage::new(self.slow_period),
+            signal: EmaStage::new(self.signal_period),
+            prev_hlc: 0.0,
+            prev_dm: 0.0,
+            prev_trend: 0.0,
+            cm: 0.0,
+            has_prev: false,
+            ..*self
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::assert_reset_streaming_matches_batch;
+
+    fn bars(n: usize) -> Vec<Ohlc> {
+        (0..n)
+            .map(|i| {
+                let mid = 100.0 + (i as Float * 0.35).sin() * 5.0 + i as Float * 0.1;
+                let range = 1.0 + (i % 4) as Float * 0.5;
+                Ohlc {
+                    open: mid,
+                    high: mid + range,
+                    low: mid - range,
+                    close: mid + (i as Float * 0.9).cos() * range * 0.5,
+                    volume: 1000.0 + ((i * 37) % 11) as Float * 100.0,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_lookback() {
+        let klinger = Klinger::new(5, 10, 4).unwrap();
+        assert_eq!(klinger.lookback(), 13);
+        let rows = klinger.compute_to_vec(&bars(30)).unwrap();
+        assert!(rows[..13].iter().all(|r| r.iter().all(|v| v.is_nan())));
+        assert!(rows[13..].iter().all(|r| r.iter().all(|v| v.is_finite())));
+    }
+
+    #[test]
+    fn test_signal_is_ema_of_kvo() {
+        let inputs = bars(120);
+        // A signal period of 1 leaves the signal equal to the KVO itself
+        let kvo: Vec<Float> = Klinger::new(8, 21, 1)
+            .unwrap()
+            .compute_to_vec(&inputs)
+            .unwrap()
+            .iter()
+            .map(|r| r[0])
+            .filter(|v| !v.is_nan())
+            .collect();
+        let rows = Klinger::new(8, 21, 5)
+            .unwrap()
+            .compute_to_vec(&inputs)
+            .unwrap();
+
+        let mut ema = EmaStage::new(5);
+        let expected: Vec<Float> = kvo.iter().map(|&k| ema.next(k)).collect();
+        let offset = inputs.len() - kvo.len();
+        for (k, &signal) in expected.iter().enumerate().skip(4) {
+            let row = rows[offset + k];
+            assert!((row[0] - kvo[k]).abs() < 1e-9);
+            assert!((row[1] - signal).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_streaming_matches_batch_and_reset() {
+        let inputs = bars(60);
+        let klinger = Klinger::new(4, 9, 3).unwrap();
+        assert_reset_streaming_matches_batch(klinger, &inputs, &inputs, 0.0);
+    }
+
+    #[test]
+    fn test_invalid_periods() {
+        assert!(Klinger::new(0, 55, 13).is_err());
+        assert!(Klinger::new(34, 55, 0).is_err());
+        assert!(matches!(
+            Klinger::new(55, 34, 13),
+            Err(TalibError::InvalidParameter { .. })
+        ));
+    }
+}
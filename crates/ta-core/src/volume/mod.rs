@@ -0,0 +1,11 @@
+//! Volume indicators.
+//! This module groups together indicators that weight or accumulate traded volume, like VWAP,
+//! OBV and the Klinger oscillator.
+
+mod klinger;
+mod obv;
+mod vwap;
+
+pub use klinger::Klinger;
+pub use obv::OBV;
+pub use vwap::VwapBands;
@@ -0,0 +1,103 @@
+//! Implementation of On-Balance Volume (OBV).
+
+use crate::{Float, Indicator, Ohlc, Resettable, Result};
+
+/// On-Balance Volume
+///
+/// Running total of volume signed by the direction of the close:
+///
+/// ```text
+/// obv = obv_prev + volume   if close > prev_close
+///     = obv_prev - volume   if close < prev_close
+///     = obv_prev            otherwise
+/// ```
+///
+/// Following TA-Lib, the first bar seeds the total with its own volume, so there is
+/// no warm-up.
+#[derive(Debug, Clone, Default)]
+pub struct OBV {
+    prev_close: Option<Float>,
+    total: Float,
+}
+
+impl OBV {
+    /// Create a new OBV.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Indicator for OBV {
+    type Input = Ohlc;
+
+    type Output = Float;
+
+    fn name(&self) -> String {
+        "OBV".to_string()
+    }
+
+    fn lookback(&self) -> usize {
+        0
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        let mut obv = Self::new();
+        Ok(inputs.iter().map(|&bar| obv.next(bar)).collect())
+    }
+
+    fn next(&mut self, input: Ohlc) -> Float {
+        match self.prev_close.replace(input.close) {
+            None => self.total = input.volume,
+            Some(prev) if input.close > prev => self.total += input.volume,
+            Some(prev) if input.close < prev => self.total -= input.volume,
+            Some(_) => {}
+        }
+        self.total
+    }
+}
+
+impl Resettable for OBV {
+    fn reset(&mut self) {
+        self.prev_close = None;
+        self.total = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bar(close: Float, volume: Float) -> Ohlc {
+        Ohlc {
+            open: close,
+            high: close,
+            low: close,
+            close,
+            volume,
+        }
+    }
+
+    #[test]
+    fn test_up_down_flat_sequence() {
+        let bars = [
+            bar(10.0, 100.0),
+            bar(11.0, 50.0), // up
+            bar(10.5, 30.0), // down
+            bar(10.5, 80.0), // flat
+            bar(12.0, 20.0), // up
+        ];
+        let obv = OBV::new();
+        assert_eq!(obv.lookback(), 0);
+        let out = obv.compute_to_vec(&bars).unwrap();
+        assert_eq!(out, vec![100.0, 150.0, 120.0, 120.0, 140.0]);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut obv = OBV::new();
+        obv.next(bar(10.0, 100.0));
+        obv.next(bar(9.0, 40.0));
+        obv.reset();
+        assert_eq!(obv.next(bar(9.0, 25.0)), 25.0);
+    }
+}
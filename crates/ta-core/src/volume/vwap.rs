@@ -0,0 +1,183 @@
+//! Implementation of VWAP with volume-weighted standard-deviation bands.
+
+use crate::{Float, Indicator, Ohlc, Resettable, Result, TalibError};
+
+/// Session VWAP with standard-deviation bands
+///
+/// Produces `[lower, vwap, upper]` per bar, where `vwap` is the volume-weighted
+/// average of the typical price since the start of the session and the bands are
+/// `vwap ± multiplier * σ`, with `σ` the volume-weighted standard deviation of the
+/// typical price over the same bars.
+///
+/// The VWAP and the weighted sum of squared deviations are updated with West's
+/// weighted form of Welford's algorithm, so each update is O(1) and the bands stay
+/// accurate when the price is large relative to its spread.
+/// Call [`Resettable::reset`] at a session boundary to anchor a new VWAP. Until some
+/// volume has traded the whole row is `Float::NAN`.
+#[derive(Debug, Clone)]
+pub struct VwapBands {
+    multiplier: Float,
+    sum_volume: Float,
+    vwap: Float,
+    m2: Float,
+}
+
+impl VwapBands {
+    /// Create VWAP bands with the given standard-deviation multiplier.
+    ///
+    /// # Errors
+    ///
+    /// Returns `TalibError::InvalidParameter` unless `multiplier` is finite and positive.
+    pub fn new(multiplier: Float) -> Result<Self> {
+        if !(multiplier.is_finite() && multiplier > 0.0) {
+            return Err(TalibError::invalid_parameter(
+                "multiplier".to_string(),
+                multiplier.to_string(),
+                "finite value greater than 0".to_string(),
+            ));
+        }
+        Ok(Self {
+            multiplier,
+            sum_volume: 0.0,
+            vwap: 0.0,
+            m2: 0.0,
+        })
+    }
+}
+
+impl Indicator<3> for VwapBands {
+    type Input = Ohlc;
+
+    type Output = [Float; 3];
+
+    fn name(&self) -> String {
+        format!("VwapBands({})", self.multiplier)
+    }
+
+    fn lookback(&self) -> usize {
+        0
+    }
+
+    fn compute_to_vec(&self, inputs: &[Self::Input]) -> Result<Vec<Self::Output>> {
+        // Batch mode treats the whole slice as one session
+        let mut bands = Self::new(self.multiplier)?;
+        Ok(inputs.iter().map(|&bar| bands.next(bar)).collect())
+    }
+
+    fn next(&mut self, input: Ohlc) -> [Float; 3] {
+        let tp = input.typical_price();
+        self.sum_volume += input.volume;
+        if self.sum_volume <= 0.0 {
+            return [Float::NAN; 3];
+        }
+        let delta = tp - self.vwap;
+        self.vwap += delta * input.volume / self.sum_volume;
+        self.m2 += input.volume * delta * (tp - self.vwap);
+
+        // m2 can dip slightly below zero from rounding
+        let variance = self.m2.max(0.0) / self.sum_volume;
+        let width = self.multiplier * variance.sqrt();
+        let row = [self.vwap - width, self.vwap, self.vwap + width];
+        if row.iter().all(|v| v.is_finite()) {
+            row
+        } else {
+            [Float::NAN; 3]
+        }
+    }
+}
+
+impl Resettable for VwapBands {
+    fn reset(&mut self) {
+        self.sum_volume = 0.0;
+        self.vwap = 0.0;
+        self.m2 = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bar(price: Float, volume: Float) -> Ohlc {
+        Ohlc {
+            open: price,
+            high: price,
+            low: price,
+            close: price,
+            volume,
+        }
+    }
+
+    #[test]
+    fn test_single_price_has_zero_width() {
+        let mut bands = VwapBands::new(2.0).unwrap();
+        let row = bands.next(bar(10.0, 5.0));
+        assert_eq!(row, [10.0, 10.0, 10.0]);
+    }
+
+    #[test]
+    fn test_vwap_is_volume_weighted() {
+        let mut bands = VwapBands::new(1.0).unwrap();
+        bands.next(bar(10.0, 1.0));
+        let [lower, vwap, upper] = bands.next(bar(20.0, 3.0));
+        assert!((vwap - 17.5).abs() < 1e-12);
+        // Weighted variance: (1*56.25 + 3*6.25) / 4 = 18.75
+        let sigma = (18.75 as Float).sqrt();
+        assert!((upper - (17.5 + sigma)).abs() < 1e-12);
+        assert!((lower - (17.5 - sigma)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_bands_accurate_at_large_price() {
+        // A spread of ±1 around a price whose square swamps the precision
+        let base: Float = if cfg!(feature = "f32") { 1e4 } else { 1e9 };
+        let mut bands = VwapBands::new(1.0).unwrap();
+        bands.next(bar(base + 1.0, 1.0));
+        let [lower, vwap, upper] = bands.next(bar(base + 3.0, 1.0));
+        assert_eq!(vwap, base + 2.0);
+        assert_eq!((lower, upper), (base + 1.0, base + 3.0));
+    }
+
+    #[test]
+    fn test_bands_widen_as_price_disperses() {
+        let inputs: Vec<Ohlc> = (0..20)
+            .map(|i| {
+                let offset = i as Float * if i % 2 == 0 { 1.0 } else { -1.0 };
+                bar(100.0 + offset, 10.0)
+            })
+            .collect();
+        let rows = VwapBands::new(2.0)
+            .unwrap()
+            .compute_to_vec(&inputs)
+            .unwrap();
+        let widths: Vec<Float> = rows.iter().map(|r| r[2] - r[0]).collect();
+        for pair in widths.windows(2).skip(1) {
+            assert!(pair[1] > pair[0], "bands did not widen: {:?}", pair);
+        }
+        for row in &rows {
+            assert!((row[1] - row[0] - (row[2] - row[1])).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_zero_volume_row_is_nan() {
+        let mut bands = VwapBands::new(2.0).unwrap();
+        assert!(bands.next(bar(10.0, 0.0)).iter().all(|v| v.is_nan()));
+        assert!(bands.next(bar(10.0, 1.0)).iter().all(|v| !v.is_nan()));
+    }
+
+    #[test]
+    fn test_session_reset_anchors_new_vwap() {
+        let mut bands = VwapBands::new(2.0).unwrap();
+        bands.next(bar(10.0, 100.0));
+        bands.reset();
+        let row = bands.next(bar(50.0, 1.0));
+        assert_eq!(row, [50.0, 50.0, 50.0]);
+    }
+
+    #[test]
+    fn test_invalid_multiplier() {
+        assert!(VwapBands::new(0.0).is_err());
+        assert!(VwapBands::new(Float::NAN).is_err());
+    }
+}
@@ -0,0 +1,138 @@
+//! Cross-implementation parity tests for `dot_product`.
+//!
+//! The scalar fallback and the runtime-dispatched implementation (AVX2/AVX-512 on
+//! x86_64, NEON on AArch64, SIMD128 on wasm32) may accumulate in a different order,
+//! so results are not required to be bit-identical. Instead every implementation must
+//! stay within the standard forward error bound for recursive summation:
+//!
+//! ```text
+//! |computed - exact| <= 2 * n * EPSILON * sum(|a[i] * b[i]|)
+//! ```
+//!
+//! where `exact` is a compensated (TwoProduct + Neumaier) reference. The CI matrix runs
+//! this file on each target, so every arch implementation is exercised somewhere.
+
+use proptest::prelude::*;
+use ta_core::simd::{dispatch, scalar};
+use ta_core::Float;
+
+/// Near-exact dot product using an FMA error-free product and Neumaier summation.
+fn reference_dot(a: &[Float], b: &[Float]) -> Float {
+    let mut sum: Float = 0.0;
+    let mut compensation: Float = 0.0;
+    let mut add = |value: Float| {
+        let t = sum + value;
+        if sum.abs() >= value.abs() {
+            compensation += (sum - t) + value;
+        } else {
+            compensation += (value - t) + sum;
+        }
+        sum = t;
+    };
+    for (&x, &y) in a.iter().zip(b.iter()) {
+        let product = x * y;
+        add(product);
+        add(x.mul_add(y, -product));
+    }
+    sum + compensation
+}
+
+/// Documented tolerance: `2 * n * EPSILON * sum(|a[i] * b[i]|)`.
+fn tolerance(a: &[Float], b: &[Float]) -> Float {
+    let magnitude: Float = a.iter().zip(b.iter()).map(|(x, y)| (x * y).abs()).sum();
+    2.0 * a.len() as Float * Float::EPSILON * magnitude + Float::MIN_POSITIVE
+}
+
+fn assert_parity(a: &[Float], b: &[Float]) {
+    let exact = reference_dot(a, b);
+    let tol = tolerance(a, b);
+    let scalar_result = scalar::dot_product(a, b);
+    let dispatch_result = dispatch::dot_product(a, b);
+    assert!(
+        (scalar_result - exact).abs() <= tol,
+        "scalar drifted: got {}, exact {}, tolerance {}",
+        scalar_result,
+        exact,
+        tol
+    );
+    assert!(
+        (dispatch_result - exact).abs() <= tol,
+        "dispatch drifted: got {}, exact {}, tolerance {}",
+        dispatch_result,
+        exact,
+        tol
+    );
+}
+
+/// Deterministic pseudo-random values in `[-1, 1)` so failures are reproducible.
+fn lcg_values(seed: u64, len: usize) -> Vec<Float> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            ((state >> 11) as f64 / (1u64 << 53) as f64 * 2.0 - 1.0) as Float
+        })
+        .collect()
+}
+
+#[test]
+fn test_parity_lengths_around_lane_boundaries() {
+    for len in 0..=67 {
+        let a = lcg_values(1, len);
+        let b = lcg_values(2, len);
+        assert_parity(&a, &b);
+    }
+}
+
+#[test]
+fn test_parity_catastrophic_cancellation() {
+    // Large products that cancel pairwise leave only the small tail terms
+    let mut a = Vec::new();
+    let mut b = Vec::new();
+    for i in 0..64 {
+        let big = 1.0e6 as Float * (i + 1) as Float;
+        a.extend_from_slice(&[big, -big, 1.0e-3]);
+        b.extend_from_slice(&[big, big, 1.0]);
+    }
+    assert_parity(&a, &b);
+}
+
+#[test]
+fn test_parity_wide_dynamic_range() {
+    let a: Vec<Float> = (0..257)
+        .map(|i| if i % 2 == 0 { 1.0e8 } else { 1.0e-8 } as Float)
+        .collect();
+    let b: Vec<Float> = (0..257)
+        .map(|i| if i % 3 == 0 { -1.0 } else { 1.0 })
+        .collect();
+    assert_parity(&a, &b);
+}
+
+#[test]
+fn test_parity_alternating_signs() {
+    let a: Vec<Float> = (0..1001)
+        .map(|i| if i % 2 == 0 { 1.0 } else { -1.0 } * (1.0 + i as Float * 1.0e-3))
+        .collect();
+    let b = vec![1.0 as Float; a.len()];
+    assert_parity(&a, &b);
+}
+
+#[test]
+fn test_parity_long_random_series() {
+    let a = lcg_values(42, 100_003);
+    let b = lcg_values(7, 100_003);
+    assert_parity(&a, &b);
+}
+
+proptest! {
+    #[test]
+    fn prop_parity_random_vectors(
+        pairs in proptest::collection::vec((-1.0e6f64..1.0e6, -1.0e6f64..1.0e6), 0..300)
+    ) {
+        let a: Vec<Float> = pairs.iter().map(|&(x, _)| x as Float).collect();
+        let b: Vec<Float> = pairs.iter().map(|&(_, y)| y as Float).collect();
+        assert_parity(&a, &b);
+    }
+}
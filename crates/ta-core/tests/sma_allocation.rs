@@ -0,0 +1,45 @@
+//! Allocation check for the buffer-writing SMA batch path.
+//!
+//! `SMA::compute_checked` works directly on `&[Float]` with the `wide` SIMD type of
+//! the active float feature and writes into a caller-provided buffer, so it must not
+//! touch the heap. A counting global allocator makes that observable; this file
+//! holds a single test so no other test thread allocates concurrently.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use ta_core::overlap::SMA;
+use ta_core::Float;
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+#[test]
+fn compute_checked_does_not_allocate() {
+    let inputs: Vec<Float> = (0..10_000).map(|i| (i as Float * 0.01).sin()).collect();
+    let mut outputs = vec![0.0; inputs.len()];
+    // Periods above the SIMD lane count take the vectorized first-window sum
+    for period in [5, 8, 33, 500] {
+        let sma = SMA::new(period);
+        let before = ALLOCATIONS.load(Ordering::SeqCst);
+        let written = sma.compute_checked(&inputs, &mut outputs).unwrap();
+        let after = ALLOCATIONS.load(Ordering::SeqCst);
+        assert_eq!(after - before, 0, "period {} allocated", period);
+        assert_eq!(written, inputs.len() - period + 1);
+    }
+}
@@ -5,28 +5,133 @@
 //!
 //! Note: This crate requires a Python 3.x interpreter to build.
 
+use numpy::{PyArray1, PyArrayMethods, PyReadonlyArray1, PyUntypedArrayMethods};
+use pyo3::create_exception;
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
+use ta_core::overlap::SMA;
+use ta_core::Indicator;
+pub use ta_core::TalibError;
 
 /// Python module for technical analysis indicators
 #[pymodule]
-fn ta_py(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
+fn ta_py(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(hello_world, m)?)?;
+    m.add_class::<PySma>()?;
+    m.add("TaError", py.get_type_bound::<TaError>())?;
+    add_error_codes(m)?;
     Ok(())
 }
 
-/// Example function to verify Python bindings work
-#[pyfunction]
-fn hello_world() -> PyResult<String> {
-    Ok("Hello from ta-py!".to_string())
+/// Expose the stable `TalibError::code` values as module constants
+fn add_error_codes(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("ERR_INVALID_INPUT", TalibError::CODE_INVALID_INPUT)?;
+    m.add("ERR_INVALID_PERIOD", TalibError::CODE_INVALID_PERIOD)?;
+    m.add("ERR_INSUFFICIENT_DATA", TalibError::CODE_INSUFFICIENT_DATA)?;
+    m.add("ERR_INVALID_PARAMETER", TalibError::CODE_INVALID_PARAMETER)?;
+    m.add("ERR_COMPUTATION_ERROR", TalibError::CODE_COMPUTATION_ERROR)?;
+    m.add("ERR_NOT_IMPLEMENTED", TalibError::CODE_NOT_IMPLEMENTED)?;
+    m.add(
+        "ERR_DIMENSION_MISMATCH",
+        TalibError::CODE_DIMENSION_MISMATCH,
+    )?;
+    m.add(
+        "ERR_OUTPUT_BUFFER_TOO_SMALL",
+        TalibError::CODE_OUTPUT_BUFFER_TOO_SMALL,
+    )?;
+    Ok(())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+create_exception!(
+    ta_py,
+    TaError,
+    PyValueError,
+    "Error raised by an indicator; `code` holds one of the `ERR_*` constants."
+);
 
-    #[test]
-    fn test_hello_world() {
-        assert_eq!(hello_world().unwrap(), "Hello from ta-py!");
+/// Map a core error to a `TaError` (a `ValueError`) carrying `TalibError::code`
+fn to_py_err(err: TalibError) -> PyErr {
+    let py_err = TaError::new_err(err.to_string());
+    Python::with_gil(|py| {
+        let attached = py_err.value_bound(py).setattr("code", err.code());
+        match attached {
+            Ok(()) => py_err,
+            Err(setattr_err) => setattr_err,
+        }
+    })
+}
+
+/// Simple moving average
+///
+/// Python: `PySma(period)`. `compute` takes a list of prices and returns the
+/// averages after warm-up (the list is `period - 1` shorter than its input);
+/// `next` returns `None` until `period` prices have been seen.
+#[pyclass(name = "PySma")]
+struct PySma {
+    inner: SMA,
+}
+
+#[pymethods]
+impl PySma {
+    #[new]
+    fn new(period: usize) -> PyResult<Self> {
+        if period == 0 {
+            return Err(to_py_err(TalibError::invalid_period(
+                period,
+                "period must be greater than zero",
+            )));
+        }
+        Ok(Self {
+            inner: SMA::new(period),
+        })
+    }
+
+    /// Batch computation over a list of prices, without the warm-up prefix
+    ///
+    /// NaN or infinite prices yield non-finite averages for the windows that
+    /// contain them; they do not raise.
+    fn compute(&self, prices: Vec<f64>) -> PyResult<Vec<f64>> {
+        let outputs = self.inner.compute_to_vec(&prices).map_err(to_py_err)?;
+        let skip = self.inner.lookback().min(outputs.len());
+        Ok(outputs[skip..].to_vec())
+    }
+
+    /// Batch computation over a 1-D `float64` NumPy array
+    ///
+    /// Reads the array in place and writes straight into a newly allocated NumPy
+    /// array, with no intermediate `Vec`, so it avoids the two list conversions of
+    /// `compute`, which dominate its cost on large series. Returns the same values
+    /// as `compute`: the warm-up prefix is dropped, and NaN or infinite prices yield
+    /// non-finite averages for the windows that contain them rather than an error.
+    ///
+    /// Raises `TypeError` for non-contiguous arrays (pass `np.ascontiguousarray(a)`)
+    /// and, through argument conversion, for arrays whose dtype is not `float64`.
+    fn compute_numpy<'py>(
+        &self,
+        py: Python<'py>,
+        prices: PyReadonlyArray1<'py, f64>,
+    ) -> PyResult<Py<PyArray1<f64>>> {
+        let inputs = prices
+            .as_slice()
+            .map_err(|_| PyTypeError::new_err("prices must be a C-contiguous 1-D float64 array"))?;
+        let len = self.inner.valid_output_len(inputs.len());
+        let outputs = PyArray1::<f64>::zeros_bound(py, len, false);
+        {
+            // Safety: the array was just created and is not shared with Python yet
+            let buffer = unsafe { outputs.as_slice_mut() }
+                .map_err(|_| PyTypeError::new_err("output array is not contiguous"))?;
+            let values = self.inner.compute_iter(inputs).skip(self.inner.lookback());
+            for (slot, value) in buffer.iter_mut().zip(values) {
+                *slot = value.map_err(to_py_err)?;
+            }
+        }
+        Ok(outputs.unbind())
+    }
+
+    /// Process one price, returning `None` during warm-up
+    fn next(&mut self, price: f64) -> Option<f64> {
+        let value = self.inner.next(price);
+        (!value.is_nan()).then_some(value)
     }
 }
 
@@ -44,4 +149,16 @@ mod tests {
     fn test_hello_world() {
         assert_eq!(hello_world().unwrap(), "Hello from ta-py!");
     }
+
+    #[test]
+    fn test_py_sma_compute_and_next() {
+        let mut sma = PySma::new(3).unwrap();
+        assert_eq!(
+            sma.compute(vec![1.0, 2.0, 3.0, 4.0, 5.0]).unwrap(),
+            vec![2.0, 3.0, 4.0]
+        );
+        assert_eq!(sma.next(1.0), None);
+        assert_eq!(sma.next(2.0), None);
+        assert_eq!(sma.next(3.0), Some(2.0));
+    }
 }
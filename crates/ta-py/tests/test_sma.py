@@ -0,0 +1,53 @@
+"""Tests for the SMA binding. Build the extension first, e.g. `maturin develop`."""
+
+import numpy as np
+import pytest
+
+import ta_py
+from ta_py import PySma, TaError
+
+
+def test_compute_drops_warm_up():
+    assert PySma(3).compute([1, 2, 3, 4, 5]) == [2, 3, 4]
+
+
+def test_next_returns_none_during_warm_up():
+    sma = PySma(2)
+    assert sma.next(1.0) is None
+    assert sma.next(3.0) == 2.0
+
+
+def test_zero_period_raises_value_error():
+    with pytest.raises(ValueError):
+        PySma(0)
+
+
+def test_errors_carry_stable_code():
+    with pytest.raises(TaError) as excinfo:
+        PySma(0)
+    assert excinfo.value.code == ta_py.ERR_INVALID_PERIOD
+
+
+def test_compute_numpy_large_input():
+    prices = np.arange(1_000_000, dtype=np.float64)
+    out = PySma(3).compute_numpy(prices)
+    assert out.shape == (len(prices) - 2,)
+    assert out[0] == 1.0
+    assert out[-1] == 999_998.0
+
+
+def test_compute_numpy_matches_compute_with_gaps():
+    prices = [1.0, float("nan"), 2.0, 3.0, 4.0, float("inf"), 5.0, 6.0, 7.0, 8.0]
+    sma = PySma(3)
+    listed = sma.compute(prices)
+    arrayed = sma.compute_numpy(np.array(prices))
+    assert len(arrayed) == len(listed)
+    np.testing.assert_array_equal(arrayed, np.array(listed))
+    assert arrayed[-1] == 7.0
+
+
+def test_compute_numpy_rejects_non_contiguous_and_wrong_dtype():
+    with pytest.raises(TypeError):
+        PySma(3).compute_numpy(np.arange(20, dtype=np.float64)[::2])
+    with pytest.raises(TypeError):
+        PySma(3).compute_numpy(np.arange(20, dtype=np.int64))
@@ -3,8 +3,116 @@
 //! This crate provides WebAssembly bindings for the core technical analysis library
 //! using wasm-bindgen.
 
+use ta_core::overlap::SMA;
+use ta_core::Indicator;
+pub use ta_core::TalibError;
 use wasm_bindgen::prelude::*;
 
+/// Stable error codes, matching `TalibError::code`
+///
+/// JS reads them as static properties, e.g. `ErrorCode.InvalidInput`, and compares
+/// them with the `code` field of a thrown error. `#[wasm_bindgen]` enums only take
+/// literal discriminants, so the codes are exposed as getters over the core
+/// constants instead.
+#[wasm_bindgen]
+pub struct ErrorCode;
+
+#[wasm_bindgen]
+impl ErrorCode {
+    /// Invalid input data
+    #[wasm_bindgen(getter = InvalidInput)]
+    pub fn invalid_input() -> u32 {
+        TalibError::CODE_INVALID_INPUT
+    }
+
+    /// Invalid period parameter
+    #[wasm_bindgen(getter = InvalidPeriod)]
+    pub fn invalid_period() -> u32 {
+        TalibError::CODE_INVALID_PERIOD
+    }
+
+    /// Not enough data points
+    #[wasm_bindgen(getter = InsufficientData)]
+    pub fn insufficient_data() -> u32 {
+        TalibError::CODE_INSUFFICIENT_DATA
+    }
+
+    /// Invalid parameter value
+    #[wasm_bindgen(getter = InvalidParameter)]
+    pub fn invalid_parameter() -> u32 {
+        TalibError::CODE_INVALID_PARAMETER
+    }
+
+    /// Numerical failure during computation
+    #[wasm_bindgen(getter = ComputationError)]
+    pub fn computation_error() -> u32 {
+        TalibError::CODE_COMPUTATION_ERROR
+    }
+
+    /// Feature not implemented
+    #[wasm_bindgen(getter = NotImplemented)]
+    pub fn not_implemented() -> u32 {
+        TalibError::CODE_NOT_IMPLEMENTED
+    }
+
+    /// Paired inputs have different lengths
+    #[wasm_bindgen(getter = DimensionMismatch)]
+    pub fn dimension_mismatch() -> u32 {
+        TalibError::CODE_DIMENSION_MISMATCH
+    }
+
+    /// Output buffer shorter than the result
+    #[wasm_bindgen(getter = OutputBufferTooSmall)]
+    pub fn output_buffer_too_small() -> u32 {
+        TalibError::CODE_OUTPUT_BUFFER_TOO_SMALL
+    }
+}
+
+/// Map a core error to a JS `Error` whose `code` field holds `TalibError::code`
+fn to_js_err(err: TalibError) -> JsValue {
+    let js_err = js_sys::Error::new(&err.to_string());
+    // Setting a property only fails on frozen objects, and a fresh Error is not one
+    let _ = js_sys::Reflect::set(&js_err, &"code".into(), &err.code().into());
+    js_err.into()
+}
+
+/// Simple moving average
+///
+/// `compute` takes and returns a `Float64Array` of the same length, with NaN for
+/// the first `period - 1` values; `next` returns `undefined` during warm-up.
+#[wasm_bindgen]
+pub struct WasmSma {
+    inner: SMA,
+}
+
+#[wasm_bindgen]
+impl WasmSma {
+    /// Create an SMA over `period` prices; throws if `period` is zero
+    #[wasm_bindgen(constructor)]
+    pub fn new(period: usize) -> Result<WasmSma, JsValue> {
+        if period == 0 {
+            return Err(to_js_err(TalibError::invalid_period(
+                period,
+                "period must be greater than zero",
+            )));
+        }
+        Ok(WasmSma {
+            inner: SMA::new(period),
+        })
+    }
+
+    /// Batch computation over a series of prices
+    pub fn compute(&self, prices: &[f64]) -> Result<Vec<f64>, JsValue> {
+        self.inner.compute_to_vec(prices).map_err(to_js_err)
+    }
+
+    /// Process one price, returning `undefined` during warm-up
+    pub fn next(&mut self, price: f64) -> Option<f64> {
+        let value = self.inner.next(price);
+        (!value.is_nan()).then_some(value)
+    }
+}
+
 /// Example function to verify WASM bindings work
 #[wasm_bindgen]
 pub fn hello_world() -> String {
@@ -26,6 +134,55 @@ mod tests {
         assert_eq!(hello_world(), "Hello from ta-wasm!");
     }
 
+    #[test]
+    fn test_error_codes_match_core() {
+        let cases = [
+            (TalibError::invalid_input("nan"), ErrorCode::invalid_input()),
+            (
+                TalibError::invalid_period(0, "zero"),
+                ErrorCode::invalid_period(),
+            ),
+            (
+                TalibError::insufficient_data(3, 1),
+                ErrorCode::insufficient_data(),
+            ),
+            (
+                TalibError::invalid_parameter("k", "0", "k > 0"),
+                ErrorCode::invalid_parameter(),
+            ),
+            (
+                TalibError::computation_error("overflow"),
+                ErrorCode::computation_error(),
+            ),
+            (
+                TalibError::not_implemented("f128"),
+                ErrorCode::not_implemented(),
+            ),
+            (
+                TalibError::dimension_mismatch(4, 3),
+                ErrorCode::dimension_mismatch(),
+            ),
+            (
+                TalibError::output_buffer_too_small(4, 3),
+                ErrorCode::output_buffer_too_small(),
+            ),
+        ];
+        for (err, code) in cases {
+            assert_eq!(err.code(), code, "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_wasm_sma() {
+        let mut sma = WasmSma::new(3).unwrap();
+        let out = sma.compute(&[1.0, 2.0, 3.0, 4.0, 5.0]).unwrap();
+        assert!(out[0].is_nan() && out[1].is_nan());
+        assert_eq!(&out[2..], &[2.0, 3.0, 4.0]);
+        assert_eq!(sma.next(1.0), None);
+        sma.next(2.0);
+        assert_eq!(sma.next(3.0), Some(2.0));
+    }
+
     #[test]
     fn test_add() {
         assert_eq!(add(1.0, 2.0), 3.0);
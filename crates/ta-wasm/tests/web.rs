@@ -0,0 +1,19 @@
+//! Tests run in a JS host with `wasm-pack test --node`.
+
+#![cfg(target_arch = "wasm32")]
+
+use ta_wasm::WasmSma;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn sma_compute_short_series() {
+    let sma = WasmSma::new(2).unwrap();
+    let out = sma.compute(&[1.0, 3.0, 5.0, 7.0]).unwrap();
+    assert!(out[0].is_nan());
+    assert_eq!(&out[1..], &[2.0, 4.0, 6.0]);
+}
+
+#[wasm_bindgen_test]
+fn sma_rejects_zero_period() {
+    assert!(WasmSma::new(0).is_err());
+}